@@ -5,6 +5,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         &[
             "../proto/email_service.proto",
             "../proto/auth_service.proto",
+            "../proto/settings_service.proto",
+            "../proto/transaction_service.proto",
         ],
         &["../proto"],
     )?;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS set_deactivated_at ON users;
+DROP FUNCTION IF EXISTS set_users_deactivated_at();
+DROP INDEX IF EXISTS idx_users_deactivated_at;
+ALTER TABLE users DROP COLUMN IF EXISTS deactivated_at;
@@ -0,0 +1,17 @@
+-- Track when an account was deactivated, so it can be purged after a grace period
+ALTER TABLE users
+ADD COLUMN deactivated_at TIMESTAMPTZ;
+UPDATE users
+SET deactivated_at = NOW()
+WHERE is_active = FALSE;
+-- Keep deactivated_at in sync with is_active
+CREATE OR REPLACE FUNCTION set_users_deactivated_at() RETURNS TRIGGER AS $$ BEGIN IF NEW.is_active THEN NEW.deactivated_at = NULL;
+ELSIF OLD.is_active THEN NEW.deactivated_at = NOW();
+END IF;
+RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+CREATE TRIGGER set_deactivated_at BEFORE
+UPDATE OF is_active ON users FOR EACH ROW EXECUTE FUNCTION set_users_deactivated_at();
+CREATE INDEX idx_users_deactivated_at ON users(deactivated_at)
+WHERE deactivated_at IS NOT NULL;
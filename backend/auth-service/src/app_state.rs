@@ -4,16 +4,25 @@ use std::time::Duration;
 use tokio::sync::Mutex;
 use tonic::{Response, Status, transport::Channel};
 use uuid::Uuid;
-use webauthn_rs::prelude::{PasskeyAuthentication, PasskeyRegistration};
+use webauthn_rs::{
+    Webauthn,
+    prelude::{PasskeyAuthentication, PasskeyRegistration},
+};
 
 use crate::{
-    Config,
+    Config, database,
     grpc::email_service::service::{
         ActivateAccountRequest, ActivateAccountResponse, ForgotPasswordRequest,
-        ForgotPasswordResponse, email_service_client::EmailServiceClient,
+        ForgotPasswordResponse, PasswordChangedRequest, PasswordChangedResponse,
+        email_service_client::EmailServiceClient,
     },
+    models::response::Error,
+    utils::jwt::JwtKeys,
 };
 
+/// How long the token checks cache whether a user is active and verified
+const USER_STATUS_TTL: Duration = Duration::from_secs(30);
+
 /// Application state shared across all routes
 ///
 /// Contains configuration and database connection pool
@@ -23,9 +32,12 @@ use crate::{
 /// * `config` - Application configuration settings
 /// * `db` - PostgreSQL connection pool for async database operations
 /// * `email_service` - A mutex for the EmailServiceClient GRPC
+/// * `webauthn` - WebAuthn instance built once from the relying party configuration
+/// * `jwt_keys` - JWT signing and verification keys built once from the configuration
 /// * `passkey_registrations` - TTL cache for WebAuthn registration challenges (5 minute expiry)
 /// * `passkey_authentications` - TTL cache for WebAuthn authentication challenges (5 minute expiry)
 /// * `pending_users` - TTL cache for pending user registration data (5 minute expiry)
+/// * `user_statuses` - TTL cache of whether users are active and verified (30 second expiry)
 ///
 /// # Usage
 /// ```rust
@@ -40,9 +52,12 @@ pub struct AppState {
     pub config: Config,
     db: PgPool,
     email_service: Mutex<EmailServiceClient<Channel>>,
+    webauthn: Webauthn,
+    jwt_keys: JwtKeys,
     passkey_registrations: Cache<Uuid, PasskeyRegistration>,
     passkey_authentications: Cache<String, PasskeyAuthentication>,
     pending_users: Cache<Uuid, (String, String)>,
+    user_statuses: Cache<Uuid, bool>,
 }
 
 impl AppState {
@@ -50,7 +65,17 @@ impl AppState {
     ///
     /// # Returns
     /// * `AppState` - the AppState that contains all the necessary configs
+    ///
+    /// # Panics
+    /// Panics if the WebAuthn relying party or JWT configuration is invalid
     pub fn new(config: Config, db: PgPool, email_service: EmailServiceClient<Channel>) -> Self {
+        let webauthn = config
+            .build_webauthn()
+            .expect("Could not build WebAuthn from the relying party configuration.");
+        let jwt_keys = config
+            .build_jwt_keys()
+            .expect("Could not build JWT keys from the configuration.");
+
         // Create caches with 5 minute TTL for WebAuthn challenges
         let passkey_registrations = Cache::builder()
             .time_to_live(Duration::from_secs(300))
@@ -64,13 +89,18 @@ impl AppState {
             .time_to_live(Duration::from_secs(300))
             .build();
 
+        let user_statuses = Cache::builder().time_to_live(USER_STATUS_TTL).build();
+
         Self {
             config,
             db,
             email_service: Mutex::new(email_service),
+            webauthn,
+            jwt_keys,
             passkey_registrations,
             passkey_authentications,
             pending_users,
+            user_statuses,
         }
     }
 
@@ -88,6 +118,22 @@ impl AppState {
         &self.db
     }
 
+    /// Gets a reference to the cached WebAuthn instance
+    ///
+    /// # Returns
+    /// * `&Webauthn` - A reference to the WebAuthn instance
+    pub fn get_webauthn(&self) -> &Webauthn {
+        &self.webauthn
+    }
+
+    /// Gets a reference to the JWT signing and verification keys
+    ///
+    /// # Returns
+    /// * `&JwtKeys` - A reference to the JWT keys
+    pub fn get_jwt_keys(&self) -> &JwtKeys {
+        &self.jwt_keys
+    }
+
     /// Call the send_activate_account GRPC from the email-service
     ///
     /// # Arguments
@@ -126,6 +172,25 @@ impl AppState {
             .await
     }
 
+    /// Call the send_password_changed GRPC from the email-service
+    ///
+    /// # Arguments
+    /// * `PasswordChangedRequest` - A request of type `PasswordChangedRequest`
+    ///
+    /// # Returns
+    /// * `Ok(Response<PasswordChangedResponse>)` - A response of type `PasswordChangedResponse`
+    /// * `Err(Status)` - A GRPC status
+    pub async fn send_password_changed(
+        &self,
+        request: PasswordChangedRequest,
+    ) -> Result<Response<PasswordChangedResponse>, Status> {
+        self.email_service
+            .lock()
+            .await
+            .send_password_changed(request)
+            .await
+    }
+
     /// Store a passkey registration challenge temporarily (5 minute expiry)
     pub async fn store_passkey_registration(&self, user_id: Uuid, reg: PasskeyRegistration) {
         self.passkey_registrations.insert(user_id, reg).await;
@@ -162,4 +227,25 @@ impl AppState {
     pub async fn get_pending_user(&self, user_id: Uuid) -> Option<(String, String)> {
         self.pending_users.remove(&user_id).await
     }
+
+    /// Checks whether a user is active and verified, so their tokens are accepted
+    ///
+    /// The result is cached for 30 seconds, so deactivating a user rejects
+    /// their tokens within that time without a query on every request.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user the token belongs to
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - `true` if the user is active and verified
+    /// * `Err(Error)` - Database operation error
+    pub async fn is_user_active(&self, user_id: Uuid) -> Result<bool, Error> {
+        if let Some(active) = self.user_statuses.get(&user_id).await {
+            return Ok(active);
+        }
+
+        let active = database::users::is_active_and_verified(user_id, &self.db).await?;
+        self.user_statuses.insert(user_id, active).await;
+        Ok(active)
+    }
 }
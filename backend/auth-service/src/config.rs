@@ -1,4 +1,11 @@
-use std::env::var;
+use std::{env::var, str::FromStr};
+
+use jsonwebtoken::Algorithm;
+
+use crate::utils::{
+    jwt::JwtKeys,
+    link::{LINK_TOKEN_PLACEHOLDER, is_valid_link_template},
+};
 
 /// Application configuration loaded from environment variables
 ///
@@ -15,26 +22,53 @@ use std::env::var;
 /// * `pg_database` - Name of the auth-service database to connect to (default: brewget_auth)
 ///
 /// ## Security Configuration
-/// * `cors_url` - Allowed CORS origin URL for frontend integration
-/// * `jwt_secret` - Secret key used for signing and verifying JWT tokens
+/// * `jwt_algorithm` - Algorithm used to sign JWT tokens, HS256 or RS256 (default: HS256)
+/// * `jwt_secret` - Secret key used for signing and verifying HS256 JWT tokens
+/// * `jwt_private_key_path` - Path to the PEM encoded RSA private key used to sign RS256 JWT tokens
+/// * `jwt_public_key_path` - Path to the PEM encoded RSA public key used to verify RS256 JWT tokens
+/// * `jwt_issuer` - Value of the `iss` claim of issued JWT tokens (default: brewget-auth-service)
+/// * `jwt_audience` - Value of the `aud` claim of issued JWT tokens (default: brewget)
 /// * `jwt_expires_in` - JWT access token expiration time in seconds
 /// * `jwt_max_age` - Maximum age for JWT refresh tokens in seconds
 ///
 /// ## Service Integration
 /// * `email_hostname` - Hostname of the email service for gRPC communication
 /// * `email_grpc_port` - Port number for the email service gRPC server
+/// * `settings_hostname` - Hostname of the settings service for gRPC communication
+/// * `settings_grpc_port` - Port number for the settings service gRPC server
+/// * `transaction_hostname` - Hostname of the transaction service for gRPC communication
+/// * `transaction_grpc_port` - Port number for the transaction service gRPC server
+/// * `internal_grpc_secret` - Shared secret sent to and expected from other services over gRPC
 /// * `frontend_hostname` - Hostname of the frontend application for URL generation
+/// * `activation_link_path` - Path of activation links with a `{token}` placeholder
+///   (default: /activate/{token})
+/// * `reset_link_path` - Path of password reset links with a `{token}` placeholder
+///   (default: /forgot-password/{token})
+///
+/// ## Account Configuration
+/// * `account_purge_grace_days` - Days a deactivated account is kept before it and its data in
+///   the other services are deleted, 0 disables the purge (default: 30)
 ///
 /// ## Captcha Configuration
 /// * `turnstile_secret` - Cloudflare Turnstile secret key for captcha verification
 ///
 /// ## Password Security Configuration
-/// * `password_history_limit` - Number of previous passwords to prevent reuse (default: 3)
+/// * `password_history_limit` - Number of previous passwords to prevent reuse, 0 or less disables
+///   password history (default: 3)
+///
+/// ## Username Configuration
+/// * `username_min_length` - Minimum number of characters of a new username, inclusive
+///   (default: 4)
 ///
 /// ## WebAuthn Configuration
 /// * `rp_id` - Relying Party ID for WebAuthn (e.g., "brewget.com" or "localhost")
-/// * `rp_origin` - Relying Party origin URL for WebAuthn (e.g., "https://brewget.com" or "http://localhost:5173")
+/// * `rp_origins` - Relying Party origin URLs for WebAuthn; the first one is the primary origin and
+///   the rest are additional allowed origins (e.g., a native app's "android:apk-key-hash:..." origin)
 /// * `rp_name` - Relying Party name displayed to users (e.g., "BrewGet")
+/// * `max_passkeys_per_user` - Maximum number of active passkeys a user can have (default: 20)
+/// * `rp_require_user_verification` - Whether passkey ceremonies must verify the user with a
+///   biometric or PIN (default: true). webauthn-rs always requests UV for passkeys, so disabling
+///   this only skips the additional server-side check of the UV flag on login.
 #[derive(Clone)]
 pub struct Config {
     pub auth_http_port: u32,
@@ -43,18 +77,33 @@ pub struct Config {
     pub pg_username: String,
     pub pg_password: String,
     pub pg_database: String,
-    pub cors_url: String,
-    pub jwt_secret: String,
+    pub jwt_algorithm: Algorithm,
+    pub jwt_secret: Option<String>,
+    pub jwt_private_key_path: Option<String>,
+    pub jwt_public_key_path: Option<String>,
+    pub jwt_issuer: String,
+    pub jwt_audience: String,
     pub jwt_expires_in: u32,
     pub jwt_max_age: u32,
     pub email_hostname: String,
     pub email_grpc_port: u32,
+    pub settings_hostname: String,
+    pub settings_grpc_port: u32,
+    pub transaction_hostname: String,
+    pub transaction_grpc_port: u32,
+    pub internal_grpc_secret: String,
     pub frontend_hostname: String,
+    pub activation_link_path: String,
+    pub reset_link_path: String,
+    pub account_purge_grace_days: u32,
     pub turnstile_secret: String,
     pub password_history_limit: i64,
+    pub username_min_length: usize,
     pub rp_id: String,
-    pub rp_origin: String,
+    pub rp_origins: Vec<String>,
     pub rp_name: String,
+    pub max_passkeys_per_user: usize,
+    pub rp_require_user_verification: bool,
 }
 
 impl Config {
@@ -72,25 +121,48 @@ impl Config {
     /// - `PG_USERNAME` - Database username
     /// - `PG_PASSWORD` - Database password
     /// - `AUTH_PG_DATABASE` - Auth service database name (falls back to PG_DATABASE if not set)
-    /// - `CORS_URL` - Allowed CORS origin URL
-    /// - `JWT_SECRET` - Secret key for JWT signing
+    /// - `JWT_ALGORITHM` - JWT signing algorithm, HS256 or RS256 (optional, defaults to HS256)
+    /// - `JWT_SECRET` - Secret key for JWT signing (required for HS256)
+    /// - `JWT_PRIVATE_KEY_PATH` - Path to the RSA private key PEM (required for RS256)
+    /// - `JWT_PUBLIC_KEY_PATH` - Path to the RSA public key PEM (required for RS256)
+    /// - `JWT_ISSUER` - Issuer of JWT tokens (optional, defaults to "brewget-auth-service")
+    /// - `JWT_AUDIENCE` - Audience of JWT tokens (optional, defaults to "brewget")
     /// - `JWT_EXPIRES_IN` - Must be a valid u32 (seconds)
     /// - `JWT_MAX_AGE` - Must be a valid u32 (seconds)
     /// - `EMAIL_HOSTNAME` - Email service hostname
     /// - `EMAIL_GRPC_PORT` - Must be a valid u32 port number
+    /// - `SETTINGS_HOSTNAME` - Settings service hostname
+    /// - `SETTINGS_GRPC_PORT` - Must be a valid u32 port number
+    /// - `TRANSACTION_HOSTNAME` - Transaction service hostname
+    /// - `TRANSACTION_GRPC_PORT` - Must be a valid u32 port number
+    /// - `INTERNAL_GRPC_SECRET` - Shared secret for internal gRPC calls
     /// - `FRONTEND_HOSTNAME` - Frontend application hostname
+    /// - `ACTIVATION_LINK_PATH` - Activation link path with a `{token}` placeholder (optional,
+    ///   defaults to "/activate/{token}")
+    /// - `RESET_LINK_PATH` - Password reset link path with a `{token}` placeholder (optional,
+    ///   defaults to "/forgot-password/{token}")
+    /// - `ACCOUNT_PURGE_GRACE_DAYS` - Days deactivated accounts are kept, 0 disables the purge
+    ///   (optional, defaults to 30)
     /// - `TURNSTILE_SECRET` - Cloudflare Turnstile secret key
-    /// - `PASSWORD_HISTORY_LIMIT` - Number of previous passwords to prevent reuse (optional, defaults to 3)
+    /// - `PASSWORD_HISTORY_LIMIT` - Number of previous passwords to prevent reuse, 0 disables the check (optional, defaults to 3)
+    /// - `USERNAME_MIN_LENGTH` - Minimum number of characters of a new username, inclusive (optional, defaults to 4)
     /// - `RP_ID` - Relying Party ID for WebAuthn (optional, defaults to "localhost")
-    /// - `RP_ORIGIN` - Relying Party origin URL for WebAuthn (optional, defaults to "http://localhost:5173")
+    /// - `RP_ORIGIN` - Comma-separated Relying Party origin URLs for WebAuthn, primary origin first
+    ///   (optional, defaults to "http://localhost:5173")
     /// - `RP_NAME` - Relying Party name for WebAuthn (optional, defaults to "BrewGet")
+    /// - `MAX_PASSKEYS_PER_USER` - Maximum active passkeys per user (optional, defaults to 20)
+    /// - `RP_REQUIRE_USER_VERIFICATION` - Require user verification for passkeys (optional, defaults to true)
     ///
     /// # Panics
     ///
     /// This method will panic if:
     /// - Any required environment variable is missing
-    /// - `AUTH_HTTP_PORT`, `AUTH_GRPC_PORT`, `JWT_EXPIRES_IN`, `JWT_MAX_AGE`, or `EMAIL_GRPC_PORT`
-    ///   cannot be parsed as u32
+    /// - `AUTH_HTTP_PORT`, `AUTH_GRPC_PORT`, `JWT_EXPIRES_IN`, `JWT_MAX_AGE`, `EMAIL_GRPC_PORT`,
+    ///   `SETTINGS_GRPC_PORT`, `TRANSACTION_GRPC_PORT` or `ACCOUNT_PURGE_GRACE_DAYS` cannot be
+    ///   parsed as u32
+    /// - `JWT_ALGORITHM` is not a valid JWT algorithm
+    /// - `MAX_PASSKEYS_PER_USER` cannot be parsed as usize
+    /// - `ACTIVATION_LINK_PATH` or `RESET_LINK_PATH` does not contain the `{token}` placeholder
     ///
     /// # Returns
     ///
@@ -121,8 +193,16 @@ impl Config {
         let pg_database = var("AUTH_PG_DATABASE")
             .or_else(|_| var("PG_DATABASE"))
             .expect("AUTH_PG_DATABASE or PG_DATABASE must be provided.");
-        let cors_url = var("CORS_URL").expect("CORS_URL must be provided.");
-        let jwt_secret = var("JWT_SECRET").expect("JWT_SECRET must be provided.");
+        let jwt_algorithm = var("JWT_ALGORITHM")
+            .map(|algorithm| {
+                Algorithm::from_str(&algorithm).expect("JWT_ALGORITHM must be a JWT algorithm.")
+            })
+            .unwrap_or(Algorithm::HS256);
+        let jwt_secret = var("JWT_SECRET").ok();
+        let jwt_private_key_path = var("JWT_PRIVATE_KEY_PATH").ok();
+        let jwt_public_key_path = var("JWT_PUBLIC_KEY_PATH").ok();
+        let jwt_issuer = var("JWT_ISSUER").unwrap_or_else(|_| "brewget-auth-service".into());
+        let jwt_audience = var("JWT_AUDIENCE").unwrap_or_else(|_| "brewget".into());
         let jwt_expires_in = var("JWT_EXPIRES_IN")
             .map(|expiry| expiry.parse::<u32>())
             .expect("JWT_EXPIRES_IN must be provided.")
@@ -136,16 +216,72 @@ impl Config {
             .map(|port| port.parse::<u32>())
             .expect("EMAIL_GRPC_PORT must be provided.")
             .expect("EMAIL_GRPC_PORT must be an u32.");
+        let settings_hostname =
+            var("SETTINGS_HOSTNAME").expect("SETTINGS_HOSTNAME must be provided.");
+        let settings_grpc_port = var("SETTINGS_GRPC_PORT")
+            .map(|port| port.parse::<u32>())
+            .expect("SETTINGS_GRPC_PORT must be provided.")
+            .expect("SETTINGS_GRPC_PORT must be an u32.");
+        let transaction_hostname =
+            var("TRANSACTION_HOSTNAME").expect("TRANSACTION_HOSTNAME must be provided.");
+        let transaction_grpc_port = var("TRANSACTION_GRPC_PORT")
+            .map(|port| port.parse::<u32>())
+            .expect("TRANSACTION_GRPC_PORT must be provided.")
+            .expect("TRANSACTION_GRPC_PORT must be an u32.");
+        let internal_grpc_secret =
+            var("INTERNAL_GRPC_SECRET").expect("INTERNAL_GRPC_SECRET must be provided.");
         let frontend_hostname =
             var("FRONTEND_HOSTNAME").expect("FRONTEND_HOSTNAME must be provided.");
+        let activation_link_path =
+            var("ACTIVATION_LINK_PATH").unwrap_or_else(|_| "/activate/{token}".into());
+        if !is_valid_link_template(&activation_link_path) {
+            panic!(
+                "ACTIVATION_LINK_PATH must contain {}.",
+                LINK_TOKEN_PLACEHOLDER
+            );
+        }
+        let reset_link_path =
+            var("RESET_LINK_PATH").unwrap_or_else(|_| "/forgot-password/{token}".into());
+        if !is_valid_link_template(&reset_link_path) {
+            panic!("RESET_LINK_PATH must contain {}.", LINK_TOKEN_PLACEHOLDER);
+        }
+        let account_purge_grace_days = var("ACCOUNT_PURGE_GRACE_DAYS")
+            .map(|days| {
+                days.parse::<u32>()
+                    .expect("ACCOUNT_PURGE_GRACE_DAYS must be an u32.")
+            })
+            .unwrap_or(30);
         let turnstile_secret = var("TURNSTILE_SECRET").expect("TURNSTILE_SECRET must be provided.");
         let password_history_limit = var("PASSWORD_HISTORY_LIMIT")
             .ok()
             .and_then(|limit| limit.parse::<i64>().ok())
             .unwrap_or(3);
+        let username_min_length = var("USERNAME_MIN_LENGTH")
+            .ok()
+            .and_then(|length| length.parse::<usize>().ok())
+            .unwrap_or(4);
         let rp_id = var("RP_ID").expect("RP_ID must be provided.");
-        let rp_origin = var("RP_ORIGIN").expect("RP_ORIGIN must be provided.");
+        let rp_origins: Vec<String> = var("RP_ORIGIN")
+            .expect("RP_ORIGIN must be provided.")
+            .split(',')
+            .map(|origin| origin.trim().to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+        if rp_origins.is_empty() {
+            panic!("RP_ORIGIN must contain at least one origin.");
+        }
         let rp_name = var("RP_NAME").expect("RP_NAME must be provided");
+        let max_passkeys_per_user = var("MAX_PASSKEYS_PER_USER")
+            .map(|limit| {
+                limit
+                    .parse::<usize>()
+                    .expect("MAX_PASSKEYS_PER_USER must be an usize.")
+            })
+            .unwrap_or(20);
+        let rp_require_user_verification = var("RP_REQUIRE_USER_VERIFICATION")
+            .ok()
+            .and_then(|require| require.parse::<bool>().ok())
+            .unwrap_or(true);
 
         Self {
             auth_http_port,
@@ -154,33 +290,107 @@ impl Config {
             pg_username,
             pg_password,
             pg_database,
-            cors_url,
+            jwt_algorithm,
             jwt_secret,
+            jwt_private_key_path,
+            jwt_public_key_path,
+            jwt_issuer,
+            jwt_audience,
             jwt_expires_in,
             jwt_max_age,
             email_hostname,
             email_grpc_port,
+            settings_hostname,
+            settings_grpc_port,
+            transaction_hostname,
+            transaction_grpc_port,
+            internal_grpc_secret,
             frontend_hostname,
+            activation_link_path,
+            reset_link_path,
+            account_purge_grace_days,
             turnstile_secret,
             password_history_limit,
+            username_min_length,
             rp_id,
-            rp_origin,
+            rp_origins,
             rp_name,
+            max_passkeys_per_user,
+            rp_require_user_verification,
         }
     }
 
     /// Build a WebAuthn instance from the configuration
     ///
+    /// The first configured origin is used as the primary origin and every
+    /// additional origin is appended to the allowed origins, so credentials
+    /// are shared between the web app and native apps.
+    ///
     /// # Returns
     /// * `Ok(Webauthn)` - A configured WebAuthn instance
-    /// * `Err(Box<dyn std::error::Error>)` - If WebAuthn cannot be built
+    /// * `Err(Box<dyn std::error::Error>)` - If an origin is invalid or WebAuthn cannot be built
     pub fn build_webauthn(&self) -> Result<webauthn_rs::Webauthn, Box<dyn std::error::Error>> {
         use url::Url;
         use webauthn_rs::WebauthnBuilder;
 
-        let rp_origin = Url::parse(&self.rp_origin)?;
-        let builder = WebauthnBuilder::new(&self.rp_id, &rp_origin)?.rp_name(&self.rp_name);
+        let (primary_origin, additional_origins) = self
+            .rp_origins
+            .split_first()
+            .ok_or("At least one RP origin must be configured")?;
+
+        let rp_origin = Url::parse(primary_origin)?;
+        let mut builder = WebauthnBuilder::new(&self.rp_id, &rp_origin)?.rp_name(&self.rp_name);
+        tracing::info!("✅ WebAuthn primary origin accepted: {}", rp_origin);
+
+        for origin in additional_origins {
+            let origin = Url::parse(origin)?;
+            builder = builder.append_allowed_origin(&origin);
+            tracing::info!("✅ WebAuthn additional origin accepted: {}", origin);
+        }
 
         Ok(builder.build()?)
     }
+
+    /// Build the JWT signing and verification keys from the configuration
+    ///
+    /// Only tokens with the configured issuer and audience pass verification.
+    /// HS256 signs and verifies with `jwt_secret`. RS256 signs with the private
+    /// key and verifies with the public key, so the public key can be handed to
+    /// other services without giving them the ability to issue tokens.
+    ///
+    /// # Returns
+    /// * `Ok(JwtKeys)` - The keys for the configured algorithm
+    /// * `Err(Box<dyn std::error::Error>)` - If a required secret or key is missing or invalid,
+    ///   or the algorithm is not supported
+    pub fn build_jwt_keys(&self) -> Result<JwtKeys, Box<dyn std::error::Error>> {
+        let keys = match self.jwt_algorithm {
+            Algorithm::HS256 => {
+                let secret = self
+                    .jwt_secret
+                    .as_ref()
+                    .ok_or("JWT_SECRET must be provided for HS256")?;
+                JwtKeys::hs256(secret.as_bytes())
+            }
+            Algorithm::RS256 => {
+                let private_key_path = self
+                    .jwt_private_key_path
+                    .as_ref()
+                    .ok_or("JWT_PRIVATE_KEY_PATH must be provided for RS256")?;
+                let public_key_path = self
+                    .jwt_public_key_path
+                    .as_ref()
+                    .ok_or("JWT_PUBLIC_KEY_PATH must be provided for RS256")?;
+
+                let keys = JwtKeys::rs256(
+                    &std::fs::read(private_key_path)?,
+                    &std::fs::read(public_key_path)?,
+                )?;
+                tracing::info!("✅ JWT RS256 keys loaded");
+                keys
+            }
+            algorithm => return Err(format!("Unsupported JWT algorithm: {:?}", algorithm).into()),
+        };
+
+        Ok(keys.with_issuer_and_audience(&self.jwt_issuer, &self.jwt_audience))
+    }
 }
@@ -47,6 +47,49 @@ pub async fn insert(
     })
 }
 
+/// Count the active passkey credentials of a user inside a transaction
+///
+/// The user's row is locked until the transaction ends, so concurrent
+/// additions for the same user are counted one after another and cannot
+/// exceed the limit together.
+///
+/// # Arguments
+/// * `user_id` - UUID of the user
+/// * `tx` - Database transaction the credential will be inserted in
+///
+/// # Returns
+/// * `Ok(usize)` - Number of active credentials of the user
+/// * `Err(Error)` - Database error
+pub async fn count_active_for_update(
+    user_id: Uuid,
+    tx: &mut Transaction<'_, Postgres>,
+) -> Result<usize, Error> {
+    let to_error = |e: sqlx::Error| -> Error {
+        tracing::error!("Failed to count passkey credentials: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            TranslationKey::SomethingWentWrong,
+        )
+            .into()
+    };
+
+    sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(to_error)?;
+
+    let (count,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM passkey_credentials WHERE user_id = $1 AND is_active = TRUE",
+    )
+    .bind(user_id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(to_error)?;
+
+    Ok(count as usize)
+}
+
 /// Find all active passkey credentials for a user
 ///
 /// # Arguments
@@ -64,7 +107,44 @@ pub async fn find_by_user_id(
         r#"
         SELECT * FROM passkey_credentials
         WHERE user_id = $1 AND is_active = TRUE
-        ORDER BY created_at DESC
+        ORDER BY last_used_at DESC NULLS LAST, created_at DESC, id
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch passkey credentials: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            TranslationKey::SomethingWentWrong,
+        )
+            .into()
+    })
+}
+
+/// Find all passkey credentials for a user, including revoked ones
+///
+/// Used by the security-history view, where revoked credentials are still
+/// relevant. Most recently used credentials come first, followed by the ones
+/// that were never used. Ties are ordered by ID, so the order is stable.
+///
+/// # Arguments
+/// * `user_id` - UUID of the user
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(Vec<PasskeyCredential>)` - List of all credentials for the user
+/// * `Err(Error)` - Database error
+pub async fn find_all_by_user_id(
+    user_id: Uuid,
+    pool: &PgPool,
+) -> Result<Vec<PasskeyCredential>, Error> {
+    sqlx::query_as::<_, PasskeyCredential>(
+        r#"
+        SELECT * FROM passkey_credentials
+        WHERE user_id = $1
+        ORDER BY last_used_at DESC NULLS LAST, created_at DESC, id
         "#,
     )
     .bind(user_id)
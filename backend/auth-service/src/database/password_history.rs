@@ -2,9 +2,12 @@ use axum::http::StatusCode;
 use sqlx::{PgPool, Postgres};
 use uuid::Uuid;
 
-use crate::models::{
-    password_history::PasswordHistory,
-    response::{Error, TranslationKey},
+use crate::{
+    models::{
+        password_history::PasswordHistory,
+        response::{Error, TranslationKey},
+    },
+    utils::password::is_password_history_enabled,
 };
 
 /// Inserts a password into the password history
@@ -42,7 +45,8 @@ where
 /// Deletes old password history entries beyond the specified limit for a user
 ///
 /// This function keeps only the N most recent passwords and removes older entries
-/// to prevent unbounded table growth.
+/// to prevent unbounded table growth. A limit of 0 or less means password history
+/// is disabled, nothing is deleted then.
 ///
 /// # Arguments
 /// * `user_id` - The UUID of the user
@@ -60,6 +64,10 @@ pub async fn cleanup_old_passwords<'a, E>(
 where
     E: sqlx::Executor<'a, Database = Postgres>,
 {
+    if !is_password_history_enabled(keep_limit) {
+        return Ok(0);
+    }
+
     sqlx::query(
         r#"
         DELETE FROM password_history
@@ -82,6 +90,9 @@ where
 
 /// Retrieves the last N password hashes for a user
 ///
+/// A limit of 0 or less means password history is disabled, no passwords are
+/// returned then and the database is not queried.
+///
 /// # Arguments
 /// * `user_id` - The UUID of the user
 /// * `limit` - Number of recent passwords to retrieve
@@ -95,6 +106,10 @@ pub async fn get_recent_passwords(
     limit: i64,
     pool: &PgPool,
 ) -> Result<Vec<PasswordHistory>, Error> {
+    if !is_password_history_enabled(limit) {
+        return Ok(Vec::new());
+    }
+
     sqlx::query_as::<_, PasswordHistory>(
         r#"
         SELECT id, user_id, password_hash, created_at
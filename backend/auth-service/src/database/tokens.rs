@@ -19,10 +19,11 @@ use crate::models::{
 pub async fn insert(new_token: NewToken, pool: &PgPool) -> Result<usize, Error> {
     sqlx::query(
         r#"
-        INSERT INTO tokens (user_id, token, token_type, expires_at)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO tokens (id, user_id, token, token_type, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
         "#,
     )
+    .bind(new_token.id)
     .bind(new_token.user_id)
     .bind(new_token.token)
     .bind(new_token.token_type)
@@ -55,28 +56,46 @@ pub async fn delete_by_uuid(uuid: Uuid, pool: &PgPool) -> Result<usize, Error> {
     .map(|result| result.rows_affected() as usize)?)
 }
 
-pub async fn delete_by_token(tkn: &str, pool: &PgPool) -> Result<usize, Error> {
+/// Deletes a single token by its ID
+///
+/// # Arguments
+/// * `jti` - The `jti` claim of the token to delete
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(usize)` - Number of tokens deleted
+/// * `Err(Error)` - Database operation error
+pub async fn delete_by_jti(jti: Uuid, pool: &PgPool) -> Result<usize, Error> {
     Ok(sqlx::query(
         r#"
         DELETE FROM tokens
-        WHERE token = $1
+        WHERE id = $1
         "#,
     )
-    .bind(tkn)
+    .bind(jti)
     .execute(pool)
     .await
     .map(|result| result.rows_affected() as usize)?)
 }
 
-pub async fn find(find_token: &str, pool: &PgPool) -> Result<Token, Error> {
+/// Finds a token by its ID
+///
+/// # Arguments
+/// * `jti` - The `jti` claim of the token
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(Token)` - The stored token
+/// * `Err(Error)` - `TokenExpired` if the token was revoked, or a database operation error
+pub async fn find_by_jti(jti: Uuid, pool: &PgPool) -> Result<Token, Error> {
     sqlx::query_as::<_, Token>(
         r#"
-        SELECT user_id, token, expires_at
+        SELECT id, user_id, expires_at
         FROM tokens
-        WHERE token = $1
+        WHERE id = $1
         "#,
     )
-    .bind(find_token)
+    .bind(jti)
     .fetch_one(pool)
     .await
     .map_err(|e: sqlx::Error| -> Error {
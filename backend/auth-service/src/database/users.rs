@@ -96,6 +96,30 @@ pub async fn filter_by_uuid(find_uuid: Uuid, pool: &PgPool) -> Result<User, Erro
     })
 }
 
+/// Checks whether a user is active and has verified their email
+///
+/// # Arguments
+/// * `find_uuid` - The user UUID to check
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(bool)` - `true` if the user exists, is active and is verified
+/// * `Err(Error)` - Database operation error
+pub async fn is_active_and_verified(find_uuid: Uuid, pool: &PgPool) -> Result<bool, Error> {
+    let allowed: Option<bool> = sqlx::query_scalar(
+        r#"
+        SELECT is_active AND is_verified
+        FROM users
+        WHERE id = $1
+        "#,
+    )
+    .bind(find_uuid)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(allowed.unwrap_or(false))
+}
+
 /// Search for a user by username or email
 ///
 /// # Arguments
@@ -234,3 +258,54 @@ where
             .into()
     })
 }
+
+/// Finds the accounts that were deactivated longer than the grace period ago
+///
+/// # Arguments
+/// * `grace_days` - Days a deactivated account is kept before it is purged
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(Vec<Uuid>)` - The IDs of the accounts to purge, longest deactivated first
+/// * `Err(Error)` - Database operation error
+pub async fn find_deactivated_before(grace_days: u32, pool: &PgPool) -> Result<Vec<Uuid>, Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT id
+        FROM users
+        WHERE is_active = FALSE
+          AND deactivated_at < NOW() - make_interval(days => $1)
+        ORDER BY deactivated_at
+        "#,
+    )
+    .bind(grace_days as i32)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.into())
+}
+
+/// Deletes a deactivated account
+///
+/// Accounts that were reactivated in the meantime are kept. Tokens, links,
+/// passkeys and audit logs are removed by their `ON DELETE CASCADE`.
+///
+/// # Arguments
+/// * `find_uuid` - The account to delete
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Ok(bool)` - `true` if the account was deleted
+/// * `Err(Error)` - Database operation error
+pub async fn delete_deactivated(find_uuid: Uuid, pool: &PgPool) -> Result<bool, Error> {
+    sqlx::query(
+        r#"
+        DELETE FROM users
+        WHERE id = $1 AND is_active = FALSE
+        "#,
+    )
+    .bind(find_uuid)
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|e| e.into())
+}
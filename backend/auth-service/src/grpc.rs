@@ -1,2 +1,4 @@
 pub mod auth_service;
 pub mod email_service;
+pub mod settings_service;
+pub mod transaction_service;
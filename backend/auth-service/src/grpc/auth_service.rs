@@ -4,12 +4,15 @@ pub mod service {
 
 use std::sync::Arc;
 
-use jsonwebtoken::{DecodingKey, Validation, decode};
+use shared_types::grpc::authorize;
 use tonic::{Request, Response, Status};
 
 use crate::{AppState, database, models::token_claim::TokenClaim};
 
-use service::{VerifyTokenRequest, VerifyTokenResponse, auth_service_server::AuthService};
+use service::{
+    GetUserInfoRequest, GetUserInfoResponse, VerifyTokenRequest, VerifyTokenResponse,
+    auth_service_server::AuthService,
+};
 
 /// gRPC service for auth operations
 pub struct AuthServiceImpl {
@@ -30,7 +33,7 @@ impl AuthService for AuthServiceImpl {
     /// * `request` - gRPC request containing the token to verify
     ///
     /// # Returns
-    /// * `Ok(Response<VerifyTokenResponse>)` - Contains user_id if token is valid, error_reason otherwise
+    /// * `Ok(Response<VerifyTokenResponse>)` - Contains user_id and expires_at if token is valid, error_reason otherwise
     /// * `Err(Status)` - gRPC error if something went wrong
     async fn verify_token(
         &self,
@@ -40,14 +43,10 @@ impl AuthService for AuthServiceImpl {
         tracing::debug!("Received token verification request");
 
         // Try to decode and validate the JWT
-        let decoded_token = match decode::<TokenClaim>(
-            &token,
-            &DecodingKey::from_secret(self.state.config.jwt_secret.as_ref()),
-            &Validation::default(),
-        ) {
-            Ok(token) => {
-                tracing::debug!("JWT decoded successfully, subject: {}", token.claims.sub);
-                token
+        let claims = match self.state.get_jwt_keys().decode::<TokenClaim>(&token) {
+            Ok(claims) => {
+                tracing::debug!("JWT decoded successfully, subject: {}", claims.sub);
+                claims
             }
             Err(e) => {
                 tracing::warn!("Failed to decode JWT token: {}", e);
@@ -55,6 +54,7 @@ impl AuthService for AuthServiceImpl {
                 return Ok(Response::new(VerifyTokenResponse {
                     user_id: None,
                     error_reason: Some("TOKEN_INVALID".to_string()),
+                    expires_at: None,
                 }));
             }
         };
@@ -62,7 +62,7 @@ impl AuthService for AuthServiceImpl {
         // Check if token exists in database and is not expired
         let pool = self.state.get_database_pool();
 
-        let token_res = match database::tokens::find(&token, pool).await {
+        let token_res = match database::tokens::find_by_jti(claims.jti, pool).await {
             Ok(token) => {
                 tracing::debug!("Token found in database for user: {}", token.get_uuid());
                 token
@@ -73,6 +73,7 @@ impl AuthService for AuthServiceImpl {
                 return Ok(Response::new(VerifyTokenResponse {
                     user_id: None,
                     error_reason: Some("TOKEN_INVALID".to_string()),
+                    expires_at: None,
                 }));
             }
         };
@@ -84,34 +85,96 @@ impl AuthService for AuthServiceImpl {
                 token_res.get_uuid()
             );
             // Clean up expired token
-            if let Err(e) = database::tokens::delete_by_token(token_res.get_token(), pool).await {
+            if let Err(e) = database::tokens::delete_by_jti(token_res.get_jti(), pool).await {
                 tracing::error!("Failed to delete expired token from database: {:?}", e);
             }
             return Ok(Response::new(VerifyTokenResponse {
                 user_id: None,
                 error_reason: Some("TOKEN_EXPIRED".to_string()),
+                expires_at: None,
             }));
         }
 
         // Verify token belongs to the correct user
-        if token_res.get_uuid().to_string() != *decoded_token.claims.sub {
+        if token_res.get_uuid().to_string() != *claims.sub {
             tracing::warn!(
                 "Token user mismatch. Database: {}, JWT: {}",
                 token_res.get_uuid(),
-                decoded_token.claims.sub
+                claims.sub
             );
             return Ok(Response::new(VerifyTokenResponse {
                 user_id: None,
                 error_reason: Some("TOKEN_INVALID".to_string()),
+                expires_at: None,
             }));
         }
 
+        // Verify the user was not deactivated since the token was issued
+        match self.state.is_user_active(token_res.get_uuid()).await {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::warn!("Token of inactive user: {}", token_res.get_uuid());
+                return Ok(Response::new(VerifyTokenResponse {
+                    user_id: None,
+                    error_reason: Some("ACCOUNT_INACTIVE".to_string()),
+                    expires_at: None,
+                }));
+            }
+            Err(_) => {
+                tracing::error!(
+                    "Failed to check account status of user: {}",
+                    token_res.get_uuid()
+                );
+                return Err(Status::internal("Failed to check account status"));
+            }
+        }
+
         // Token is valid, return user ID
-        let user_id = decoded_token.claims.sub.to_string();
+        let user_id = claims.sub.to_string();
         tracing::info!("Token verified successfully for user: {}", user_id);
         Ok(Response::new(VerifyTokenResponse {
             user_id: Some(user_id),
             error_reason: None,
+            expires_at: Some(claims.exp as i64),
+        }))
+    }
+
+    /// Looks up a user by email so other services can reference the account
+    ///
+    /// Only internal callers holding the shared secret may look users up, so
+    /// the emails of accounts cannot be enumerated through the gRPC port.
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the email to look up
+    ///
+    /// # Returns
+    /// * `Ok(Response<GetUserInfoResponse>)` - Contains the user_id and username if an active account uses the email
+    /// * `Err(Status)` - `UNAUTHENTICATED` if the internal secret is missing or wrong
+    async fn get_user_info(
+        &self,
+        request: Request<GetUserInfoRequest>,
+    ) -> Result<Response<GetUserInfoResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)?;
+
+        let email = request.into_inner().email;
+        tracing::debug!("Received user info request");
+
+        let pool = self.state.get_database_pool();
+
+        let user = match database::users::filter_by_email(email.trim(), pool).await {
+            Ok(user) if user.is_account_active() => user,
+            _ => {
+                tracing::debug!("No active user found for user info request");
+                return Ok(Response::new(GetUserInfoResponse {
+                    user_id: None,
+                    username: None,
+                }));
+            }
+        };
+
+        Ok(Response::new(GetUserInfoResponse {
+            user_id: Some(user.get_uuid().to_string()),
+            username: Some(user.get_username()),
         }))
     }
 }
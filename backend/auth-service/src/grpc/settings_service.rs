@@ -0,0 +1,3 @@
+pub mod service {
+    tonic::include_proto!("settings_service");
+}
@@ -9,9 +9,14 @@ mod utils;
 pub use app_state::AppState;
 pub use config::Config;
 
-use crate::routes::make_app;
+use std::{sync::Arc, time::Duration};
+
+use crate::{routes::make_app, utils::account_purge::AccountPurger};
 use grpc::auth_service::{AuthServiceImpl, service::auth_service_server::AuthServiceServer};
 
+/// How often accounts deactivated longer than the grace period are purged
+const ACCOUNT_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing/logging
@@ -30,6 +35,11 @@ async fn main() {
     // Initialize configuration from environment variables
     let config = Config::init();
     tracing::info!("✅ Configuration loaded successfully");
+    if !config.rp_require_user_verification {
+        tracing::warn!(
+            "⚠️ RP_REQUIRE_USER_VERIFICATION is disabled; passkey logins will not be rejected when the UV flag is missing"
+        );
+    }
     tracing::debug!(
         "HTTP port: {}, gRPC port: {}",
         config.auth_http_port,
@@ -101,7 +111,16 @@ async fn main() {
         .expect("Could not connect to email service");
         tracing::info!("✅ Email service client connected");
 
-        let state = std::sync::Arc::new(AppState::new(grpc_config, db, email_service));
+        let state = Arc::new(AppState::new(grpc_config, db, email_service));
+
+        // Periodically delete deactivated accounts and their data in the other services
+        if state.config.account_purge_grace_days > 0 {
+            tokio::spawn(purge_deactivated_accounts(state.clone()));
+            tracing::info!(
+                "✅ Deactivated accounts kept for {} days",
+                state.config.account_purge_grace_days
+            );
+        }
 
         let auth_service = AuthServiceImpl::new(state);
         tracing::info!("✅ gRPC service initialized");
@@ -118,3 +137,30 @@ async fn main() {
     tracing::info!("✅ Both servers are running");
     tokio::try_join!(http_server, grpc_server).expect("Server error");
 }
+
+/// Deletes the accounts past their grace period once per purge interval
+///
+/// Failures are logged and retried on the next run.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+async fn purge_deactivated_accounts(state: Arc<AppState>) {
+    let purger = AccountPurger::new(&state.config);
+    let mut interval = tokio::time::interval(ACCOUNT_PURGE_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match purger
+            .purge(
+                state.config.account_purge_grace_days,
+                state.get_database_pool(),
+            )
+            .await
+        {
+            Ok(purged) => tracing::info!("Purged {} deactivated accounts", purged),
+            Err(_) => tracing::error!("Failed to purge deactivated accounts"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use crate::Config;
+use crate::{Config, utils::link::render_link};
 
 /// Represents an activation link stored in the database
 ///
@@ -62,8 +62,12 @@ impl NewActivationLink {
     // Get the activation link
     ///
     /// # Returns
-    /// * `String` - The activation link
+    /// * `String` - The activation link, built from `ACTIVATION_LINK_PATH`
     pub fn get_link(&self, config: &Config) -> String {
-        format!("{}/activate/{}", config.frontend_hostname, self.get_id())
+        render_link(
+            &config.frontend_hostname,
+            &config.activation_link_path,
+            &self.get_id().to_string(),
+        )
     }
 }
@@ -12,6 +12,25 @@ pub enum AuthMethod {
     Otp,
 }
 
+/// Enum representing why an authentication attempt failed
+///
+/// Serialized to a stable snake_case string stored in the audit log metadata,
+/// so failures can be aggregated reliably.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthFailureReason {
+    /// The supplied password did not match
+    InvalidPassword,
+    /// The account email has not been verified yet
+    AccountNotVerified,
+    /// The account has been deactivated
+    AccountInactive,
+    /// The passkey assertion could not be verified
+    PasskeyVerificationFailed,
+    /// The passkey assertion did not include user verification
+    UserVerificationRequired,
+}
+
 /// Represents an authentication audit log entry in the database
 ///
 /// This struct tracks authentication attempts for security and compliance purposes
@@ -46,6 +65,7 @@ pub struct AuthenticationAuditLogResponse {
     pub id: Uuid,
     pub auth_method: AuthMethod,
     pub success: bool,
+    pub failure_reason: Option<AuthFailureReason>,
     pub ip_address: Option<String>,
     pub user_agent: Option<String>,
     pub attempted_at: DateTime<Utc>,
@@ -53,13 +73,76 @@ pub struct AuthenticationAuditLogResponse {
 
 impl From<AuthenticationAuditLog> for AuthenticationAuditLogResponse {
     fn from(log: AuthenticationAuditLog) -> Self {
+        let failure_reason = log
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("reason"))
+            .and_then(|reason| serde_json::from_value(reason.clone()).ok());
+
         Self {
             id: log.id,
             auth_method: log.auth_method,
             success: log.success,
+            failure_reason,
             ip_address: log.ip_address,
             user_agent: log.user_agent,
             attempted_at: log.attempted_at,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audit_log(metadata: Option<serde_json::Value>) -> AuthenticationAuditLog {
+        AuthenticationAuditLog {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            auth_method: AuthMethod::Password,
+            success: false,
+            ip_address: None,
+            user_agent: None,
+            attempted_at: Utc::now(),
+            metadata,
+        }
+    }
+
+    #[test]
+    fn test_auth_failure_reason_serialization() {
+        let json = serde_json::to_string(&AuthFailureReason::InvalidPassword).unwrap();
+        assert_eq!(json, r#""invalid_password""#);
+
+        let json = serde_json::to_string(&AuthFailureReason::PasskeyVerificationFailed).unwrap();
+        assert_eq!(json, r#""passkey_verification_failed""#);
+    }
+
+    #[test]
+    fn test_auth_failure_reason_deserialization() {
+        let reason: AuthFailureReason = serde_json::from_str(r#""account_inactive""#).unwrap();
+        assert_eq!(reason, AuthFailureReason::AccountInactive);
+
+        assert!(serde_json::from_str::<AuthFailureReason>(r#""unknown_reason""#).is_err());
+    }
+
+    #[test]
+    fn test_response_failure_reason_from_metadata() {
+        let log = audit_log(Some(serde_json::json!({"reason": "account_not_verified"})));
+        let response: AuthenticationAuditLogResponse = log.into();
+
+        assert_eq!(
+            response.failure_reason,
+            Some(AuthFailureReason::AccountNotVerified)
+        );
+    }
+
+    #[test]
+    fn test_response_failure_reason_missing_or_unknown() {
+        let response: AuthenticationAuditLogResponse = audit_log(None).into();
+        assert_eq!(response.failure_reason, None);
+
+        let log = audit_log(Some(serde_json::json!({"reason": "something_else"})));
+        let response: AuthenticationAuditLogResponse = log.into();
+        assert_eq!(response.failure_reason, None);
+    }
+}
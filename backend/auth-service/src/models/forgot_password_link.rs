@@ -2,7 +2,7 @@ use chrono::{DateTime, Duration, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use crate::Config;
+use crate::{Config, utils::link::render_link};
 
 /// Represents an forgot password link stored in the database
 ///
@@ -79,12 +79,12 @@ impl NewForgotPasswordLink {
     // Get the forgot password link
     ///
     /// # Returns
-    /// * `String` - The forgot password link
+    /// * `String` - The forgot password link, built from `RESET_LINK_PATH`
     pub fn get_link(&self, config: &Config) -> String {
-        format!(
-            "{}/forgot-password/{}",
-            config.frontend_hostname,
-            self.get_id()
+        render_link(
+            &config.frontend_hostname,
+            &config.reset_link_path,
+            &self.get_id().to_string(),
         )
     }
 }
@@ -3,6 +3,8 @@ use serde::Serialize;
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::utils::passkey;
+
 /// Represents a passkey credential stored in the database
 ///
 /// This struct maps to the passkey_credentials table and contains WebAuthn credential data
@@ -67,23 +69,75 @@ pub struct NewPasskeyCredential {
 /// # Fields
 /// * `id` - Unique identifier for this credential record
 /// * `device_name` - User-friendly name for the device (optional)
+/// * `aaguid` - Authenticator attestation GUID (optional)
+/// * `authenticator_name` - Friendly name of the authenticator, if the AAGUID is known
 /// * `created_at` - When this credential was created
 /// * `last_used_at` - When this credential was last used for authentication
+/// * `is_active` - Whether this credential is active or has been revoked
 #[derive(Serialize)]
 pub struct PasskeyCredentialResponse {
     pub id: Uuid,
     pub device_name: Option<String>,
+    pub aaguid: Option<Uuid>,
+    pub authenticator_name: Option<String>,
     pub created_at: DateTime<Utc>,
     pub last_used_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
 }
 
 impl From<PasskeyCredential> for PasskeyCredentialResponse {
     fn from(credential: PasskeyCredential) -> Self {
+        let aaguid = credential
+            .aaguid
+            .as_deref()
+            .and_then(|bytes| Uuid::from_slice(bytes).ok());
+        let authenticator_name = aaguid
+            .as_ref()
+            .and_then(passkey::authenticator_name)
+            .map(str::to_string);
+
         Self {
             id: credential.id,
             device_name: credential.device_name,
+            aaguid,
+            authenticator_name,
             created_at: credential.created_at,
             last_used_at: credential.last_used_at,
+            is_active: credential.is_active,
         }
     }
 }
+
+/// Checks whether a user already has the maximum number of active passkeys
+///
+/// # Arguments
+/// * `active_count` - Number of active passkeys the user currently has
+/// * `max_passkeys` - Maximum number of active passkeys allowed
+///
+/// # Returns
+/// `true` if another passkey may not be added
+pub fn is_passkey_limit_reached(active_count: usize, max_passkeys: usize) -> bool {
+    active_count >= max_passkeys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_passkey_limit_reached_below_limit() {
+        assert!(!is_passkey_limit_reached(0, 20));
+        assert!(!is_passkey_limit_reached(19, 20));
+    }
+
+    #[test]
+    fn test_is_passkey_limit_reached_at_limit() {
+        assert!(is_passkey_limit_reached(20, 20));
+        assert!(is_passkey_limit_reached(21, 20));
+    }
+
+    #[test]
+    fn test_is_passkey_limit_reached_with_zero_limit() {
+        assert!(is_passkey_limit_reached(0, 0));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod change_password_info;
 pub mod forgot_password_info;
 pub mod login_info;
 pub mod passkey_login_info;
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+/// Represents the passwords required to change the password of a logged-in user
+///
+/// This struct is used to deserialize JSON data sent to the `/change-password/authenticated` endpoint
+///
+/// # Fields
+/// * `current_password` - The user's current password, proving they know it
+/// * `new_password` - The new password to set
+///
+/// # Example
+/// ```json
+/// {
+///     "current_password": "OldPassword123",
+///     "new_password": "NewPassword456"
+/// }
+/// ```
+#[derive(Deserialize)]
+pub struct ChangePasswordInfo {
+    pub current_password: String,
+    pub new_password: String,
+}
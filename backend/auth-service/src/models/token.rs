@@ -9,27 +9,27 @@ use crate::models::user::User;
 /// This struct maps to the tokens table and contains authentication token information
 ///
 /// # Fields
+/// * `id` - ID of the token, used as the `jti` claim of the JWT
 /// * `user_id` - ID of the user this token belongs to
-/// * `token` - The actual token string
 /// * `expires_at` - Timestamp when the token expires
 #[derive(FromRow, Clone)]
 pub struct Token {
+    id: Uuid,
     user_id: Uuid,
-    token: String,
     expires_at: DateTime<Utc>,
 }
 
 impl Token {
+    /// Returns the ID of the token, the `jti` claim of the JWT
+    pub fn get_jti(&self) -> Uuid {
+        self.id
+    }
+
     /// Returns the UUID of the user associated with this token
     pub fn get_uuid(&self) -> Uuid {
         self.user_id
     }
 
-    /// Returns the JWT
-    pub fn get_token(&self) -> &str {
-        &self.token
-    }
-
     /// Checks if the token has expired
     ///
     /// # Returns
@@ -47,11 +47,13 @@ impl Token {
 /// This struct is used for creating new token records
 ///
 /// # Fields
+/// * `id` - ID of the token, the `jti` claim of the JWT
 /// * `user_id` - ID of the user this token belongs to
 /// * `token` - The actual token string
 /// * `token_type` - Type of token
 /// * `expires_at` - When the token expires
 pub struct NewToken {
+    pub id: Uuid,
     pub user_id: Uuid,
     pub token: String,
     pub token_type: String,
@@ -62,6 +64,7 @@ impl NewToken {
     /// Creates a new token record
     ///
     /// # Arguments
+    /// * `jti` - The token ID from the JWT claims
     /// * `user` - Reference to the user the token belongs to
     /// * `token` - The token string
     /// * `tip` - Optional token type, defaults to empty string
@@ -69,8 +72,15 @@ impl NewToken {
     ///
     /// # Returns
     /// A new `NewToken` instance ready for database insertion
-    pub fn new(user: &User, token: &str, tip: Option<&str>, expiry: Option<DateTime<Utc>>) -> Self {
+    pub fn new(
+        jti: Uuid,
+        user: &User,
+        token: &str,
+        tip: Option<&str>,
+        expiry: Option<DateTime<Utc>>,
+    ) -> Self {
         Self {
+            id: jti,
             user_id: user.get_uuid(),
             token: token.into(),
             token_type: tip.unwrap_or_default().into(),
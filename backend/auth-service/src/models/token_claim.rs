@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
+use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// JWT claims structure used for token generation and validation
 ///
@@ -8,6 +10,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Fields
 /// * `sub` - Subject claim, typically contains user identifier
+/// * `iss` - Issuer claim, identifies the auth service that signed the token
+/// * `aud` - Audience claim, identifies the services the token is intended for
+/// * `jti` - Token ID claim, matches the id of the token row in the database
 /// * `iat` - Issued At timestamp (in seconds since Unix epoch)
 /// * `exp` - Expiration timestamp (in seconds since Unix epoch)
 ///
@@ -15,6 +20,9 @@ use serde::{Deserialize, Serialize};
 /// ```json
 /// {
 ///     "sub": "user123",
+///     "iss": "brewget-auth-service",
+///     "aud": "brewget",
+///     "jti": "550e8400-e29b-41d4-a716-446655440000",
 ///     "iat": 1692115200,
 ///     "exp": 1692118800
 /// }
@@ -22,6 +30,53 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize)]
 pub struct TokenClaim {
     pub sub: Arc<str>,
+    pub iss: Arc<str>,
+    pub aud: Arc<str>,
+    pub jti: Uuid,
     pub iat: usize,
     pub exp: usize,
 }
+
+impl TokenClaim {
+    /// Creates the claims of a new token with a random token ID
+    ///
+    /// # Arguments
+    /// * `sub` - The user identifier
+    /// * `iss` - The issuer of the token
+    /// * `aud` - The audience of the token
+    /// * `max_age` - How long the token is valid for
+    ///
+    /// # Returns
+    /// A new `TokenClaim` issued now
+    pub fn new(sub: &str, iss: &str, aud: &str, max_age: Duration) -> Self {
+        let now = Utc::now();
+
+        Self {
+            sub: sub.into(),
+            iss: iss.into(),
+            aud: aud.into(),
+            jti: Uuid::new_v4(),
+            iat: now.timestamp() as usize,
+            exp: (now + max_age).timestamp() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_token_claim() {
+        let claims = TokenClaim::new("user123", "issuer", "audience", Duration::seconds(60));
+
+        assert_eq!(&*claims.sub, "user123");
+        assert_eq!(&*claims.iss, "issuer");
+        assert_eq!(&*claims.aud, "audience");
+        assert_eq!(claims.exp - claims.iat, 60);
+        assert_ne!(
+            claims.jti,
+            TokenClaim::new("user123", "issuer", "audience", Duration::seconds(60)).jti
+        );
+    }
+}
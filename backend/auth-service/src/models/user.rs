@@ -1,7 +1,78 @@
+use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use crate::utils::password::{hash_password, verify_password};
+use crate::{
+    models::{authentication_audit_log::AuthFailureReason, response::TranslationKey},
+    utils::password::{hash_password, verify_password},
+};
+
+/// The state of a user account, derived from its verification and active flags
+///
+/// An account that is neither verified nor active is `Unverified`, as the
+/// email has to be verified before anything else about the account matters.
+///
+/// Serialized to a stable snake_case string, e.g. `"deactivated"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountStatus {
+    /// The account email has not been verified yet
+    Unverified,
+    /// The account is verified and can be used
+    Active,
+    /// The account has been deleted temporarily
+    Deactivated,
+}
+
+impl AccountStatus {
+    /// Derives the status from the flags stored for a user
+    ///
+    /// # Arguments
+    /// * `is_verified` - Whether the account email has been verified
+    /// * `is_active` - Whether the account has NOT been deleted temporarily
+    pub fn from_flags(is_verified: bool, is_active: bool) -> Self {
+        match (is_verified, is_active) {
+            (false, _) => AccountStatus::Unverified,
+            (true, true) => AccountStatus::Active,
+            (true, false) => AccountStatus::Deactivated,
+        }
+    }
+
+    /// Returns the status as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountStatus::Unverified => "unverified",
+            AccountStatus::Active => "active",
+            AccountStatus::Deactivated => "deactivated",
+        }
+    }
+
+    /// Returns why an account with this status cannot log in
+    ///
+    /// # Returns
+    /// * `None` if the account can log in
+    /// * `Some((reason, translation_key))` - The reason stored in the audit log and the error
+    ///   returned to the client otherwise
+    pub fn login_failure(&self) -> Option<(AuthFailureReason, TranslationKey)> {
+        match self {
+            AccountStatus::Unverified => Some((
+                AuthFailureReason::AccountNotVerified,
+                TranslationKey::EmailNotVerified,
+            )),
+            AccountStatus::Active => None,
+            AccountStatus::Deactivated => Some((
+                AuthFailureReason::AccountInactive,
+                TranslationKey::AccountDeletedTemporarily,
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
 /// Represents a user in the database
 ///
@@ -66,13 +137,12 @@ impl User {
         self.has_passkey
     }
 
-    /// Checks if the account email has been verified
+    /// Returns the state of the account
     ///
     /// # Returns
-    /// * `true` if the email is verified
-    /// * `false` if the email is NOT verified
-    pub fn is_account_verified(&self) -> bool {
-        self.is_verified
+    /// * `AccountStatus` - The status derived from the verification and active flags
+    pub fn status(&self) -> AccountStatus {
+        AccountStatus::from_flags(self.is_verified, self.is_active)
     }
 
     /// Checks if the account has been deleted temporarily
@@ -141,3 +211,54 @@ impl NewUser {
         self.password.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_status_from_flags() {
+        assert_eq!(
+            AccountStatus::from_flags(false, true),
+            AccountStatus::Unverified
+        );
+        assert_eq!(AccountStatus::from_flags(true, true), AccountStatus::Active);
+        assert_eq!(
+            AccountStatus::from_flags(true, false),
+            AccountStatus::Deactivated
+        );
+        // The email has to be verified first, even for a deleted account
+        assert_eq!(
+            AccountStatus::from_flags(false, false),
+            AccountStatus::Unverified
+        );
+    }
+
+    #[test]
+    fn test_account_status_login_failure() {
+        assert_eq!(AccountStatus::Active.login_failure(), None);
+        assert_eq!(
+            AccountStatus::Unverified.login_failure(),
+            Some((
+                AuthFailureReason::AccountNotVerified,
+                TranslationKey::EmailNotVerified
+            ))
+        );
+        assert_eq!(
+            AccountStatus::Deactivated.login_failure(),
+            Some((
+                AuthFailureReason::AccountInactive,
+                TranslationKey::AccountDeletedTemporarily
+            ))
+        );
+    }
+
+    #[test]
+    fn test_account_status_serializes_as_snake_case() {
+        assert_eq!(
+            serde_json::to_value(AccountStatus::Deactivated).unwrap(),
+            "deactivated"
+        );
+        assert_eq!(AccountStatus::Unverified.to_string(), "unverified");
+    }
+}
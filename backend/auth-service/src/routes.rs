@@ -14,22 +14,14 @@ mod verify;
 
 use std::sync::Arc;
 
-use axum::{
-    Router,
-    http::{
-        HeaderValue, Method,
-        header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
-    },
-};
+use axum::Router;
 use sqlx::postgres::PgPoolOptions;
-use tower_http::cors::CorsLayer;
 
 use crate::{
     AppState, Config, grpc::email_service::service::email_service_client::EmailServiceClient,
 };
 
 pub async fn make_app(config: Config) -> Result<Router, Box<dyn std::error::Error>> {
-    let cors = HeaderValue::from_str(&config.cors_url)?;
     let postgres_url = format!(
         "postgres://{}:{}@{}/{}",
         config.pg_username, config.pg_password, config.pg_url, config.pg_database
@@ -58,12 +50,6 @@ pub async fn make_app(config: Config) -> Result<Router, Box<dyn std::error::Erro
 
     let state = Arc::new(AppState::new(config, db, email_service));
 
-    let cors = CorsLayer::new()
-        .allow_origin(cors)
-        .allow_methods([Method::GET, Method::POST])
-        .allow_credentials(true)
-        .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE]);
-
     let router = Router::new()
         .nest("/health", health::get_router(state.clone()))
         .nest("/register", register::get_router(state.clone()))
@@ -86,7 +72,6 @@ pub async fn make_app(config: Config) -> Result<Router, Box<dyn std::error::Erro
         .nest("/passkey/login", passkey_login::get_router(state.clone()))
         .nest("/passkey/manage", passkey_manage::get_router(state.clone()))
         .nest("/audit", audit::get_router(state.clone()))
-        .with_state(state)
-        .layer(cors);
+        .with_state(state);
     Ok(router)
 }
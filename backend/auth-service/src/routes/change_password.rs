@@ -1,25 +1,48 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Extension, Json, Router, extract::State, http::StatusCode, middleware, response::IntoResponse,
+    routing::post,
+};
+use chrono::Utc;
+use uuid::Uuid;
 
 use crate::{
     AppState, database,
+    grpc::email_service::service::PasswordChangedRequest,
     models::{
-        request::reset_password_info::ResetPasswordInfo,
+        request::{
+            change_password_info::ChangePasswordInfo, reset_password_info::ResetPasswordInfo,
+        },
         response::{Error, TranslationKey, TranslationKeyMessage},
     },
-    utils::password::{hash_password, is_password_in_history, validate_password},
+    routes::middlewares::auth_guard::auth_guard,
+    utils::password::{
+        hash_password, is_password_history_enabled, is_password_in_history, validate_password,
+    },
 };
 
 /// Creates a router for the change password routes
+///
+/// # Routes
+/// - `POST /` - Change the password using a forgot password link
+/// - `POST /authenticated` - Change the password of the logged-in user (protected by auth middleware)
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
         .route("/", post(change_password_handler))
+        .route(
+            "/authenticated",
+            post(authenticated_change_password_handler)
+                .route_layer(middleware::from_fn_with_state(state.clone(), auth_guard)),
+        )
         .with_state(state)
 }
 
 /// Change password endpoint handler
 ///
+/// Once the new password is committed, the user is sent a notification email
+/// in the background; a failure to send it does not fail the request.
+///
 /// # Returns
 /// JSON response with translation key "PASSWORD_SUCCESSFULLY_CHANGED" if the password was changed.
 ///
@@ -50,41 +73,131 @@ async fn change_password_handler(
         return Err((StatusCode::BAD_REQUEST, TranslationKey::LinkIsExpired).into());
     }
 
+    update_password(&state, link.get_uuid(), &body.password).await?;
+
+    // Delete the forgot password link from the db
+    tracing::debug!("Deleting forgot password link: {}", body.id);
+    if database::forgot_password_links::delete(body.id, pool).await? != 1 {
+        tracing::error!("Failed to delete forgot password link: {}", body.id);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            TranslationKey::SomethingWentWrong,
+        )
+            .into());
+    }
+
+    tracing::info!(
+        "Password change successful for user_id: {}",
+        link.get_uuid()
+    );
+    notify_password_changed(state, link.get_uuid());
+
+    Ok(Json(TranslationKeyMessage {
+        translation_key: TranslationKey::PasswordSuccessfullyChanged,
+    }))
+}
+
+/// Authenticated change password endpoint handler
+///
+/// Lets a logged-in user change their password by proving they know the
+/// current one. The new password goes through the same validation and
+/// password history checks as a password reset.
+///
+/// # Returns
+/// JSON response with translation key "PASSWORD_SUCCESSFULLY_CHANGED" if the password was changed.
+/// * `400 Bad Request` - "CURRENT_PASSWORD_INCORRECT" if the current password does not match
+///
+/// # Example Response
+/// ```json
+/// {
+///     "translation_key": "PASSWORD_SUCCESSFULLY_CHANGED"
+/// }
+/// ```
+async fn authenticated_change_password_handler(
+    State(state): State<Arc<AppState>>,
+    Extension(user_uuid): Extension<String>,
+    Json(body): Json<ChangePasswordInfo>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "Authenticated password change request for user_id: {}",
+        user_uuid
+    );
+
+    let uuid = Uuid::from_str(&user_uuid)?;
+    let user = database::users::filter_by_uuid(uuid, state.get_database_pool()).await?;
+
+    // Passkey-only users have no current password and can never match
+    if !user.is_password_valid(&body.current_password) {
+        tracing::warn!("Incorrect current password for user_id: {}", user_uuid);
+        return Err((
+            StatusCode::BAD_REQUEST,
+            TranslationKey::CurrentPasswordIncorrect,
+        )
+            .into());
+    }
+
+    update_password(&state, uuid, &body.new_password).await?;
+
+    tracing::info!("Password change successful for user_id: {}", user_uuid);
+    notify_password_changed(state, uuid);
+
+    Ok(Json(TranslationKeyMessage {
+        translation_key: TranslationKey::PasswordSuccessfullyChanged,
+    }))
+}
+
+/// Validates a new password and stores it for a user
+///
+/// The password is rejected if it is invalid or was used recently, unless
+/// password history is disabled. The password update and the history
+/// insertion happen in a single transaction.
+///
+/// # Arguments
+/// * `state` - Shared application state
+/// * `user_id` - The UUID of the user whose password is changed
+/// * `password` - The new plain text password
+///
+/// # Returns
+/// * `Ok(())` - The password was changed
+/// * `Err(Error)` - Invalid or reused password, or database errors
+async fn update_password(state: &AppState, user_id: Uuid, password: &str) -> Result<(), Error> {
+    let pool = state.get_database_pool();
+
     // Check if the password is ok and hash it
-    validate_password(&body.password).map_err(|translation_key| -> Error {
+    validate_password(password).map_err(|translation_key| -> Error {
         tracing::warn!(
-            "Invalid password format for password change, link_id: {}, error: {:?}",
-            body.id,
+            "Invalid password format for password change, user_id: {}, error: {:?}",
+            user_id,
             translation_key
         );
         (StatusCode::BAD_REQUEST, translation_key).into()
     })?;
 
-    // Check if the password has been used in recent passwords
+    // Check if the password has been used in recent passwords, unless password history is disabled
     let password_history_limit = state.config.password_history_limit;
-    let recent_passwords = database::password_history::get_recent_passwords(
-        link.get_uuid(),
-        password_history_limit,
-        pool,
-    )
-    .await?;
-    let recent_hashes: Vec<String> = recent_passwords
-        .iter()
-        .map(|ph| ph.get_password_hash())
-        .collect();
-
-    if is_password_in_history(&body.password, &recent_hashes) {
-        tracing::warn!("Password reuse attempt for user_id: {}", link.get_uuid());
-        return Err((
-            StatusCode::BAD_REQUEST,
-            TranslationKey::PasswordCannotBeReused,
-        )
-            .into());
+    let password_history_enabled = is_password_history_enabled(password_history_limit);
+    if password_history_enabled {
+        let recent_passwords =
+            database::password_history::get_recent_passwords(user_id, password_history_limit, pool)
+                .await?;
+        let recent_hashes: Vec<String> = recent_passwords
+            .iter()
+            .map(|ph| ph.get_password_hash())
+            .collect();
+
+        if is_password_in_history(password, &recent_hashes) {
+            tracing::warn!("Password reuse attempt for user_id: {}", user_id);
+            return Err((
+                StatusCode::BAD_REQUEST,
+                TranslationKey::PasswordCannotBeReused,
+            )
+                .into());
+        }
     }
 
-    tracing::debug!("Hashing new password for user_id: {}", link.get_uuid());
-    let new_hashed_password = hash_password(&body.password).map_err(|_| -> Error {
-        tracing::error!("Failed to hash password for user_id: {}", link.get_uuid());
+    tracing::debug!("Hashing new password for user_id: {}", user_id);
+    let new_hashed_password = hash_password(password).map_err(|_| -> Error {
+        tracing::error!("Failed to hash password for user_id: {}", user_id);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             TranslationKey::SomethingWentWrong,
@@ -102,25 +215,26 @@ async fn change_password_handler(
     })?;
 
     // Change the password of the user
-    database::users::change_password(link.get_uuid(), new_hashed_password.clone(), &mut *tx)
-        .await?;
+    database::users::change_password(user_id, new_hashed_password.clone(), &mut *tx).await?;
 
-    // Store the new password in history
-    database::password_history::insert(link.get_uuid(), new_hashed_password, &mut *tx).await?;
+    if password_history_enabled {
+        // Store the new password in history
+        database::password_history::insert(user_id, new_hashed_password, &mut *tx).await?;
 
-    // Cleanup old password history entries beyond the limit
-    database::password_history::cleanup_old_passwords(
-        link.get_uuid(),
-        password_history_limit,
-        &mut *tx,
-    )
-    .await?;
+        // Cleanup old password history entries beyond the limit
+        database::password_history::cleanup_old_passwords(
+            user_id,
+            password_history_limit,
+            &mut *tx,
+        )
+        .await?;
+    }
 
     // Commit the transaction
     tx.commit().await.map_err(|_| -> Error {
         tracing::error!(
             "Failed to commit password change transaction for user_id: {}",
-            link.get_uuid()
+            user_id
         );
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -129,22 +243,44 @@ async fn change_password_handler(
             .into()
     })?;
 
-    // Delete the forgot password link from the db
-    tracing::debug!("Deleting forgot password link: {}", body.id);
-    if database::forgot_password_links::delete(body.id, pool).await? != 1 {
-        tracing::error!("Failed to delete forgot password link: {}", body.id);
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::SomethingWentWrong,
-        )
-            .into());
-    }
+    Ok(())
+}
 
-    tracing::info!(
-        "Password change successful for user_id: {}",
-        link.get_uuid()
-    );
-    Ok(Json(TranslationKeyMessage {
-        translation_key: TranslationKey::PasswordSuccessfullyChanged,
-    }))
+/// Sends the password changed email in the background
+///
+/// The password is already changed, so a failure is only logged.
+///
+/// # Arguments
+/// * `state` - Shared application state
+/// * `user_id` - The UUID of the user whose password was changed
+fn notify_password_changed(state: Arc<AppState>, user_id: Uuid) {
+    let changed_at = Utc::now();
+    tokio::spawn(async move {
+        match database::users::filter_by_uuid(user_id, state.get_database_pool()).await {
+            Ok(user) => {
+                let request = PasswordChangedRequest {
+                    username: user.get_username(),
+                    email: user.get_email(),
+                    changed_at: changed_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                    link: format!("{}/login", state.config.frontend_hostname),
+                };
+
+                if let Err(e) = state.send_password_changed(request).await {
+                    tracing::error!(
+                        "Failed to send password changed email for user_id {}: {}",
+                        user_id,
+                        e
+                    );
+                } else {
+                    tracing::info!("Password changed email sent for user_id: {}", user_id);
+                }
+            }
+            Err(_) => {
+                tracing::error!(
+                    "Failed to find user {} for the password changed email",
+                    user_id
+                );
+            }
+        }
+    });
 }
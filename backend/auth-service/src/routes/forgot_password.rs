@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
 
 use crate::{
     AppState, database,
@@ -10,6 +16,7 @@ use crate::{
         request::forgot_password_info::ForgotPasswordInfo,
         response::{Error, TranslationKey, TranslationKeyMessage},
     },
+    utils::language::email_language,
 };
 
 /// Creates a router for the forgot password routes
@@ -32,6 +39,7 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 /// ```
 async fn forgot_password_handler(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(body): Json<ForgotPasswordInfo>,
 ) -> Result<impl IntoResponse, Error> {
     tracing::info!("Forgot password request for email: {}", body.email);
@@ -54,6 +62,7 @@ async fn forgot_password_handler(
 
     // Clone necessary data for the async processing
     let email = body.email.clone();
+    let language = email_language(&headers).to_string();
     let state_clone = state.clone();
 
     tokio::spawn(async move {
@@ -71,6 +80,7 @@ async fn forgot_password_handler(
                     username: user.get_username(),
                     email: user.get_email(),
                     link: new_forgot_password_link.get_link(&state_clone.config),
+                    language,
                 };
 
                 if let Err(e) = state_clone.send_forgot_password(request).await {
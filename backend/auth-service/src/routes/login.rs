@@ -7,13 +7,12 @@ use axum::{
     response::IntoResponse,
     routing::post,
 };
-use chrono::{Duration, Utc};
-use jsonwebtoken::{EncodingKey, Header, encode};
+use chrono::Duration;
 
 use crate::{
     AppState, database,
     models::{
-        authentication_audit_log::AuthMethod,
+        authentication_audit_log::{AuthFailureReason, AuthMethod},
         request::login_info::LoginInfo,
         response::{Error, Token, TranslationKey},
         token::NewToken,
@@ -35,9 +34,10 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 ///
 /// # Flow
 /// 1. Validates username/password combination
-/// 2. Generates JWT token with user claims
-/// 3. Stores token in database
-/// 4. Returns token to client
+/// 2. Checks that the account status is active
+/// 3. Generates JWT token with user claims
+/// 4. Stores token in database
+/// 5. Returns token to client
 ///
 /// # Arguments
 /// * `state` - Application state containing config and DB connection
@@ -61,6 +61,18 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 ///     "token": "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."
 /// }
 /// ```
+///
+/// # Example Error Response
+///
+/// Accounts that are not verified or were deleted temporarily are rejected
+/// with their `AccountStatus` in the `status` param:
+///
+/// ```json
+/// {
+///     "translation_key": "EMAIL_NOT_VERIFIED",
+///     "params": { "status": "unverified" }
+/// }
+/// ```
 async fn login_handler(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
@@ -103,7 +115,7 @@ async fn login_handler(
             false,
             ip_address.clone(),
             user_agent.clone(),
-            Some("invalid_password"),
+            Some(AuthFailureReason::InvalidPassword),
             pool,
         )
         .await;
@@ -115,10 +127,12 @@ async fn login_handler(
             .into());
     }
 
-    // Check if user has activated his account
-    if !user.is_account_verified() {
+    // Only verified accounts that were not deleted temporarily can log in
+    let status = user.status();
+    if let Some((reason, translation_key)) = status.login_failure() {
         tracing::warn!(
-            "Unverified account login attempt for username: {}",
+            "Login attempt for {} account with username: {}",
+            status,
             body.username
         );
 
@@ -129,62 +143,29 @@ async fn login_handler(
             false,
             ip_address.clone(),
             user_agent.clone(),
-            Some("account_not_verified"),
+            Some(reason),
             pool,
         )
         .await;
 
-        return Err((StatusCode::UNAUTHORIZED, TranslationKey::EmailNotVerified).into());
+        return Err(Error::new(StatusCode::UNAUTHORIZED, translation_key)
+            .with_param("status", status.as_str()));
     }
 
-    // Check if the account is deleted temporarily
-    if !user.is_account_active() {
-        tracing::warn!(
-            "Inactive account login attempt for username: {}",
-            body.username
-        );
-
-        // Log failed authentication attempt
-        utils::audit::log_authentication_attempt(
-            user.get_uuid(),
-            AuthMethod::Password,
-            false,
-            ip_address.clone(),
-            user_agent.clone(),
-            Some("account_inactive"),
-            pool,
-        )
-        .await;
-
-        return Err((
-            StatusCode::UNAUTHORIZED,
-            TranslationKey::AccountDeletedTemporarily,
-        )
-            .into());
-    }
-
-    // Generate token timestamps
-    let now = Utc::now();
-    let iat = now.timestamp() as usize;
-    let exp = (now + Duration::seconds(state.config.jwt_max_age.into())).timestamp() as usize;
-
     // Create token claims
-    let claims = TokenClaim {
-        sub: user.get_uuid().to_string().into(),
-        exp,
-        iat,
-    };
+    let claims = TokenClaim::new(
+        &user.get_uuid().to_string(),
+        &state.config.jwt_issuer,
+        &state.config.jwt_audience,
+        Duration::seconds(state.config.jwt_max_age.into()),
+    );
 
     // Generate JWT token
     tracing::debug!("Generating JWT token for user: {}", user.get_uuid());
-    let token = encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(state.config.jwt_secret.as_ref()),
-    )?;
+    let token = state.get_jwt_keys().encode(&claims)?;
 
     // Store token into database
-    let new_token = NewToken::new(&user, &token, None, None);
+    let new_token = NewToken::new(claims.jti, &user, &token, None, None);
     database::tokens::insert(new_token, pool).await?;
 
     // Log successful authentication attempt
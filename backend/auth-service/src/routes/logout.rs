@@ -12,28 +12,33 @@ use axum::{
 use uuid::Uuid;
 
 /// Creates a router for the logout routes
+///
+/// # Routes
+///
+/// - `GET /` - Log out the current session (protected by auth middleware)
+/// - `GET /all` - Log out every session of the user (protected by auth middleware)
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
-        .route(
-            "/",
-            get(logout_handler)
-                .route_layer(middleware::from_fn_with_state(state.clone(), auth_guard)),
-        )
+        .route("/", get(logout_handler))
+        .route("/all", get(logout_all_handler))
+        .route_layer(middleware::from_fn_with_state(state.clone(), auth_guard))
         .with_state(state)
 }
 
 /// Handles user logout requests
 ///
-/// Invalidates user's JWT tokens by removing them from the database
+/// Invalidates the JWT token of the request by removing it from the database,
+/// so the user's other sessions stay logged in
 ///
 /// # Flow
-/// 1. Extracts user ID from request extensions (set by auth middleware)
-/// 2. Deletes all tokens associated with the user
+/// 1. Extracts the token ID from request extensions (set by auth middleware)
+/// 2. Deletes the token
 /// 3. Returns success message
 ///
 /// # Arguments
 /// * `state` - Application state containing DB connection
 /// * `user_uuid` - User ID from auth middleware
+/// * `jti` - Token ID from auth middleware
 ///
 /// # Returns
 /// * `Ok(Json<TranslationKeyMessage>)` - Success message on logout
@@ -48,17 +53,55 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 async fn logout_handler(
     State(state): State<Arc<AppState>>,
     Extension(user_uuid): Extension<String>,
+    Extension(jti): Extension<Uuid>,
 ) -> Result<impl IntoResponse, Error> {
     tracing::info!("Logout request for user_id: {}", user_uuid);
 
+    // Delete only the token of this session
+    let pool = state.get_database_pool();
+    tracing::debug!("Deleting token {} for user_id: {}", jti, user_uuid);
+    database::tokens::delete_by_jti(jti, pool).await?;
+
+    tracing::info!("Logout successful for user_id: {}", user_uuid);
+    // Return success message
+    Ok(Json(TranslationKeyMessage {
+        translation_key: TranslationKey::Ok,
+    }))
+}
+
+/// Handles requests to log out of every session
+///
+/// Invalidates all JWT tokens of the user by removing them from the database
+///
+/// # Flow
+/// 1. Extracts user ID from request extensions (set by auth middleware)
+/// 2. Deletes all tokens associated with the user
+/// 3. Returns success message
+///
+/// # Arguments
+/// * `state` - Application state containing DB connection
+/// * `user_uuid` - User ID from auth middleware
+///
+/// # Returns
+/// * `Ok(Json<TranslationKeyMessage>)` - Success message on logout
+/// * `Err(Error)` - Database errors
+async fn logout_all_handler(
+    State(state): State<Arc<AppState>>,
+    Extension(user_uuid): Extension<String>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("Logout all request for user_id: {}", user_uuid);
+
     // Delete all tokens for the user
     let pool = state.get_database_pool();
     let uuid = Uuid::from_str(&user_uuid)?;
     tracing::debug!("Deleting tokens for user_id: {}", user_uuid);
-    database::tokens::delete_by_uuid(uuid, pool).await?;
+    let deleted = database::tokens::delete_by_uuid(uuid, pool).await?;
 
-    tracing::info!("Logout successful for user_id: {}", user_uuid);
-    // Return success message
+    tracing::info!(
+        "Logout all successful for user_id: {}, {} sessions ended",
+        user_uuid,
+        deleted
+    );
     Ok(Json(TranslationKeyMessage {
         translation_key: TranslationKey::Ok,
     }))
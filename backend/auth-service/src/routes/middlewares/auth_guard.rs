@@ -6,7 +6,6 @@ use axum::{
     middleware::Next,
     response::IntoResponse,
 };
-use jsonwebtoken::{DecodingKey, Validation, decode};
 
 use crate::{
     AppState, database,
@@ -22,7 +21,8 @@ use crate::{
 /// 2. Decodes and validates the JWT
 /// 3. Checks if token exists in database and is not expired
 /// 4. Verifies token belongs to correct user
-/// 5. Adds user ID to request extensions
+/// 5. Verifies the user is still active and verified
+/// 6. Adds the user ID (`String`) and the token ID (`Uuid`) to request extensions
 ///
 /// # Arguments
 /// * `state` - Application state containing config and DB connection
@@ -39,6 +39,7 @@ use crate::{
 ///   - Token is invalid/expired
 ///   - Token not found in database
 ///   - Token user mismatch
+///   - User deactivated or unverified
 pub async fn auth_guard(
     State(state): State<Arc<AppState>>,
     mut req: Request,
@@ -53,30 +54,33 @@ pub async fn auth_guard(
         .ok_or((StatusCode::UNAUTHORIZED, TranslationKey::NotLoggedIn))?;
 
     // Decode and validate JWT token
-    let decoded_token = decode::<TokenClaim>(
-        received_token,
-        &DecodingKey::from_secret(state.config.jwt_secret.as_ref()),
-        &Validation::default(),
-    )
-    .map_err(|_| (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid))?;
+    let claims = state
+        .get_jwt_keys()
+        .decode::<TokenClaim>(received_token)
+        .map_err(|_| (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid))?;
 
     // Check if token exists in database
     let pool = state.get_database_pool();
-    let token_res = database::tokens::find(received_token, pool).await?;
+    let token_res = database::tokens::find_by_jti(claims.jti, pool).await?;
 
     // Verify token is not expired
     if token_res.is_expired() {
-        database::tokens::delete_by_token(token_res.get_token(), pool).await?;
+        database::tokens::delete_by_jti(token_res.get_jti(), pool).await?;
         return Err((StatusCode::UNAUTHORIZED, TranslationKey::TokenExpired).into());
     }
 
     // Verify token belongs to correct user
-    if token_res.get_uuid().to_string() != *decoded_token.claims.sub {
+    if token_res.get_uuid().to_string() != *claims.sub {
         return Err((StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid).into());
     }
 
-    // Add user ID to request extensions and continue
-    req.extensions_mut()
-        .insert(decoded_token.claims.sub.to_string());
+    // Verify the user was not deactivated since the token was issued
+    if !state.is_user_active(token_res.get_uuid()).await? {
+        return Err((StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid).into());
+    }
+
+    // Add user ID and token ID to request extensions and continue
+    req.extensions_mut().insert(claims.sub.to_string());
+    req.extensions_mut().insert(claims.jti);
     Ok(next.run(req).await)
 }
@@ -7,14 +7,13 @@ use axum::{
     response::IntoResponse,
     routing::post,
 };
-use chrono::{Duration, Utc};
-use jsonwebtoken::{EncodingKey, Header, encode};
+use chrono::Duration;
 use webauthn_rs::prelude::*;
 
 use crate::{
     AppState, database,
     models::{
-        authentication_audit_log::AuthMethod,
+        authentication_audit_log::{AuthFailureReason, AuthMethod},
         request::passkey_login_info::{
             PasskeyLoginFinishRequest, PasskeyLoginStartRequest, PasskeyLoginStartResponse,
         },
@@ -109,14 +108,7 @@ async fn passkey_login_start(
     }
 
     // Generate challenge
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     let (request_challenge_response, passkey_authentication) = webauthn
         .start_passkey_authentication(&passkeys)
@@ -164,7 +156,8 @@ async fn passkey_login_start(
 ///
 /// # Returns
 /// * `Ok(Json<Token>)` - JWT token for authenticated session
-/// * `Err(Error)` - Verification or database errors
+/// * `Err(Error)` - Verification or database errors, or a `FORBIDDEN` error with the
+///   `AccountStatus` in the `status` param if the account is not active
 async fn passkey_login_finish(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
@@ -188,14 +181,7 @@ async fn passkey_login_finish(
         })?;
 
     // Verify credential
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     // Parse the credential from JSON
     let credential: PublicKeyCredential =
@@ -226,7 +212,7 @@ async fn passkey_login_finish(
                     false,
                     ip_address.clone(),
                     user_agent.clone(),
-                    Some("passkey_verification_failed"),
+                    Some(AuthFailureReason::PasskeyVerificationFailed),
                     pool,
                 )
                 .await;
@@ -239,8 +225,13 @@ async fn passkey_login_finish(
             }
         };
 
-    // Check if account is verified
-    if !user.is_account_verified() {
+    // Enforce user verification (biometric/PIN) when required by the deployment
+    if state.config.rp_require_user_verification && !authentication_result.user_verified() {
+        tracing::error!(
+            "Passkey authentication without user verification for: {}",
+            body.username
+        );
+
         // Log failed authentication attempt
         utils::audit::log_authentication_attempt(
             user.get_uuid(),
@@ -248,16 +239,27 @@ async fn passkey_login_finish(
             false,
             ip_address.clone(),
             user_agent.clone(),
-            Some("account_not_verified"),
+            Some(AuthFailureReason::UserVerificationRequired),
             pool,
         )
         .await;
 
-        return Err((StatusCode::FORBIDDEN, TranslationKey::EmailNotVerified).into());
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            TranslationKey::PasskeyAuthenticationFailed,
+        )
+            .into());
     }
 
-    // Check if account is active
-    if !user.is_account_active() {
+    // Only verified accounts that were not deleted temporarily can log in
+    let status = user.status();
+    if let Some((reason, translation_key)) = status.login_failure() {
+        tracing::warn!(
+            "Passkey login attempt for {} account with username: {}",
+            status,
+            body.username
+        );
+
         // Log failed authentication attempt
         utils::audit::log_authentication_attempt(
             user.get_uuid(),
@@ -265,16 +267,13 @@ async fn passkey_login_finish(
             false,
             ip_address.clone(),
             user_agent.clone(),
-            Some("account_inactive"),
+            Some(reason),
             pool,
         )
         .await;
 
-        return Err((
-            StatusCode::FORBIDDEN,
-            TranslationKey::AccountDeletedTemporarily,
-        )
-            .into());
+        return Err(Error::new(StatusCode::FORBIDDEN, translation_key)
+            .with_param("status", status.as_str()));
     }
 
     // Update credential counter
@@ -298,24 +297,17 @@ async fn passkey_login_finish(
     .await?;
 
     // Generate JWT token
-    let now = Utc::now();
-    let iat = now.timestamp() as usize;
-    let exp = (now + Duration::seconds(state.config.jwt_max_age.into())).timestamp() as usize;
-
-    let claims = TokenClaim {
-        sub: user.get_uuid().to_string().into(),
-        exp,
-        iat,
-    };
-
-    let token = encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(state.config.jwt_secret.as_ref()),
-    )?;
+    let claims = TokenClaim::new(
+        &user.get_uuid().to_string(),
+        &state.config.jwt_issuer,
+        &state.config.jwt_audience,
+        Duration::seconds(state.config.jwt_max_age.into()),
+    );
+
+    let token = state.get_jwt_keys().encode(&claims)?;
 
     // Store token
-    let new_token = NewToken::new(&user, &token, None, None);
+    let new_token = NewToken::new(claims.jti, &user, &token, None, None);
     database::tokens::insert(new_token, pool).await?;
 
     // Log successful authentication attempt
@@ -2,27 +2,40 @@ use std::{str::FromStr, sync::Arc};
 
 use axum::{
     Extension, Json, Router,
-    extract::State,
-    http::StatusCode,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
 };
+use serde::Deserialize;
 use uuid::Uuid;
 use webauthn_rs::prelude::*;
 
 use crate::{
     AppState, database,
     models::{
-        passkey_credential::{NewPasskeyCredential, PasskeyCredentialResponse},
+        passkey_credential::{
+            NewPasskeyCredential, PasskeyCredential, PasskeyCredentialResponse,
+            is_passkey_limit_reached,
+        },
         request::passkey_register_info::{
             PasskeyRegisterFinishRequest, PasskeyRegisterStartResponse,
         },
         response::{Error, TranslationKey, TranslationKeyMessage},
     },
     routes::middlewares::auth_guard::auth_guard,
+    utils,
 };
 
+/// Query parameters for passkey listing
+#[derive(Debug, Deserialize)]
+pub struct ListPasskeysQuery {
+    /// Whether revoked passkeys should be included (default: false)
+    #[serde(default)]
+    include_revoked: bool,
+}
+
 /// Creates a router for the passkey management routes
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
@@ -36,7 +49,14 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 
 /// List all passkeys for the authenticated user
 ///
-/// This endpoint retrieves all active passkey credentials for the authenticated user.
+/// This endpoint retrieves the passkey credentials for the authenticated user,
+/// sorted by most recently used first. Only active credentials are returned
+/// unless `include_revoked=true` is passed.
+///
+/// # Arguments
+/// * `state` - Application state containing DB connection
+/// * `user_uuid` - Authenticated user's ID from middleware
+/// * `query` - Query parameters including optional `include_revoked` flag
 ///
 /// # Returns
 /// * `Ok(Json<Vec<PasskeyCredentialResponse>>)` - List of passkey credentials
@@ -44,17 +64,47 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 async fn list_passkeys(
     State(state): State<Arc<AppState>>,
     Extension(user_uuid): Extension<String>,
+    Query(query): Query<ListPasskeysQuery>,
 ) -> Result<impl IntoResponse, Error> {
     let user_id = Uuid::from_str(&user_uuid)?;
-    tracing::info!("Listing passkeys for user: {}", user_id);
+    tracing::info!(
+        "Listing passkeys for user: {} (include_revoked: {})",
+        user_id,
+        query.include_revoked
+    );
 
     let pool = state.get_database_pool();
-    let credentials = database::passkey_credentials::find_by_user_id(user_id, pool).await?;
+    let credentials = database::passkey_credentials::find_all_by_user_id(user_id, pool).await?;
 
-    let response: Vec<PasskeyCredentialResponse> =
-        credentials.into_iter().map(Into::into).collect();
+    Ok(Json(listed_passkeys(credentials, query.include_revoked)))
+}
 
-    Ok(Json(response))
+/// Selects and orders the passkeys shown to the user
+///
+/// Revoked passkeys are dropped unless requested. The most recently used
+/// passkeys come first, then the never used ones, newest first. Passkeys
+/// created at the same time are ordered by ID, so the order never changes
+/// between requests.
+///
+/// # Arguments
+/// * `credentials` - All passkey credentials of the user
+/// * `include_revoked` - Whether revoked passkeys are kept
+///
+/// # Returns
+/// The passkeys to list, in display order
+fn listed_passkeys(
+    mut credentials: Vec<PasskeyCredential>,
+    include_revoked: bool,
+) -> Vec<PasskeyCredentialResponse> {
+    credentials.retain(|credential| include_revoked || credential.is_active);
+    credentials.sort_by(|a, b| {
+        b.last_used_at
+            .cmp(&a.last_used_at)
+            .then_with(|| b.created_at.cmp(&a.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    credentials.into_iter().map(Into::into).collect()
 }
 
 /// Start adding a new passkey for an authenticated user
@@ -85,6 +135,11 @@ async fn add_passkey_start(
     let existing_credentials =
         database::passkey_credentials::find_by_user_id(user_id, pool).await?;
 
+    ensure_passkey_limit(
+        existing_credentials.len(),
+        state.config.max_passkeys_per_user,
+    )?;
+
     // Convert credentials to Passkey format
     let passkeys: Vec<Passkey> = existing_credentials
         .iter()
@@ -95,14 +150,7 @@ async fn add_passkey_start(
     let credentials: Vec<CredentialID> = passkeys.iter().map(|pk| pk.cred_id()).cloned().collect();
 
     // Generate WebAuthn challenge
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     let (creation_challenge_response, passkey_registration) = webauthn
         .start_passkey_registration(
@@ -139,6 +187,7 @@ async fn add_passkey_start(
 /// # Arguments
 /// * `state` - Application state containing config and DB connection
 /// * `user_uuid` - Authenticated user's ID from middleware
+/// * `headers` - HTTP headers from the request (used to capture the user agent)
 /// * `body` - JSON request body containing credential response
 ///
 /// # Returns
@@ -147,6 +196,7 @@ async fn add_passkey_start(
 async fn add_passkey_finish(
     State(state): State<Arc<AppState>>,
     Extension(user_uuid): Extension<String>,
+    headers: HeaderMap,
     Json(body): Json<PasskeyRegisterFinishRequest>,
 ) -> Result<impl IntoResponse, Error> {
     let user_id = Uuid::from_str(&user_uuid)?;
@@ -166,14 +216,7 @@ async fn add_passkey_finish(
             })?;
 
     // Verify credential
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     // Parse the credential from JSON
     let credential: RegisterPublicKeyCredential =
@@ -218,16 +261,25 @@ async fn add_passkey_finish(
             .into()
     })?;
 
+    let aaguid = utils::passkey::extract_aaguid(&credential.response.attestation_object)
+        .map(|aaguid| aaguid.as_bytes().to_vec());
+    let (_, user_agent) = utils::audit::extract_request_metadata(&headers);
+
     let new_credential = NewPasskeyCredential {
         user_id,
         credential_id: credential_id_bytes,
         public_key: public_key_json,
         counter: 0,
-        aaguid: None,
+        aaguid,
         device_name: body.device_name,
-        user_agent: None,
+        user_agent,
     };
 
+    // Re-check the limit in case passkeys were added since the challenge was issued
+    let active_count =
+        database::passkey_credentials::count_active_for_update(user_id, &mut tx).await?;
+    ensure_passkey_limit(active_count, state.config.max_passkeys_per_user)?;
+
     database::passkey_credentials::insert(new_credential, &mut tx).await?;
 
     tx.commit().await.map_err(|e| -> Error {
@@ -246,6 +298,24 @@ async fn add_passkey_finish(
     }))
 }
 
+/// Ensure the user has not reached the maximum number of active passkeys
+///
+/// # Arguments
+/// * `active_count` - Number of active passkeys the user currently has
+/// * `max_passkeys` - The configured maximum of active passkeys per user
+///
+/// # Returns
+/// * `Ok(())` - The user can add another passkey
+/// * `Err(Error)` - 409 `TooManyPasskeys` when the limit is reached
+fn ensure_passkey_limit(active_count: usize, max_passkeys: usize) -> Result<(), Error> {
+    if is_passkey_limit_reached(active_count, max_passkeys) {
+        tracing::warn!("Passkey limit of {} reached", max_passkeys);
+        return Err((StatusCode::CONFLICT, TranslationKey::TooManyPasskeys).into());
+    }
+
+    Ok(())
+}
+
 /// Remove a passkey for the authenticated user
 ///
 /// This endpoint deactivates a specific passkey credential.
@@ -275,3 +345,118 @@ async fn remove_passkey(
         translation_key: TranslationKey::PasskeyRemovedSuccessfully,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::*;
+
+    fn credential(
+        created_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+        is_active: bool,
+    ) -> PasskeyCredential {
+        PasskeyCredential {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            credential_id: vec![1, 2, 3],
+            public_key: vec![4, 5, 6],
+            counter: 0,
+            aaguid: None,
+            credential_device_type: None,
+            credential_backed_up: None,
+            device_name: None,
+            user_agent: None,
+            created_at,
+            last_used_at,
+            is_active,
+        }
+    }
+
+    fn ids(passkeys: &[PasskeyCredentialResponse]) -> Vec<Uuid> {
+        passkeys.iter().map(|passkey| passkey.id).collect()
+    }
+
+    #[test]
+    fn test_revoked_passkeys_hidden_by_default() {
+        let now = Utc::now();
+        let active = credential(now, None, true);
+        let revoked = credential(now - Duration::days(1), Some(now), false);
+
+        let listed = listed_passkeys(vec![active.clone(), revoked.clone()], false);
+        assert_eq!(ids(&listed), vec![active.id]);
+
+        let listed = listed_passkeys(vec![active.clone(), revoked.clone()], true);
+        assert_eq!(ids(&listed), vec![revoked.id, active.id]);
+        assert!(!listed[0].is_active);
+    }
+
+    #[test]
+    fn test_include_revoked_query_defaults_to_false() {
+        let query = Query::<ListPasskeysQuery>::try_from_uri(&"/list".parse().unwrap()).unwrap();
+        assert!(!query.include_revoked);
+
+        let query = Query::<ListPasskeysQuery>::try_from_uri(
+            &"/list?include_revoked=true".parse().unwrap(),
+        )
+        .unwrap();
+        assert!(query.include_revoked);
+    }
+
+    #[test]
+    fn test_passkeys_ordered_by_last_use_then_creation() {
+        let now = Utc::now();
+        let never_used_old = credential(now - Duration::days(3), None, true);
+        let never_used_new = credential(now - Duration::days(1), None, true);
+        let used_long_ago =
+            credential(now - Duration::days(5), Some(now - Duration::days(2)), true);
+        let used_recently = credential(now - Duration::days(4), Some(now), true);
+
+        let listed = listed_passkeys(
+            vec![
+                never_used_old.clone(),
+                used_long_ago.clone(),
+                never_used_new.clone(),
+                used_recently.clone(),
+            ],
+            false,
+        );
+
+        assert_eq!(
+            ids(&listed),
+            vec![
+                used_recently.id,
+                used_long_ago.id,
+                never_used_new.id,
+                never_used_old.id
+            ]
+        );
+    }
+
+    #[test]
+    fn test_passkey_order_is_stable_for_ties() {
+        let now = Utc::now();
+        let first = credential(now, None, true);
+        let second = credential(now, None, true);
+        let mut expected = vec![first.id, second.id];
+        expected.sort();
+
+        let listed = listed_passkeys(vec![first.clone(), second.clone()], false);
+        let reversed = listed_passkeys(vec![second, first], false);
+
+        assert_eq!(ids(&listed), expected);
+        assert_eq!(ids(&reversed), expected);
+    }
+
+    #[test]
+    fn test_ensure_passkey_limit_below_limit() {
+        assert!(ensure_passkey_limit(2, 3).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_passkey_limit_at_limit_is_conflict() {
+        let error = ensure_passkey_limit(3, 3).unwrap_err();
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+}
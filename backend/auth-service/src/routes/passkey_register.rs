@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
 use uuid::Uuid;
 use webauthn_rs::prelude::*;
 
@@ -68,9 +74,13 @@ async fn passkey_register_start(
         })?;
 
     // Validate inputs
-    if body.username.len() <= 3 {
-        return Err((StatusCode::BAD_REQUEST, TranslationKey::UsernameTooShort).into());
-    }
+    let username_min_length = state.config.username_min_length;
+    utils::validation::validate_username(&body.username, username_min_length).map_err(
+        |translation_key| -> Error {
+            Error::new(StatusCode::BAD_REQUEST, translation_key)
+                .with_param("min_length", username_min_length)
+        },
+    )?;
 
     if !email_address::EmailAddress::is_valid(&body.email) {
         return Err((StatusCode::BAD_REQUEST, TranslationKey::EmailAddressInvalid).into());
@@ -93,14 +103,7 @@ async fn passkey_register_start(
     let user_id = Uuid::new_v4();
 
     // Generate WebAuthn challenge
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     let (creation_challenge_response, passkey_registration) = webauthn
         .start_passkey_registration(
@@ -156,6 +159,8 @@ async fn passkey_register_start(
 ///
 /// # Arguments
 /// * `state` - Application state containing config and DB connection
+/// * `headers` - HTTP headers from the request (used to capture the user agent and pick the
+///   email language)
 /// * `body` - JSON request body containing credential response
 ///
 /// # Returns
@@ -163,6 +168,7 @@ async fn passkey_register_start(
 /// * `Err(Error)` - Validation, verification, or database errors
 async fn passkey_register_finish(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(body): Json<PasskeyRegisterFinishRequest>,
 ) -> Result<impl IntoResponse, Error> {
     tracing::info!("Passkey registration finish for user: {}", body.user_id);
@@ -192,14 +198,7 @@ async fn passkey_register_finish(
             })?;
 
     // Verify credential
-    let webauthn = state.config.build_webauthn().map_err(|e| -> Error {
-        tracing::error!("Failed to build WebAuthn: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-            .into()
-    })?;
+    let webauthn = state.get_webauthn();
 
     // Parse the credential from JSON
     let credential: RegisterPublicKeyCredential =
@@ -269,14 +268,18 @@ async fn passkey_register_finish(
             .into()
     })?;
 
+    let aaguid = utils::passkey::extract_aaguid(&credential.response.attestation_object)
+        .map(|aaguid| aaguid.as_bytes().to_vec());
+    let (_, user_agent) = utils::audit::extract_request_metadata(&headers);
+
     let new_credential = NewPasskeyCredential {
         user_id: body.user_id,
         credential_id: credential_id_bytes,
         public_key: public_key_json,
         counter: 0, // Initial counter is 0 for new passkeys
-        aaguid: None,
+        aaguid,
         device_name: body.device_name,
-        user_agent: None,
+        user_agent,
     };
 
     database::passkey_credentials::insert(new_credential, &mut tx).await?;
@@ -300,6 +303,7 @@ async fn passkey_register_finish(
         username: username.clone(),
         email: email.clone(),
         link,
+        language: utils::language::email_language(&headers).to_string(),
     };
 
     state
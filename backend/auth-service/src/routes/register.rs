@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
 
 use crate::{
     AppState, database,
@@ -11,7 +17,11 @@ use crate::{
         response::{Error, TranslationKey, TranslationKeyMessage},
         user::NewUser,
     },
-    utils::password::validate_password,
+    utils::{
+        language::email_language,
+        password::{is_password_history_enabled, validate_password},
+        validation::validate_username,
+    },
 };
 
 /// Creates a router for the register routes
@@ -35,6 +45,7 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 ///
 /// # Arguments
 /// * `state` - Application state containing config and DB connection
+/// * `headers` - HTTP headers from the request (used to pick the email language)
 /// * `body` - JSON request body containing registration information
 ///
 /// # Returns
@@ -58,6 +69,7 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 /// ```
 async fn register_handler(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(body): Json<RegisterInfo>,
 ) -> Result<impl IntoResponse, Error> {
     tracing::info!(
@@ -82,11 +94,17 @@ async fn register_handler(
                 .into()
         })?;
 
-    // Validate username length
-    if body.username.len() <= 3 {
-        tracing::warn!("Username too short for registration: {}", body.username);
-        return Err((StatusCode::BAD_REQUEST, TranslationKey::UsernameTooShort).into());
-    }
+    // Validate username, it needs at least `username_min_length` allowed characters
+    let username_min_length = state.config.username_min_length;
+    validate_username(&body.username, username_min_length).map_err(|translation_key| -> Error {
+        tracing::warn!(
+            "Invalid username for registration: {}, error: {:?}",
+            body.username,
+            translation_key
+        );
+        Error::new(StatusCode::BAD_REQUEST, translation_key)
+            .with_param("min_length", username_min_length)
+    })?;
 
     // Validate password length
     validate_password(&body.password).map_err(|translation_key| -> Error {
@@ -155,8 +173,10 @@ async fn register_handler(
     database::users::insert(new_user, &mut *tx).await?;
     database::activation_links::insert(new_activation_link, &mut *tx).await?;
 
-    // Store initial password in history
-    database::password_history::insert(user_uuid, password_hash, &mut *tx).await?;
+    // Store initial password in history, unless password history is disabled
+    if is_password_history_enabled(state.config.password_history_limit) {
+        database::password_history::insert(user_uuid, password_hash, &mut *tx).await?;
+    }
 
     // Commit the transaction
     tx.commit().await.map_err(|_| -> Error {
@@ -177,6 +197,7 @@ async fn register_handler(
         username: body.username.clone(),
         email: body.email.clone(),
         link,
+        language: email_language(&headers).to_string(),
     };
     if let Err(status) = state.send_activate_account(request).await {
         tracing::error!(
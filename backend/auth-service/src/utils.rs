@@ -1,3 +1,9 @@
+pub mod account_purge;
 pub mod audit;
 pub mod captcha;
+pub mod jwt;
+pub mod language;
+pub mod link;
+pub mod passkey;
 pub mod password;
+pub mod validation;
@@ -0,0 +1,475 @@
+use std::{future::Future, time::Duration};
+
+use shared_types::grpc::INTERNAL_SECRET_METADATA_KEY;
+use sqlx::PgPool;
+use tonic::{
+    Code, Request, Status,
+    transport::{Channel, Endpoint},
+};
+use uuid::Uuid;
+
+use crate::{
+    Config, database,
+    grpc::{
+        settings_service::service::{
+            DeleteUserDataRequest as DeleteSettingsRequest,
+            settings_service_client::SettingsServiceClient,
+        },
+        transaction_service::service::{
+            DeleteUserDataRequest as DeleteWalletsRequest,
+            transaction_service_client::TransactionServiceClient,
+        },
+    },
+    models::response::Error,
+};
+
+/// How many times a service is called before the purge of an account is put
+/// off to the next sweep
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry, doubled for every further retry
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// How long the other services get to answer a call
+const GRPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Deletes deactivated accounts and their data in the other services
+///
+/// The data in the settings and transaction services is deleted first, so an
+/// account is only removed once nothing is left behind that points to it.
+///
+/// # Fields
+/// * `settings_service` - Client of the settings service
+/// * `transaction_service` - Client of the transaction service
+/// * `internal_grpc_secret` - Shared secret sent with every call
+/// * `retry_delay` - Delay before the first retry of a failed call
+pub struct AccountPurger {
+    settings_service: SettingsServiceClient<Channel>,
+    transaction_service: TransactionServiceClient<Channel>,
+    internal_grpc_secret: String,
+    retry_delay: Duration,
+}
+
+impl AccountPurger {
+    /// Creates a purger calling the services of the configuration
+    ///
+    /// The services are connected lazily, so they do not have to be running
+    /// when the auth service starts.
+    ///
+    /// # Panics
+    /// Panics if a service URL is invalid
+    pub fn new(config: &Config) -> Self {
+        Self::with_channels(
+            lazy_channel(format!(
+                "{}:{}",
+                config.settings_hostname, config.settings_grpc_port
+            )),
+            lazy_channel(format!(
+                "{}:{}",
+                config.transaction_hostname, config.transaction_grpc_port
+            )),
+            config.internal_grpc_secret.clone(),
+            INITIAL_RETRY_DELAY,
+        )
+    }
+
+    fn with_channels(
+        settings_channel: Channel,
+        transaction_channel: Channel,
+        internal_grpc_secret: String,
+        retry_delay: Duration,
+    ) -> Self {
+        Self {
+            settings_service: SettingsServiceClient::new(settings_channel),
+            transaction_service: TransactionServiceClient::new(transaction_channel),
+            internal_grpc_secret,
+            retry_delay,
+        }
+    }
+
+    /// Deletes the accounts deactivated longer than the grace period ago
+    ///
+    /// Accounts whose data could not be deleted are kept and tried again on
+    /// the next run.
+    ///
+    /// # Arguments
+    /// * `grace_days` - Days a deactivated account is kept
+    /// * `pool` - Database connection pool
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - Number of accounts deleted
+    /// * `Err(Error)` - Database operation error
+    pub async fn purge(&self, grace_days: u32, pool: &PgPool) -> Result<usize, Error> {
+        let mut purged = 0;
+
+        for user_id in database::users::find_deactivated_before(grace_days, pool).await? {
+            if let Err(status) = self.delete_user_data(user_id).await {
+                tracing::error!(
+                    "Failed to delete the data of user {}: {}",
+                    user_id,
+                    status.message()
+                );
+                continue;
+            }
+
+            if database::users::delete_deactivated(user_id, pool).await? {
+                tracing::info!("Purged deactivated user {}", user_id);
+                purged += 1;
+            }
+        }
+
+        Ok(purged)
+    }
+
+    /// Deletes the settings and wallets of a user in the other services
+    ///
+    /// Transient failures are retried with an exponential backoff, every
+    /// other failure stops the deletion right away.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user whose data to delete
+    ///
+    /// # Returns
+    /// * `Ok(())` - If both services deleted the user's data
+    /// * `Err(Status)` - The last failure of the service that could not delete it
+    pub async fn delete_user_data(&self, user_id: Uuid) -> Result<(), Status> {
+        with_retries(MAX_ATTEMPTS, self.retry_delay, || {
+            let mut client = self.settings_service.clone();
+            let request = self.request(DeleteSettingsRequest {
+                user_id: user_id.to_string(),
+            });
+
+            async move { client.delete_user_data(request?).await }
+        })
+        .await?;
+
+        with_retries(MAX_ATTEMPTS, self.retry_delay, || {
+            let mut client = self.transaction_service.clone();
+            let request = self.request(DeleteWalletsRequest {
+                user_id: user_id.to_string(),
+            });
+
+            async move { client.delete_user_data(request?).await }
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Wraps a message in a request carrying the internal secret
+    fn request<T>(&self, message: T) -> Result<Request<T>, Status> {
+        let secret = self
+            .internal_grpc_secret
+            .parse()
+            .map_err(|_| Status::internal("Invalid internal gRPC secret"))?;
+
+        let mut request = Request::new(message);
+        request
+            .metadata_mut()
+            .insert(INTERNAL_SECRET_METADATA_KEY, secret);
+
+        Ok(request)
+    }
+}
+
+/// Creates a channel that connects on its first call
+///
+/// # Panics
+/// Panics if the URL is invalid
+fn lazy_channel(url: String) -> Channel {
+    Endpoint::from_shared(url)
+        .expect("Invalid service URL")
+        .connect_timeout(GRPC_TIMEOUT)
+        .timeout(GRPC_TIMEOUT)
+        .connect_lazy()
+}
+
+/// Calls a service until it succeeds, fails permanently or runs out of attempts
+///
+/// # Arguments
+/// * `max_attempts` - Maximum number of calls
+/// * `initial_delay` - Delay before the first retry, doubled for every further retry
+/// * `call` - Makes one call to the service
+///
+/// # Returns
+/// The result of the last call
+async fn with_retries<T, F, Fut>(
+    max_attempts: u32,
+    initial_delay: Duration,
+    mut call: F,
+) -> Result<T, Status>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Status>>,
+{
+    let mut delay = initial_delay;
+    let mut attempt = 1;
+
+    loop {
+        match call().await {
+            Err(status) if attempt < max_attempts && is_transient(&status) => {
+                tracing::warn!(
+                    "Attempt {} failed: {}, retrying in {:?}",
+                    attempt,
+                    status.message(),
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Checks whether a failed call may succeed when retried
+///
+/// Timed out calls are cancelled by the client, so `CANCELLED` is retried too.
+fn is_transient(status: &Status) -> bool {
+    matches!(
+        status.code(),
+        Code::Unavailable | Code::DeadlineExceeded | Code::Cancelled | Code::Aborted
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashSet, VecDeque},
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicU32, Ordering},
+        },
+    };
+
+    use shared_types::grpc::authorize;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::{Response, metadata::MetadataMap, transport::Server};
+
+    use super::*;
+    use crate::grpc::{
+        settings_service::service::{
+            DeleteUserDataResponse as DeleteSettingsResponse, GetPushTokensRequest,
+            GetPushTokensResponse, GetUnsubscribeTokenRequest, GetUnsubscribeTokenResponse,
+            GetUserSettingsRequest, GetUserSettingsResponse,
+            settings_service_server::{SettingsService, SettingsServiceServer},
+        },
+        transaction_service::service::{
+            DeleteUserDataResponse as DeleteWalletsResponse, GetWalletBalancesRequest,
+            GetWalletBalancesResponse,
+            transaction_service_server::{TransactionService, TransactionServiceServer},
+        },
+    };
+
+    const SECRET: &str = "secret";
+
+    /// Data of a mock service and the failures it answers with first
+    #[derive(Default)]
+    struct MockData {
+        users: Mutex<HashSet<String>>,
+        failures: Mutex<VecDeque<Code>>,
+        calls: AtomicU32,
+    }
+
+    impl MockData {
+        fn new(user_id: Uuid, failures: &[Code]) -> Arc<Self> {
+            Arc::new(Self {
+                users: Mutex::new(HashSet::from([user_id.to_string()])),
+                failures: Mutex::new(failures.iter().copied().collect()),
+                calls: AtomicU32::new(0),
+            })
+        }
+
+        fn delete(&self, metadata: &MetadataMap, user_id: &str) -> Result<bool, Status> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            authorize(metadata, SECRET)?;
+
+            if let Some(code) = self.failures.lock().unwrap().pop_front() {
+                return Err(Status::new(code, "mock failure"));
+            }
+
+            Ok(self.users.lock().unwrap().remove(user_id))
+        }
+
+        fn has_user(&self, user_id: Uuid) -> bool {
+            self.users.lock().unwrap().contains(&user_id.to_string())
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    struct MockSettingsService(Arc<MockData>);
+
+    #[tonic::async_trait]
+    impl SettingsService for MockSettingsService {
+        async fn get_user_settings(
+            &self,
+            _request: Request<GetUserSettingsRequest>,
+        ) -> Result<Response<GetUserSettingsResponse>, Status> {
+            Err(Status::unimplemented("not mocked"))
+        }
+
+        async fn delete_user_data(
+            &self,
+            request: Request<DeleteSettingsRequest>,
+        ) -> Result<Response<DeleteSettingsResponse>, Status> {
+            let deleted = self
+                .0
+                .delete(request.metadata(), &request.get_ref().user_id)?;
+
+            Ok(Response::new(DeleteSettingsResponse { deleted }))
+        }
+
+        async fn get_push_tokens(
+            &self,
+            _request: Request<GetPushTokensRequest>,
+        ) -> Result<Response<GetPushTokensResponse>, Status> {
+            Err(Status::unimplemented("not mocked"))
+        }
+
+        async fn get_unsubscribe_token(
+            &self,
+            _request: Request<GetUnsubscribeTokenRequest>,
+        ) -> Result<Response<GetUnsubscribeTokenResponse>, Status> {
+            Err(Status::unimplemented("not mocked"))
+        }
+    }
+
+    struct MockTransactionService(Arc<MockData>);
+
+    #[tonic::async_trait]
+    impl TransactionService for MockTransactionService {
+        async fn get_wallet_balances(
+            &self,
+            _request: Request<GetWalletBalancesRequest>,
+        ) -> Result<Response<GetWalletBalancesResponse>, Status> {
+            Err(Status::unimplemented("not mocked"))
+        }
+
+        async fn delete_user_data(
+            &self,
+            request: Request<DeleteWalletsRequest>,
+        ) -> Result<Response<DeleteWalletsResponse>, Status> {
+            let deleted = self
+                .0
+                .delete(request.metadata(), &request.get_ref().user_id)?;
+
+            Ok(Response::new(DeleteWalletsResponse {
+                deleted_wallets: deleted as u64,
+            }))
+        }
+    }
+
+    /// Starts mock settings and transaction services and a purger calling them
+    async fn purger(settings: Arc<MockData>, transactions: Arc<MockData>) -> AccountPurger {
+        let settings_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let settings_url = format!("http://{}", settings_listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(SettingsServiceServer::new(MockSettingsService(settings)))
+                .serve_with_incoming(TcpListenerStream::new(settings_listener)),
+        );
+
+        let transaction_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let transaction_url = format!("http://{}", transaction_listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(TransactionServiceServer::new(MockTransactionService(
+                    transactions,
+                )))
+                .serve_with_incoming(TcpListenerStream::new(transaction_listener)),
+        );
+
+        AccountPurger::with_channels(
+            lazy_channel(settings_url),
+            lazy_channel(transaction_url),
+            SECRET.into(),
+            Duration::ZERO,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_data_removes_data_from_both_services() {
+        let user_id = Uuid::new_v4();
+        let settings = MockData::new(user_id, &[]);
+        let transactions = MockData::new(user_id, &[]);
+        let purger = purger(settings.clone(), transactions.clone()).await;
+
+        purger.delete_user_data(user_id).await.unwrap();
+
+        assert!(!settings.has_user(user_id));
+        assert!(!transactions.has_user(user_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_data_retries_after_transient_failure() {
+        let user_id = Uuid::new_v4();
+        let settings = MockData::new(user_id, &[Code::Unavailable]);
+        let transactions = MockData::new(user_id, &[Code::DeadlineExceeded]);
+        let purger = purger(settings.clone(), transactions.clone()).await;
+
+        purger.delete_user_data(user_id).await.unwrap();
+
+        assert_eq!(settings.calls(), 2);
+        assert_eq!(transactions.calls(), 2);
+        assert!(!settings.has_user(user_id));
+        assert!(!transactions.has_user(user_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_data_stops_on_permanent_failure() {
+        let user_id = Uuid::new_v4();
+        let settings = MockData::new(user_id, &[Code::Internal]);
+        let transactions = MockData::new(user_id, &[]);
+        let purger = purger(settings.clone(), transactions.clone()).await;
+
+        let status = purger.delete_user_data(user_id).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::Internal);
+        assert_eq!(settings.calls(), 1);
+        assert_eq!(transactions.calls(), 0);
+        assert!(transactions.has_user(user_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_data_with_wrong_secret_is_not_retried() {
+        let user_id = Uuid::new_v4();
+        let settings = MockData::new(user_id, &[]);
+        let transactions = MockData::new(user_id, &[]);
+        let mut purger = purger(settings.clone(), transactions).await;
+        purger.internal_grpc_secret = "wrong".into();
+
+        let status = purger.delete_user_data(user_id).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(settings.calls(), 1);
+        assert!(settings.has_user(user_id));
+    }
+
+    #[tokio::test]
+    async fn test_with_retries_gives_up_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), Status> = with_retries(3, Duration::ZERO, || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(Status::unavailable("down")) }
+        })
+        .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&Status::unavailable("")));
+        assert!(is_transient(&Status::deadline_exceeded("")));
+        assert!(is_transient(&Status::cancelled("")));
+        assert!(!is_transient(&Status::internal("")));
+        assert!(!is_transient(&Status::invalid_argument("")));
+        assert!(!is_transient(&Status::unauthenticated("")));
+    }
+}
@@ -2,7 +2,10 @@ use axum::http::HeaderMap;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::{database, models::authentication_audit_log::AuthMethod};
+use crate::{
+    database,
+    models::authentication_audit_log::{AuthFailureReason, AuthMethod},
+};
 
 /// Extracts request metadata from HTTP headers
 ///
@@ -36,7 +39,7 @@ pub fn extract_request_metadata(headers: &HeaderMap) -> (Option<String>, Option<
 /// * `success` - Whether the authentication was successful
 /// * `ip_address` - Optional IP address of the request
 /// * `user_agent` - Optional user agent string
-/// * `reason` - Optional reason for failure (e.g., "invalid_password", "account_not_verified")
+/// * `reason` - Optional reason for failure, stored as a stable string in the metadata
 /// * `pool` - Database connection pool
 pub async fn log_authentication_attempt(
     user_id: Uuid,
@@ -44,7 +47,7 @@ pub async fn log_authentication_attempt(
     success: bool,
     ip_address: Option<String>,
     user_agent: Option<String>,
-    reason: Option<&str>,
+    reason: Option<AuthFailureReason>,
     pool: &PgPool,
 ) {
     let metadata = reason.map(|r| serde_json::json!({"reason": r}));
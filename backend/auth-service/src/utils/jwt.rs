@@ -0,0 +1,190 @@
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode, errors::Result,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Keys used to sign and verify JWTs
+///
+/// Built once at startup from the configured algorithm, see `Config::build_jwt_keys`.
+///
+/// # Fields
+/// * `algorithm` - The signing algorithm
+/// * `encoding_key` - Key used to sign tokens (shared secret or RSA private key)
+/// * `decoding_key` - Key used to verify tokens (shared secret or RSA public key)
+/// * `validation` - Rules tokens must pass, only the signing algorithm is accepted
+pub struct JwtKeys {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtKeys {
+    /// Creates HS256 keys from a shared secret
+    ///
+    /// # Arguments
+    /// * `secret` - The secret used both for signing and verifying
+    ///
+    /// # Returns
+    /// * `JwtKeys` - Keys for the HS256 algorithm
+    pub fn hs256(secret: &[u8]) -> Self {
+        Self {
+            algorithm: Algorithm::HS256,
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            validation: Validation::new(Algorithm::HS256),
+        }
+    }
+
+    /// Creates RS256 keys from a PEM encoded RSA key pair
+    ///
+    /// # Arguments
+    /// * `private_key_pem` - The RSA private key used for signing
+    /// * `public_key_pem` - The RSA public key used for verifying
+    ///
+    /// # Returns
+    /// * `Ok(JwtKeys)` - Keys for the RS256 algorithm
+    /// * `Err(Error)` - If either key is not a valid PEM encoded RSA key
+    pub fn rs256(private_key_pem: &[u8], public_key_pem: &[u8]) -> Result<Self> {
+        Ok(Self {
+            algorithm: Algorithm::RS256,
+            encoding_key: EncodingKey::from_rsa_pem(private_key_pem)?,
+            decoding_key: DecodingKey::from_rsa_pem(public_key_pem)?,
+            validation: Validation::new(Algorithm::RS256),
+        })
+    }
+
+    /// Requires verified tokens to carry the given `iss` and `aud` claims
+    ///
+    /// # Arguments
+    /// * `issuer` - The expected issuer
+    /// * `audience` - The expected audience
+    ///
+    /// # Returns
+    /// * `JwtKeys` - The keys with the stricter validation
+    pub fn with_issuer_and_audience(mut self, issuer: &str, audience: &str) -> Self {
+        self.validation.set_issuer(&[issuer]);
+        self.validation.set_audience(&[audience]);
+        self.validation
+            .set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        self
+    }
+
+    /// Signs the claims into a JWT
+    ///
+    /// # Arguments
+    /// * `claims` - The claims to put in the token payload
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The signed token
+    /// * `Err(Error)` - If the claims cannot be serialized or signed
+    pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String> {
+        encode(&Header::new(self.algorithm), claims, &self.encoding_key)
+    }
+
+    /// Verifies a JWT and returns its claims
+    ///
+    /// Tokens signed with any other algorithm than the configured one, or with
+    /// another issuer or audience than configured, are rejected.
+    ///
+    /// # Arguments
+    /// * `token` - The token to verify
+    ///
+    /// # Returns
+    /// * `Ok(T)` - The claims of the token
+    /// * `Err(Error)` - If the signature, algorithm or expiry is invalid
+    pub fn decode<T: DeserializeOwned>(&self, token: &str) -> Result<T> {
+        decode::<T>(token, &self.decoding_key, &self.validation).map(|data| data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use chrono::Duration;
+    use rsa::{
+        RsaPrivateKey,
+        pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding},
+        rand_core::OsRng,
+    };
+
+    use super::*;
+    use crate::models::token_claim::TokenClaim;
+
+    /// RSA key pair generated once per test run, as PEM encoded private and public keys
+    fn test_rsa_keys() -> &'static (String, String) {
+        static KEYS: OnceLock<(String, String)> = OnceLock::new();
+
+        KEYS.get_or_init(|| {
+            let private_key = RsaPrivateKey::new(&mut OsRng, 2048).unwrap();
+            let private_pem = private_key.to_pkcs8_pem(LineEnding::LF).unwrap();
+            let public_pem = private_key
+                .to_public_key()
+                .to_public_key_pem(LineEnding::LF)
+                .unwrap();
+
+            (private_pem.to_string(), public_pem)
+        })
+    }
+
+    fn rs256_keys() -> JwtKeys {
+        let (private_pem, public_pem) = test_rsa_keys();
+
+        JwtKeys::rs256(private_pem.as_bytes(), public_pem.as_bytes()).unwrap()
+    }
+
+    fn claims() -> TokenClaim {
+        TokenClaim::new("user123", "issuer", "audience", Duration::minutes(5))
+    }
+
+    #[test]
+    fn test_hs256_roundtrip() {
+        let keys = JwtKeys::hs256(b"secret").with_issuer_and_audience("issuer", "audience");
+        let token = keys.encode(&claims()).unwrap();
+
+        let decoded: TokenClaim = keys.decode(&token).unwrap();
+        assert_eq!(&*decoded.sub, "user123");
+        assert!(
+            JwtKeys::hs256(b"other")
+                .decode::<TokenClaim>(&token)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rs256_roundtrip() {
+        let keys = rs256_keys().with_issuer_and_audience("issuer", "audience");
+        let token = keys.encode(&claims()).unwrap();
+
+        let decoded: TokenClaim = keys.decode(&token).unwrap();
+        assert_eq!(&*decoded.sub, "user123");
+    }
+
+    #[test]
+    fn test_rejects_other_algorithm() {
+        let keys = rs256_keys().with_issuer_and_audience("issuer", "audience");
+        // Signing with the public key as HMAC secret is the classic algorithm confusion attack
+        let token = JwtKeys::hs256(test_rsa_keys().1.as_bytes())
+            .encode(&claims())
+            .unwrap();
+
+        assert!(keys.decode::<TokenClaim>(&token).is_err());
+    }
+
+    #[test]
+    fn test_rejects_other_issuer_or_audience() {
+        let token = JwtKeys::hs256(b"secret").encode(&claims()).unwrap();
+
+        let keys = JwtKeys::hs256(b"secret").with_issuer_and_audience("other", "audience");
+        assert!(keys.decode::<TokenClaim>(&token).is_err());
+
+        let keys = JwtKeys::hs256(b"secret").with_issuer_and_audience("issuer", "other");
+        assert!(keys.decode::<TokenClaim>(&token).is_err());
+    }
+
+    #[test]
+    fn test_rs256_invalid_key() {
+        assert!(JwtKeys::rs256(b"not a key", b"not a key").is_err());
+    }
+}
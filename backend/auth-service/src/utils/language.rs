@@ -0,0 +1,49 @@
+use axum::http::{HeaderMap, header};
+use shared_types::enums::Language;
+
+/// Picks the language of the emails sent for a request
+///
+/// The user's settings live in the settings service and do not exist yet
+/// while registering, so the language comes from the `Accept-Language` header.
+///
+/// # Arguments
+/// * `headers` - HTTP headers from the request
+///
+/// # Returns
+/// The preferred supported language, or English if the header is missing or names none
+pub fn email_language(headers: &HeaderMap) -> Language {
+    headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Language::from_accept_language)
+        .unwrap_or(Language::En)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_email_language_from_accept_language() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_static("ro-RO,ro;q=0.9,en;q=0.8"),
+        );
+        assert_eq!(email_language(&headers), Language::Ro);
+    }
+
+    #[test]
+    fn test_email_language_defaults_to_english() {
+        assert_eq!(email_language(&HeaderMap::new()), Language::En);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_static("ja, zh-CN"),
+        );
+        assert_eq!(email_language(&headers), Language::En);
+    }
+}
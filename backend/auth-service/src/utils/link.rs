@@ -0,0 +1,63 @@
+/// Placeholder replaced by the link's token in a link path template
+pub const LINK_TOKEN_PLACEHOLDER: &str = "{token}";
+
+/// Checks that a link path template contains the token placeholder
+///
+/// # Arguments
+///
+/// * `template` - The path template (e.g. "/activate/{token}")
+///
+/// # Returns
+///
+/// `true` if the template contains `{token}`
+pub fn is_valid_link_template(template: &str) -> bool {
+    template.contains(LINK_TOKEN_PLACEHOLDER)
+}
+
+/// Builds a frontend link from a path template
+///
+/// # Arguments
+///
+/// * `frontend_hostname` - The frontend URL the path is appended to
+/// * `template` - The path template with the `{token}` placeholder
+/// * `token` - The value replacing the placeholder
+///
+/// # Returns
+///
+/// The full link (e.g. "https://brewget.com/verify?token=...")
+pub fn render_link(frontend_hostname: &str, template: &str, token: &str) -> String {
+    format!(
+        "{}{}",
+        frontend_hostname,
+        template.replace(LINK_TOKEN_PLACEHOLDER, token)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_link_template() {
+        assert!(is_valid_link_template("/activate/{token}"));
+        assert!(is_valid_link_template("/verify?token={token}"));
+        assert!(!is_valid_link_template("/activate/"));
+        assert!(!is_valid_link_template("/activate/{id}"));
+    }
+
+    #[test]
+    fn test_render_path_link() {
+        assert_eq!(
+            render_link("https://brewget.com", "/activate/{token}", "abc"),
+            "https://brewget.com/activate/abc"
+        );
+    }
+
+    #[test]
+    fn test_render_query_link() {
+        assert_eq!(
+            render_link("http://localhost:5173", "/verify?token={token}", "abc"),
+            "http://localhost:5173/verify?token=abc"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use serde_cbor_2::Value;
+use uuid::Uuid;
+
+/// Length of the relying party id hash at the start of the authenticator data
+const RP_ID_HASH_LEN: usize = 32;
+
+/// Bit in the authenticator data flags signaling attested credential data
+const ATTESTED_CREDENTIAL_DATA_FLAG: u8 = 0b0100_0000;
+
+/// Offset of the AAGUID in the authenticator data (rpIdHash + flags + signCount)
+const AAGUID_OFFSET: usize = RP_ID_HASH_LEN + 1 + 4;
+
+/// Length of the AAGUID in bytes
+const AAGUID_LEN: usize = 16;
+
+/// Known authenticator AAGUIDs mapped to user-friendly names
+///
+/// Sourced from the community maintained passkey AAGUID list. Only the most
+/// common authenticators are included; unknown AAGUIDs simply have no name.
+const KNOWN_AUTHENTICATORS: &[(&str, &str)] = &[
+    ("fbfc3007-154e-4ecc-8c0b-6e020557d7bd", "iCloud Keychain"),
+    (
+        "dd4ec289-e01d-41c9-bb89-70fa845d4bf2",
+        "iCloud Keychain (Managed)",
+    ),
+    (
+        "ea9b8d66-4d01-1d21-3ce4-b6b48cb575d4",
+        "Google Password Manager",
+    ),
+    ("adce0002-35bc-c60a-648b-0b25f1f05503", "Chrome on Mac"),
+    ("08987058-cadc-4b81-b6e1-30de50dcbe96", "Windows Hello"),
+    ("9ddd1817-af5a-4672-a2b9-3e3dd95000a9", "Windows Hello"),
+    ("6028b017-b1d4-4c02-b4b3-afcdafc96bb2", "Windows Hello"),
+    ("53414d53-554e-4700-0000-000000000000", "Samsung Pass"),
+    ("bada5566-a7aa-401f-bd96-45619a55120d", "1Password"),
+    ("d548826e-79b4-db40-a3d8-11116f7e8349", "Bitwarden"),
+    ("531126d6-e717-415c-9320-3d9aa6981239", "Dashlane"),
+    ("cb69481e-8ff7-4039-93ec-0a2729a154a8", "YubiKey 5"),
+    ("ee882879-721c-4913-9775-3dfcce97072a", "YubiKey 5"),
+    ("fa2b99dc-9e39-4257-8f92-4a30d23c4118", "YubiKey 5"),
+    ("2fc0579f-8113-47ea-b116-bb5a8db9202a", "YubiKey 5"),
+    ("c5ef55ff-ad9a-4b9f-b580-adebafe026d0", "YubiKey 5Ci"),
+];
+
+/// Extracts the authenticator AAGUID from a WebAuthn attestation object
+///
+/// The attestation object is a CBOR map whose `authData` entry contains the
+/// authenticator data. When attested credential data is present, the AAGUID
+/// follows the rpIdHash, flags and signature counter.
+///
+/// # Arguments
+/// * `attestation_object` - Raw attestation object bytes from the registration response
+///
+/// # Returns
+/// * `Some(Uuid)` - The AAGUID of the authenticator
+/// * `None` - The object could not be parsed, has no attested credential data,
+///   or the AAGUID was zeroed out (e.g. by the client under "none" attestation)
+pub fn extract_aaguid(attestation_object: &[u8]) -> Option<Uuid> {
+    let Value::Map(map) = serde_cbor_2::from_slice::<Value>(attestation_object).ok()? else {
+        return None;
+    };
+
+    let Some(Value::Bytes(auth_data)) = map.get(&Value::Text("authData".to_string())) else {
+        return None;
+    };
+
+    let flags = *auth_data.get(RP_ID_HASH_LEN)?;
+    if flags & ATTESTED_CREDENTIAL_DATA_FLAG == 0 {
+        return None;
+    }
+
+    let aaguid =
+        Uuid::from_slice(auth_data.get(AAGUID_OFFSET..AAGUID_OFFSET + AAGUID_LEN)?).ok()?;
+    (!aaguid.is_nil()).then_some(aaguid)
+}
+
+/// Looks up a user-friendly authenticator name for an AAGUID
+///
+/// # Arguments
+/// * `aaguid` - The authenticator AAGUID
+///
+/// # Returns
+/// * `Some(&str)` - The name of the authenticator if it is known
+/// * `None` - The AAGUID is not in the known authenticators list
+pub fn authenticator_name(aaguid: &Uuid) -> Option<&'static str> {
+    let aaguid = aaguid.hyphenated().to_string();
+
+    KNOWN_AUTHENTICATORS
+        .iter()
+        .find(|(known, _)| *known == aaguid)
+        .map(|(_, name)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn build_attestation_object(flags: u8, aaguid: &Uuid) -> Vec<u8> {
+        let mut auth_data = vec![0u8; RP_ID_HASH_LEN];
+        auth_data.push(flags);
+        auth_data.extend_from_slice(&[0, 0, 0, 0]);
+        auth_data.extend_from_slice(aaguid.as_bytes());
+        auth_data.extend_from_slice(&[0, 2, 0xab, 0xcd]);
+
+        let mut map = BTreeMap::new();
+        map.insert(
+            Value::Text("fmt".to_string()),
+            Value::Text("none".to_string()),
+        );
+        map.insert(
+            Value::Text("attStmt".to_string()),
+            Value::Map(BTreeMap::new()),
+        );
+        map.insert(Value::Text("authData".to_string()), Value::Bytes(auth_data));
+
+        serde_cbor_2::to_vec(&Value::Map(map)).unwrap()
+    }
+
+    #[test]
+    fn test_extract_aaguid() {
+        let aaguid = Uuid::parse_str("fbfc3007-154e-4ecc-8c0b-6e020557d7bd").unwrap();
+        let attestation_object = build_attestation_object(0b0100_0101, &aaguid);
+
+        assert_eq!(extract_aaguid(&attestation_object), Some(aaguid));
+    }
+
+    #[test]
+    fn test_extract_aaguid_without_attested_credential_data() {
+        let aaguid = Uuid::parse_str("fbfc3007-154e-4ecc-8c0b-6e020557d7bd").unwrap();
+        let attestation_object = build_attestation_object(0b0000_0101, &aaguid);
+
+        assert_eq!(extract_aaguid(&attestation_object), None);
+    }
+
+    #[test]
+    fn test_extract_aaguid_zeroed() {
+        let attestation_object = build_attestation_object(0b0100_0101, &Uuid::nil());
+
+        assert_eq!(extract_aaguid(&attestation_object), None);
+    }
+
+    #[test]
+    fn test_extract_aaguid_invalid_cbor() {
+        assert_eq!(extract_aaguid(&[0xff, 0x00, 0x01]), None);
+        assert_eq!(extract_aaguid(&[]), None);
+    }
+
+    #[test]
+    fn test_authenticator_name_known() {
+        let aaguid = Uuid::parse_str("ea9b8d66-4d01-1d21-3ce4-b6b48cb575d4").unwrap();
+        assert_eq!(authenticator_name(&aaguid), Some("Google Password Manager"));
+
+        let aaguid = Uuid::parse_str("cb69481e-8ff7-4039-93ec-0a2729a154a8").unwrap();
+        assert_eq!(authenticator_name(&aaguid), Some("YubiKey 5"));
+    }
+
+    #[test]
+    fn test_authenticator_name_unknown() {
+        assert_eq!(authenticator_name(&Uuid::new_v4()), None);
+    }
+}
@@ -88,6 +88,21 @@ pub fn is_password_in_history(password: &str, password_hashes: &[String]) -> boo
         .any(|hash| verify_password(password, hash).is_ok())
 }
 
+/// Checks whether password history is enabled
+///
+/// A limit of 0 or less disables the feature: passwords are neither recorded
+/// in nor checked against the history.
+///
+/// # Arguments
+/// * `limit` - The configured number of previous passwords to prevent reuse
+///
+/// # Returns
+/// * `true` - If previous passwords must be recorded and checked
+/// * `false` - If the feature is disabled
+pub fn is_password_history_enabled(limit: i64) -> bool {
+    limit > 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,4 +250,17 @@ mod tests {
         // Test non-matching password
         assert!(!is_password_in_history("NonMatchingPassword1", &hashes));
     }
+
+    #[test]
+    fn test_is_password_history_enabled_zero_disables() {
+        assert!(!is_password_history_enabled(0));
+        assert!(!is_password_history_enabled(-1));
+        assert!(!is_password_history_enabled(i64::MIN));
+    }
+
+    #[test]
+    fn test_is_password_history_enabled_one_enables() {
+        assert!(is_password_history_enabled(1));
+        assert!(is_password_history_enabled(3));
+    }
 }
@@ -0,0 +1,75 @@
+use crate::models::response::TranslationKey;
+
+/// Checks whether a character may be used in a username
+///
+/// Usernames are limited to ASCII letters, digits, `_` and `-`.
+fn is_username_character(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Validates a username against the username policy
+///
+/// # Arguments
+/// * `username` - The username to validate
+/// * `min_length` - Minimum number of characters, inclusive
+///
+/// # Returns
+/// * `Ok(())` - If the username is valid
+/// * `Err(TranslationKey)` - `UsernameTooShort` if it has fewer than `min_length` characters,
+///   `UsernameInvalidCharacters` if it contains a character outside the policy
+pub fn validate_username(username: &str, min_length: usize) -> Result<(), TranslationKey> {
+    if username.chars().count() < min_length {
+        return Err(TranslationKey::UsernameTooShort);
+    }
+
+    if !username.chars().all(is_username_character) {
+        return Err(TranslationKey::UsernameInvalidCharacters);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_username_min_length_is_inclusive() {
+        assert_eq!(
+            validate_username("abc", 4),
+            Err(TranslationKey::UsernameTooShort)
+        );
+        assert_eq!(validate_username("abcd", 4), Ok(()));
+        assert_eq!(validate_username("abc", 3), Ok(()));
+        assert_eq!(
+            validate_username("", 1),
+            Err(TranslationKey::UsernameTooShort)
+        );
+    }
+
+    #[test]
+    fn test_validate_username_characters() {
+        assert_eq!(validate_username("brew_get-42", 4), Ok(()));
+        assert_eq!(
+            validate_username("brew get", 4),
+            Err(TranslationKey::UsernameInvalidCharacters)
+        );
+        assert_eq!(
+            validate_username("brew@get", 4),
+            Err(TranslationKey::UsernameInvalidCharacters)
+        );
+        assert_eq!(
+            validate_username("brëwget", 4),
+            Err(TranslationKey::UsernameInvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_validate_username_counts_characters() {
+        // Multi-byte characters count once towards the length
+        assert_eq!(
+            validate_username("äöü", 4),
+            Err(TranslationKey::UsernameTooShort)
+        );
+    }
+}
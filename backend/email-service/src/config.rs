@@ -1,4 +1,8 @@
-use std::env::var;
+use std::{
+    collections::HashMap,
+    env::{var, vars},
+    path::PathBuf,
+};
 
 /// Application configuration loaded from environment variables
 ///
@@ -18,6 +22,21 @@ use std::env::var;
 /// * `smtp_relay` - SMTP server hostname for sending emails
 /// * `smtp_username` - SMTP authentication username
 /// * `smtp_password` - SMTP authentication password
+///
+/// ## Template Configuration
+/// * `email_template_dir` - Directory the email templates are re-read from on every render,
+///   for development; the embedded templates are used if unset
+/// * `email_logo_path` - Image embedded inline in the HTML emails and referenced by the templates
+///   as `cid:logo`; the emails are sent without a logo if unset
+///
+/// ## Sender Configuration
+/// * `email_senders` - Sender overrides by template name; templates without one are sent
+///   from `smtp_name`
+///
+/// ## Unsubscribe Configuration
+/// * `email_unsubscribe_url` - Unsubscribe endpoint of the settings service, linked with the
+///   recipient's token from the `List-Unsubscribe` header of non-transactional emails; those
+///   emails cannot be sent if unset
 #[derive(Clone)]
 pub struct Config {
     pub email_grpc_port: u32,
@@ -27,6 +46,25 @@ pub struct Config {
     pub smtp_relay: String,
     pub smtp_username: String,
     pub smtp_password: String,
+    pub email_template_dir: Option<PathBuf>,
+    pub email_logo_path: Option<PathBuf>,
+    pub email_senders: HashMap<String, EmailSender>,
+    pub email_unsubscribe_url: Option<String>,
+}
+
+/// Sender overrides of the emails sent with one template
+///
+/// The emails are always sent from `smtp_email`, only the display name and
+/// the address replies go to can differ per template.
+///
+/// # Fields
+///
+/// * `from_name` - Display name for the sender, instead of `smtp_name`
+/// * `reply_to` - Email address replies are sent to, instead of the sender
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailSender {
+    pub from_name: Option<String>,
+    pub reply_to: Option<String>,
 }
 
 impl Config {
@@ -46,6 +84,16 @@ impl Config {
     /// - `SMTP_USERNAME` - SMTP authentication username
     /// - `SMTP_PASSWORD` - SMTP authentication password
     ///
+    /// The following environment variables are optional:
+    /// - `EMAIL_TEMPLATE_DIR` - Directory to load the email templates from on every render, for
+    ///   development (e.g. `backend/email-service/emails`)
+    /// - `EMAIL_LOGO_PATH` - PNG, JPEG or GIF logo to embed inline in the HTML emails
+    /// - `EMAIL_FROM_NAME_<TEMPLATE>` - Display name for the sender of one template's emails
+    ///   (e.g. `EMAIL_FROM_NAME_ACTIVATE_ACCOUNT=BrewGet Security`)
+    /// - `EMAIL_REPLY_TO_<TEMPLATE>` - Reply-to address of one template's emails
+    /// - `EMAIL_UNSUBSCRIBE_URL` - Unsubscribe endpoint of the settings service, required to send
+    ///   non-transactional emails; mail providers only accept HTTPS URLs
+    ///
     /// # Panics
     ///
     /// This method will panic if:
@@ -79,6 +127,18 @@ impl Config {
         let smtp_relay = var("SMTP_RELAY").expect("SMTP_RELAY must be provided.");
         let smtp_username = var("SMTP_USERNAME").expect("SMTP_USERNAME must be provided.");
         let smtp_password = var("SMTP_PASSWORD").expect("SMTP_PASSWORD must be provided.");
+        let email_template_dir = var("EMAIL_TEMPLATE_DIR")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        let email_logo_path = var("EMAIL_LOGO_PATH")
+            .ok()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from);
+        let email_senders = parse_email_senders(vars());
+        let email_unsubscribe_url = var("EMAIL_UNSUBSCRIBE_URL")
+            .ok()
+            .filter(|url| !url.is_empty());
 
         Self {
             email_grpc_port,
@@ -88,6 +148,98 @@ impl Config {
             smtp_relay,
             smtp_username,
             smtp_password,
+            email_template_dir,
+            email_logo_path,
+            email_senders,
+            email_unsubscribe_url,
         }
     }
 }
+
+/// Prefix of the variables overriding the sender name of a template
+const FROM_NAME_PREFIX: &str = "EMAIL_FROM_NAME_";
+/// Prefix of the variables overriding the reply-to address of a template
+const REPLY_TO_PREFIX: &str = "EMAIL_REPLY_TO_";
+
+/// Collects the per template sender overrides from environment variables
+///
+/// The template name is the lowercased variable name suffix, so
+/// `EMAIL_FROM_NAME_ACTIVATE_ACCOUNT` applies to `activate_account`. Empty
+/// values are ignored.
+///
+/// # Arguments
+///
+/// * `vars` - The environment variables, as name and value pairs
+///
+/// # Returns
+///
+/// The sender overrides by template name
+pub fn parse_email_senders(
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> HashMap<String, EmailSender> {
+    let mut senders: HashMap<String, EmailSender> = HashMap::new();
+
+    for (name, value) in vars {
+        if value.is_empty() {
+            continue;
+        }
+
+        if let Some(template) = name.strip_prefix(FROM_NAME_PREFIX) {
+            senders
+                .entry(template.to_lowercase())
+                .or_default()
+                .from_name = Some(value);
+        } else if let Some(template) = name.strip_prefix(REPLY_TO_PREFIX) {
+            senders.entry(template.to_lowercase()).or_default().reply_to = Some(value);
+        }
+    }
+
+    senders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_email_senders() {
+        let senders = parse_email_senders(vars(&[
+            ("EMAIL_FROM_NAME_ACTIVATE_ACCOUNT", "BrewGet Security"),
+            ("EMAIL_REPLY_TO_ACTIVATE_ACCOUNT", "security@example.com"),
+            ("EMAIL_FROM_NAME_WEEKLY_SUMMARY", "BrewGet Reports"),
+            ("SMTP_NAME", "BrewGet"),
+        ]));
+
+        assert_eq!(senders.len(), 2);
+        assert_eq!(
+            senders["activate_account"],
+            EmailSender {
+                from_name: Some("BrewGet Security".to_string()),
+                reply_to: Some("security@example.com".to_string()),
+            }
+        );
+        assert_eq!(
+            senders["weekly_summary"],
+            EmailSender {
+                from_name: Some("BrewGet Reports".to_string()),
+                reply_to: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_email_senders_ignores_empty_values() {
+        let senders = parse_email_senders(vars(&[
+            ("EMAIL_FROM_NAME_ACTIVATE_ACCOUNT", ""),
+            ("EMAIL_REPLY_TO_FORGOT_PASSWORD", ""),
+        ]));
+        assert!(senders.is_empty());
+    }
+}
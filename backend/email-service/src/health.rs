@@ -24,6 +24,7 @@ async fn health_checker_handler() -> Json<Health> {
     Json(Health {
         status: HealthStatus::Healthy,
         database: None,
+        auth_service: None,
         version: env!("CARGO_PKG_VERSION").into(),
     })
 }
@@ -1,26 +1,484 @@
-use std::sync::Arc;
+use std::{path::Path, str::FromStr, sync::Arc};
 
-use handlebars::Handlebars;
+use handlebars::{Handlebars, TemplateError};
 use lettre::{
-    Message, SmtpTransport, Transport, message::MultiPart,
+    Message, SmtpTransport, Transport,
+    message::{
+        Attachment, Mailbox, MessageBuilder, MultiPart, SinglePart,
+        header::{ContentType, Header, HeaderName, HeaderValue},
+    },
     transport::smtp::authentication::Credentials,
 };
-use serde_json::json;
+use serde_json::{Map, Value};
+use shared_types::enums::Language;
 use tonic::{Request, Response, Result, Status};
-use tracing::{error, info, instrument};
+use tracing::{error, info, instrument, warn};
 
 use crate::{
-    config::Config,
+    config::{Config, EmailSender},
     service::email_service::{
         ActivateAccountRequest, ActivateAccountResponse, ForgotPasswordRequest,
-        ForgotPasswordResponse, email_service_server::EmailService,
+        ForgotPasswordResponse, PasswordChangedRequest, PasswordChangedResponse,
+        TemplatedEmailRequest, TemplatedEmailResponse, email_service_server::EmailService,
     },
 };
 
-/// HTML template for account activation emails
-const ACTIVATE_ACCOUNT_TEMPLATE: &str = include_str!("../emails/activate_account_template.html");
-/// HTML template for password reset emails
-const FORGOT_PASSWORD_TEMPLATE: &str = include_str!("../emails/forgot_password_template.html");
+/// HTML templates for account activation emails
+const ACTIVATE_ACCOUNT_TEMPLATE: &str = include_str!("../emails/activate_account_template.en.html");
+const ACTIVATE_ACCOUNT_TEMPLATE_ES: &str =
+    include_str!("../emails/activate_account_template.es.html");
+const ACTIVATE_ACCOUNT_TEMPLATE_FR: &str =
+    include_str!("../emails/activate_account_template.fr.html");
+const ACTIVATE_ACCOUNT_TEMPLATE_DE: &str =
+    include_str!("../emails/activate_account_template.de.html");
+const ACTIVATE_ACCOUNT_TEMPLATE_RO: &str =
+    include_str!("../emails/activate_account_template.ro.html");
+/// HTML templates for password reset emails
+const FORGOT_PASSWORD_TEMPLATE: &str = include_str!("../emails/forgot_password_template.en.html");
+const FORGOT_PASSWORD_TEMPLATE_ES: &str =
+    include_str!("../emails/forgot_password_template.es.html");
+const FORGOT_PASSWORD_TEMPLATE_FR: &str =
+    include_str!("../emails/forgot_password_template.fr.html");
+const FORGOT_PASSWORD_TEMPLATE_DE: &str =
+    include_str!("../emails/forgot_password_template.de.html");
+const FORGOT_PASSWORD_TEMPLATE_RO: &str =
+    include_str!("../emails/forgot_password_template.ro.html");
+/// HTML template for password changed notification emails
+const PASSWORD_CHANGED_TEMPLATE: &str = include_str!("../emails/password_changed_template.en.html");
+
+/// An email template the service can send
+///
+/// # Fields
+///
+/// * `name` - Name the template is registered and requested by
+/// * `translations` - The languages the template is available in, English first; emails in
+///   any other language fall back to English
+/// * `transactional` - Whether the emails are sent because of something the user did, like
+///   signing up; all other emails carry unsubscribe headers
+pub struct EmailTemplate {
+    pub name: &'static str,
+    pub translations: &'static [TemplateTranslation],
+    pub transactional: bool,
+}
+
+/// An email template in one language
+///
+/// # Fields
+///
+/// * `language` - Language of the translation
+/// * `source` - HTML template embedded at build time
+/// * `subject` - Subject of the emails sent in this language
+/// * `plain` - Handlebars template of the plain text version
+pub struct TemplateTranslation {
+    pub language: Language,
+    pub source: &'static str,
+    pub subject: &'static str,
+    pub plain: &'static str,
+}
+
+impl EmailTemplate {
+    /// Returns the translation of the template in a language
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The recipient's language
+    ///
+    /// # Returns
+    ///
+    /// The translation in that language, or the English one if the template is not translated
+    pub fn translation(&self, language: Language) -> &TemplateTranslation {
+        self.translations
+            .iter()
+            .find(|translation| translation.language == language)
+            .unwrap_or(&self.translations[0])
+    }
+
+    /// Returns the file of the HTML template in a language in the template directory
+    fn file_name(&self, language: Language) -> String {
+        format!("{}_template.{}.html", self.name, language)
+    }
+
+    /// Returns the name the HTML template in a language is registered by
+    ///
+    /// English is registered by the template name, other languages as `{name}_{language}`.
+    fn html_name(&self, language: Language) -> String {
+        match language {
+            Language::En => self.name.to_string(),
+            _ => format!("{}_{}", self.name, language),
+        }
+    }
+
+    /// Returns the name the plain text version in a language is registered by
+    fn plain_name(&self, language: Language) -> String {
+        match language {
+            Language::En => format!("{}_plain", self.name),
+            _ => format!("{}_plain_{}", self.name, language),
+        }
+    }
+}
+
+/// Registered templates
+///
+/// Adding a template here makes it available to `send_templated_email`
+/// without a proto change.
+pub static TEMPLATES: [EmailTemplate; 3] = [
+    EmailTemplate {
+        name: "activate_account",
+        translations: &[
+            TemplateTranslation {
+                language: Language::En,
+                source: ACTIVATE_ACCOUNT_TEMPLATE,
+                subject: "Activate your account",
+                plain: "Use the following link to activate your account: {{{activation_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Es,
+                source: ACTIVATE_ACCOUNT_TEMPLATE_ES,
+                subject: "Activa tu cuenta",
+                plain: "Usa el siguiente enlace para activar tu cuenta: {{{activation_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Fr,
+                source: ACTIVATE_ACCOUNT_TEMPLATE_FR,
+                subject: "Activez votre compte",
+                plain: "Utilisez le lien suivant pour activer votre compte : {{{activation_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::De,
+                source: ACTIVATE_ACCOUNT_TEMPLATE_DE,
+                subject: "Aktiviere dein Konto",
+                plain: "Verwende den folgenden Link, um dein Konto zu aktivieren: {{{activation_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Ro,
+                source: ACTIVATE_ACCOUNT_TEMPLATE_RO,
+                subject: "Activează-ți contul",
+                plain: "Folosește următorul link pentru a-ți activa contul: {{{activation_link}}}",
+            },
+        ],
+        transactional: true,
+    },
+    EmailTemplate {
+        name: "forgot_password",
+        translations: &[
+            TemplateTranslation {
+                language: Language::En,
+                source: FORGOT_PASSWORD_TEMPLATE,
+                subject: "Reset your password",
+                plain: "Use the following link to reset your password: {{{forgot_password_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Es,
+                source: FORGOT_PASSWORD_TEMPLATE_ES,
+                subject: "Restablece tu contraseña",
+                plain: "Usa el siguiente enlace para restablecer tu contraseña: {{{forgot_password_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Fr,
+                source: FORGOT_PASSWORD_TEMPLATE_FR,
+                subject: "Réinitialisez votre mot de passe",
+                plain: "Utilisez le lien suivant pour réinitialiser votre mot de passe : {{{forgot_password_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::De,
+                source: FORGOT_PASSWORD_TEMPLATE_DE,
+                subject: "Setze dein Passwort zurück",
+                plain: "Verwende den folgenden Link, um dein Passwort zurückzusetzen: {{{forgot_password_link}}}",
+            },
+            TemplateTranslation {
+                language: Language::Ro,
+                source: FORGOT_PASSWORD_TEMPLATE_RO,
+                subject: "Resetează-ți parola",
+                plain: "Folosește următorul link pentru a-ți reseta parola: {{{forgot_password_link}}}",
+            },
+        ],
+        transactional: true,
+    },
+    EmailTemplate {
+        name: "password_changed",
+        translations: &[TemplateTranslation {
+            language: Language::En,
+            source: PASSWORD_CHANGED_TEMPLATE,
+            subject: "Your password was changed",
+            plain: "The password of your account {{{username}}} was changed on {{{changed_at}}}. If this was you, no further action is needed. If this wasn't you, reset your password right away: {{{reset_link}}}",
+        }],
+        transactional: true,
+    },
+];
+
+/// Parses the language of an email
+///
+/// # Arguments
+///
+/// * `code` - The language code of the request (e.g. "es"), empty if not known
+///
+/// # Returns
+///
+/// The language, or English for an empty or unsupported code
+pub fn email_language(code: &str) -> Language {
+    Language::from_str(code).unwrap_or_else(|_| {
+        if !code.is_empty() {
+            warn!(language = %code, "Unsupported email language, falling back to English");
+        }
+        Language::En
+    })
+}
+
+/// Value of the `List-Unsubscribe-Post` header asking for one-click unsubscribes
+const LIST_UNSUBSCRIBE_ONE_CLICK: &str = "List-Unsubscribe=One-Click";
+
+/// The `List-Unsubscribe` header (RFC 2369) with the URL unsubscribing the recipient
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListUnsubscribe(String);
+
+impl Header for ListUnsubscribe {
+    fn name() -> HeaderName {
+        HeaderName::new_from_ascii_str("List-Unsubscribe")
+    }
+
+    fn parse(s: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = s
+            .trim()
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .ok_or("List-Unsubscribe URL must be enclosed in angle brackets")?;
+        Ok(Self(url.to_string()))
+    }
+
+    fn display(&self) -> HeaderValue {
+        HeaderValue::new(Self::name(), format!("<{}>", self.0))
+    }
+}
+
+/// The `List-Unsubscribe-Post` header (RFC 8058) marking the unsubscribe URL as one-click
+///
+/// Mail providers then unsubscribe the recipient with a POST to the URL,
+/// without opening it in a browser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListUnsubscribePost;
+
+impl Header for ListUnsubscribePost {
+    fn name() -> HeaderName {
+        HeaderName::new_from_ascii_str("List-Unsubscribe-Post")
+    }
+
+    fn parse(s: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if s.trim() != LIST_UNSUBSCRIBE_ONE_CLICK {
+            return Err(format!("Unsupported List-Unsubscribe-Post value: {}", s).into());
+        }
+        Ok(Self)
+    }
+
+    fn display(&self) -> HeaderValue {
+        HeaderValue::new(Self::name(), LIST_UNSUBSCRIBE_ONE_CLICK.to_string())
+    }
+}
+
+/// Builds the URL unsubscribing the recipient of an email
+///
+/// The token is appended as the `token` query parameter. It must be URL safe,
+/// like the base64url tokens of the settings service, so it is never encoded.
+///
+/// # Arguments
+///
+/// * `base_url` - The unsubscribe endpoint of the settings service
+/// * `token` - The token identifying the recipient
+///
+/// # Returns
+///
+/// * `Ok(String)` - The unsubscribe URL
+/// * `Err(EmailError)` - `InvalidUnsubscribeToken` for an empty or not URL safe token
+pub fn unsubscribe_url(base_url: &str, token: &str) -> Result<String, EmailError> {
+    let url_safe = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~');
+    if token.is_empty() || !token.chars().all(url_safe) {
+        return Err(EmailError::InvalidUnsubscribeToken);
+    }
+
+    let separator = if base_url.contains('?') { '&' } else { '?' };
+    Ok(format!("{}{}token={}", base_url, separator, token))
+}
+
+/// Adds the one-click unsubscribe headers to an email
+///
+/// # Arguments
+///
+/// * `builder` - The email being built
+/// * `url` - The URL unsubscribing the recipient
+///
+/// # Returns
+///
+/// The builder with the `List-Unsubscribe` and `List-Unsubscribe-Post` headers
+pub fn with_unsubscribe_headers(builder: MessageBuilder, url: String) -> MessageBuilder {
+    builder
+        .header(ListUnsubscribe(url))
+        .header(ListUnsubscribePost)
+}
+
+/// Returns the registered template with a name
+///
+/// # Arguments
+///
+/// * `name` - Name of the template
+///
+/// # Returns
+///
+/// The template, or `None` if no template has that name
+pub fn find_template(name: &str) -> Option<&'static EmailTemplate> {
+    TEMPLATES.iter().find(|template| template.name == name)
+}
+
+/// Creates the Handlebars template engine with all email templates registered
+///
+/// Every translation of a template is registered, English by the template
+/// name and the other languages as `{name}_{language}`.
+///
+/// Without a template directory the templates embedded at build time are used.
+/// With one, the templates are loaded from its files (e.g.
+/// `activate_account_template.es.html`) in dev mode, so every render re-reads
+/// them and template changes apply without a rebuild. The plain text versions
+/// are always the embedded ones.
+///
+/// # Arguments
+///
+/// * `template_dir` - Optional directory containing the template files
+///
+/// # Returns
+///
+/// * `Ok(Handlebars)` - The template engine with all templates registered
+/// * `Err(TemplateError)` - A template could not be read or parsed
+pub fn build_handlebars(template_dir: Option<&Path>) -> Result<Handlebars<'static>, TemplateError> {
+    let mut handlebars = Handlebars::new();
+
+    match template_dir {
+        Some(template_dir) => {
+            info!(template_dir = %template_dir.display(), "Loading email templates from disk");
+            handlebars.set_dev_mode(true);
+            for template in &TEMPLATES {
+                for translation in template.translations {
+                    let file = template_dir.join(template.file_name(translation.language));
+                    handlebars
+                        .register_template_file(&template.html_name(translation.language), file)
+                        .inspect_err(|e| {
+                            error!(error = %e, language = %translation.language, "Failed to register {} template", template.name);
+                        })?;
+                }
+            }
+        }
+        None => {
+            for template in &TEMPLATES {
+                for translation in template.translations {
+                    handlebars
+                        .register_template_string(
+                            &template.html_name(translation.language),
+                            translation.source,
+                        )
+                        .inspect_err(|e| {
+                            error!(error = %e, language = %translation.language, "Failed to register {} template", template.name);
+                        })?;
+                }
+            }
+        }
+    }
+
+    for template in &TEMPLATES {
+        for translation in template.translations {
+            handlebars
+                .register_template_string(
+                    &template.plain_name(translation.language),
+                    translation.plain,
+                )
+                .inspect_err(|e| {
+                    error!(error = %e, language = %translation.language, "Failed to register {} plain text template", template.name);
+                })?;
+        }
+    }
+
+    Ok(handlebars)
+}
+
+/// Content ID the templates reference the inline logo by, as `cid:logo`
+const LOGO_CONTENT_ID: &str = "logo";
+
+/// Logo image embedded inline in the HTML emails
+///
+/// # Fields
+///
+/// * `content` - The raw image bytes
+/// * `content_type` - The image MIME type, derived from the file extension
+#[derive(Clone)]
+pub struct Logo {
+    content: Vec<u8>,
+    content_type: ContentType,
+}
+
+/// Loads the logo embedded in the HTML emails
+///
+/// # Arguments
+///
+/// * `path` - Path of a PNG, JPEG or GIF image
+///
+/// # Returns
+///
+/// * `Ok(Logo)` - The image and its MIME type
+/// * `Err(std::io::Error)` - The file could not be read or is not a supported image type
+pub fn load_logo(path: &Path) -> std::io::Result<Logo> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    let content_type = match extension.as_deref() {
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        _ => {
+            error!(logo_path = %path.display(), "Unsupported email logo image type");
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "EMAIL_LOGO_PATH must be a PNG, JPEG or GIF image",
+            ));
+        }
+    };
+
+    let content = std::fs::read(path).inspect_err(|e| {
+        error!(error = %e, logo_path = %path.display(), "Failed to read email logo");
+    })?;
+
+    Ok(Logo {
+        content,
+        content_type: ContentType::parse(content_type).expect("image MIME types are valid"),
+    })
+}
+
+/// Builds the body of an email from its plain text and HTML versions
+///
+/// With a logo, the HTML version is sent in a related multipart together with
+/// the logo as an inline attachment, so the templates can show it as `cid:logo`.
+///
+/// # Arguments
+///
+/// * `plain` - The plain text version of the email
+/// * `html` - The rendered HTML version of the email
+/// * `logo` - Optional logo to attach inline
+///
+/// # Returns
+///
+/// The multipart body offering both versions
+pub fn build_body(plain: String, html: String, logo: Option<&Logo>) -> MultiPart {
+    let Some(logo) = logo else {
+        return MultiPart::alternative_plain_html(plain, html);
+    };
+
+    MultiPart::alternative()
+        .singlepart(SinglePart::plain(plain))
+        .multipart(
+            MultiPart::related()
+                .singlepart(SinglePart::html(html))
+                .singlepart(
+                    Attachment::new_inline(LOGO_CONTENT_ID.to_string())
+                        .body(logo.content.clone(), logo.content_type.clone()),
+                ),
+        )
+}
 
 /// Protocol Buffers definitions for the email service
 pub mod email_service {
@@ -32,6 +490,12 @@ pub mod email_service {
 pub enum EmailError {
     /// Error parsing email address
     AddressParse(String),
+    /// No template is registered with the requested name
+    UnknownTemplate(String),
+    /// A non-transactional email without a valid unsubscribe token
+    InvalidUnsubscribeToken,
+    /// A non-transactional email while `EMAIL_UNSUBSCRIBE_URL` is not set
+    UnsubscribeUnavailable,
     /// Error rendering email template
     TemplateRender(String),
     /// Error creating email message
@@ -44,6 +508,13 @@ impl std::fmt::Display for EmailError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             EmailError::AddressParse(msg) => write!(f, "Failed to parse email address: {}", msg),
+            EmailError::UnknownTemplate(name) => write!(f, "Unknown email template: {}", name),
+            EmailError::InvalidUnsubscribeToken => {
+                write!(f, "Missing or invalid unsubscribe token")
+            }
+            EmailError::UnsubscribeUnavailable => {
+                write!(f, "No unsubscribe URL is configured")
+            }
             EmailError::TemplateRender(msg) => {
                 write!(f, "Failed to render email template: {}", msg)
             }
@@ -55,6 +526,19 @@ impl std::fmt::Display for EmailError {
 
 impl std::error::Error for EmailError {}
 
+/// Options of a single email
+///
+/// # Fields
+///
+/// * `sender` - Sender overrides of the email
+/// * `unsubscribe_token` - Token identifying the recipient in the unsubscribe URL, required by
+///   non-transactional templates
+#[derive(Clone, Debug, Default)]
+pub struct MailOptions {
+    pub sender: EmailSender,
+    pub unsubscribe_token: Option<String>,
+}
+
 /// Email service implementation
 ///
 /// This struct contains the configuration and SMTP transport needed to send emails.
@@ -64,11 +548,14 @@ impl std::error::Error for EmailError {}
 ///
 /// * `config` - Shared configuration containing SMTP settings
 /// * `mailer` - SMTP transport for sending emails
-/// * `handlebars` - Pre-configured Handlebars template engine with registered templates
+/// * `handlebars` - Pre-configured Handlebars template engine with registered templates,
+///   re-reading them from disk on every render if `EMAIL_TEMPLATE_DIR` is set
+/// * `logo` - Logo attached inline to the HTML emails, loaded from `EMAIL_LOGO_PATH`
 pub struct Service {
     config: Arc<Config>,
     mailer: SmtpTransport,
     handlebars: Handlebars<'static>,
+    logo: Option<Logo>,
 }
 
 impl Service {
@@ -90,6 +577,10 @@ impl Service {
     ///
     /// This function can return errors in the following cases:
     /// - Invalid SMTP relay hostname
+    /// - A template in `EMAIL_TEMPLATE_DIR` is missing or invalid
+    /// - The image in `EMAIL_LOGO_PATH` is missing or not a PNG, JPEG or GIF
+    /// - An `EMAIL_FROM_NAME_<TEMPLATE>` or `EMAIL_REPLY_TO_<TEMPLATE>` names an unknown template
+    /// - An `EMAIL_REPLY_TO_<TEMPLATE>` address is invalid
     /// - SMTP transport configuration failure
     ///
     /// # Example
@@ -115,93 +606,183 @@ impl Service {
             .build();
 
         // Initialize Handlebars template engine with registered templates
-        let mut handlebars = Handlebars::new();
-        handlebars
-            .register_template_string("activate_account", ACTIVATE_ACCOUNT_TEMPLATE)
-            .map_err(|e| {
-                error!(error = %e, "Failed to register activate_account template");
-                e
-            })?;
-        handlebars
-            .register_template_string("forgot_password", FORGOT_PASSWORD_TEMPLATE)
-            .map_err(|e| {
-                error!(error = %e, "Failed to register forgot_password template");
-                e
-            })?;
+        let handlebars = build_handlebars(config.email_template_dir.as_deref())?;
+
+        let logo = config
+            .email_logo_path
+            .as_deref()
+            .map(load_logo)
+            .transpose()?;
+
+        for (template_name, sender) in &config.email_senders {
+            if find_template(template_name).is_none() {
+                error!(template_name = %template_name, "Sender configured for an unknown template");
+                return Err(format!("No email template named {}", template_name).into());
+            }
+            if let Some(reply_to) = &sender.reply_to {
+                reply_to.parse::<Mailbox>().inspect_err(|e| {
+                    error!(error = %e, template_name = %template_name, reply_to = %reply_to, "Invalid reply-to address");
+                })?;
+            }
+        }
 
         info!("Email service initialized successfully");
         Ok(Self {
             config,
             mailer,
             handlebars,
+            logo,
         })
     }
 
-    /// Creates an account activation email message
+    /// Creates an email message from a registered template
+    ///
+    /// Renders the HTML and plain text versions of the template with the given
+    /// data, and takes the subject from the template's translation in
+    /// `language`. Templates not translated to it, and unsupported language
+    /// codes, fall back to English.
     ///
-    /// This function generates both plain text and HTML versions of the activation email
-    /// using the Handlebars template engine.
+    /// The sender name and reply-to address are taken from the options, then
+    /// from the template's configured sender, then default to `smtp_name` and
+    /// no reply-to. Emails of non-transactional templates get the one-click
+    /// unsubscribe headers, pointing at `EMAIL_UNSUBSCRIBE_URL` with the
+    /// options' unsubscribe token.
     ///
     /// # Arguments
     ///
-    /// * `request` - The activation account request containing user details and activation link
+    /// * `email` - The email address to send to
+    /// * `username` - The name of the recipient, also available to the template
+    /// * `template_name` - The name of the registered template
+    /// * `language` - The recipient's language code, empty for English
+    /// * `options` - Sender overrides and unsubscribe token of this email
+    /// * `data` - The values the template renders
     ///
     /// # Returns
     ///
     /// * `Ok(Message)` - Successfully created email message
-    /// * `Err(EmailError)` - Error occurred during message creation
-    #[instrument(skip(self, request), fields(email = %request.email, username = %request.username))]
-    async fn create_activate_account_mail(
+    /// * `Err(EmailError)` - Unknown template, missing unsubscribe token or error during message
+    ///   creation
+    #[instrument(skip(self, options, data), fields(email = %email, username = %username))]
+    async fn create_mail(
         &self,
-        request: &ActivateAccountRequest,
+        email: &str,
+        username: &str,
+        template_name: &str,
+        language: &str,
+        options: &MailOptions,
+        mut data: Map<String, Value>,
     ) -> std::result::Result<Message, EmailError> {
-        info!("Creating activation account email message");
+        info!("Creating {} email message", template_name);
+
+        let template = find_template(template_name).ok_or_else(|| {
+            error!(template_name = %template_name, "Unknown email template");
+            EmailError::UnknownTemplate(template_name.to_string())
+        })?;
+        let translation = template.translation(email_language(language));
 
-        let m = Message::builder()
+        let sender = &options.sender;
+        let configured = self.config.email_senders.get(template.name);
+        let from_name = sender
+            .from_name
+            .as_deref()
+            .or_else(|| configured.and_then(|c| c.from_name.as_deref()))
+            .unwrap_or(&self.config.smtp_name);
+        let reply_to = sender
+            .reply_to
+            .as_deref()
+            .or_else(|| configured.and_then(|c| c.reply_to.as_deref()));
+
+        let mut m = Message::builder()
             .from(
-                format!("{} <{}>", self.config.smtp_name, self.config.smtp_email)
+                format!("{} <{}>", from_name, self.config.smtp_email)
                     .parse()
                     .map_err(|e| {
                         error!(error = ?e, from_email = %self.config.smtp_email, "Failed to parse 'from' email address");
                         EmailError::AddressParse(format!("Invalid 'from' address: {}", e))
                     })?,
             )
-            .to(format!("{} <{}>", request.username, request.email)
+            .to(format!("{} <{}>", username, email)
                 .parse()
                 .map_err(|e| {
-                    error!(error = ?e, to_email = %request.email, "Failed to parse 'to' email address");
+                    error!(error = ?e, to_email = %email, "Failed to parse 'to' email address");
                     EmailError::AddressParse(format!("Invalid 'to' address: {}", e))
                 })?)
-            .subject("Activate your account");
+            .subject(translation.subject);
+        if let Some(reply_to) = reply_to {
+            m = m.reply_to(reply_to.parse().map_err(|e| {
+                error!(error = ?e, reply_to = %reply_to, "Failed to parse 'reply-to' email address");
+                EmailError::AddressParse(format!("Invalid 'reply-to' address: {}", e))
+            })?);
+        }
+        if !template.transactional {
+            let base_url = self.config.email_unsubscribe_url.as_deref().ok_or_else(|| {
+                error!(template_name = %template.name, "Cannot send non-transactional email without EMAIL_UNSUBSCRIBE_URL");
+                EmailError::UnsubscribeUnavailable
+            })?;
+            let url = unsubscribe_url(
+                base_url,
+                options.unsubscribe_token.as_deref().unwrap_or_default(),
+            )
+            .inspect_err(|_| {
+                error!(template_name = %template.name, "Missing or invalid unsubscribe token");
+            })?;
+            m = with_unsubscribe_headers(m, url);
+        }
 
-        let plain = format!(
-            "Use the following link to activate your account: {}",
-            request.link
-        );
+        data.insert("username".into(), username.into());
+        data.insert("logo".into(), self.logo.is_some().into());
 
         let html = self
             .handlebars
-            .render(
-                "activate_account",
-                &json!({"activation_link": request.link}),
-            )
+            .render(&template.html_name(translation.language), &data)
+            .map_err(|e| {
+                error!(error = %e, "Failed to render {} email template", template.name);
+                EmailError::TemplateRender(e.to_string())
+            })?;
+        let plain = self
+            .handlebars
+            .render(&template.plain_name(translation.language), &data)
             .map_err(|e| {
-                error!(error = %e, "Failed to render activation email template");
+                error!(error = %e, "Failed to render {} plain text template", template.name);
                 EmailError::TemplateRender(e.to_string())
             })?;
 
-        info!("Successfully created activation account email message");
-        m.multipart(MultiPart::alternative_plain_html(plain, html))
+        info!("Successfully created {} email message", template_name);
+        m.multipart(build_body(plain, html, self.logo.as_ref()))
             .map_err(|e| {
                 error!(error = ?e, "Failed to create multipart email message");
                 EmailError::MessageBuild(e.to_string())
             })
     }
 
-    /// Creates a password reset email message
+    /// Creates an account activation email message
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The activation account request containing user details and activation link
     ///
-    /// This function generates both plain text and HTML versions of the password reset email
-    /// using the Handlebars template engine.
+    /// # Returns
+    ///
+    /// * `Ok(Message)` - Successfully created email message
+    /// * `Err(EmailError)` - Error occurred during message creation
+    async fn create_activate_account_mail(
+        &self,
+        request: &ActivateAccountRequest,
+    ) -> std::result::Result<Message, EmailError> {
+        let data = Map::from_iter([("activation_link".into(), request.link.clone().into())]);
+
+        self.create_mail(
+            &request.email,
+            &request.username,
+            "activate_account",
+            &request.language,
+            &MailOptions::default(),
+            data,
+        )
+        .await
+    }
+
+    /// Creates a password reset email message
     ///
     /// # Arguments
     ///
@@ -211,52 +792,54 @@ impl Service {
     ///
     /// * `Ok(Message)` - Successfully created email message
     /// * `Err(EmailError)` - Error occurred during message creation
-    #[instrument(skip(self, request), fields(email = %request.email, username = %request.username))]
     async fn create_forgot_password_mail(
         &self,
         request: &ForgotPasswordRequest,
     ) -> std::result::Result<Message, EmailError> {
-        info!("Creating forgot password email message");
+        let data = Map::from_iter([("forgot_password_link".into(), request.link.clone().into())]);
 
-        let m = Message::builder()
-            .from(
-                format!("{} <{}>", self.config.smtp_name, self.config.smtp_email)
-                    .parse()
-                    .map_err(|e| {
-                        error!(error = ?e, from_email = %self.config.smtp_email, "Failed to parse 'from' email address");
-                        EmailError::AddressParse(format!("Invalid 'from' address: {}", e))
-                    })?,
-            )
-            .to(format!("{} <{}>", request.username, request.email)
-                .parse()
-                .map_err(|e| {
-                    error!(error = ?e, to_email = %request.email, "Failed to parse 'to' email address");
-                    EmailError::AddressParse(format!("Invalid 'to' address: {}", e))
-                })?)
-            .subject("Reset your password");
-
-        let plain = format!(
-            "Use the following link to reset your password: {}",
-            request.link
-        );
+        self.create_mail(
+            &request.email,
+            &request.username,
+            "forgot_password",
+            &request.language,
+            &MailOptions::default(),
+            data,
+        )
+        .await
+    }
 
-        let html = self
-            .handlebars
-            .render(
-                "forgot_password",
-                &json!({"forgot_password_link": request.link}),
-            )
-            .map_err(|e| {
-                error!(error = %e, "Failed to render forgot password email template");
-                EmailError::TemplateRender(e.to_string())
-            })?;
+    /// Creates a password changed notification email message
+    ///
+    /// The email tells the user how to reset the password in case they did not
+    /// change it themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The password changed request containing user details, change time and reset link
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Message)` - Successfully created email message
+    /// * `Err(EmailError)` - Error occurred during message creation
+    async fn create_password_changed_mail(
+        &self,
+        request: &PasswordChangedRequest,
+    ) -> std::result::Result<Message, EmailError> {
+        let data = Map::from_iter([
+            ("changed_at".into(), request.changed_at.clone().into()),
+            ("reset_link".into(), request.link.clone().into()),
+        ]);
 
-        info!("Successfully created forgot password email message");
-        m.multipart(MultiPart::alternative_plain_html(plain, html))
-            .map_err(|e| {
-                error!(error = ?e, "Failed to create multipart email message");
-                EmailError::MessageBuild(e.to_string())
-            })
+        self.create_mail(
+            &request.email,
+            &request.username,
+            "password_changed",
+            "",
+            &MailOptions::default(),
+            data,
+        )
+        .await
     }
 
     /// Sends an email using the configured SMTP transport
@@ -308,6 +891,8 @@ impl EmailService for Service {
     /// * `username` - The username of the user
     /// * `email` - The email address to send the activation email to
     /// * `link` - The activation link to include in the email
+    /// * `language` - The user's language code, selects the translation of the email; English
+    ///   if empty or unsupported
     ///
     /// # Response Fields
     ///
@@ -372,6 +957,8 @@ impl EmailService for Service {
     /// * `username` - The username of the user
     /// * `email` - The email address to send the password reset email to
     /// * `link` - The password reset link to include in the email
+    /// * `language` - The user's language code, selects the translation of the email; English
+    ///   if empty or unsupported
     ///
     /// # Response Fields
     ///
@@ -416,10 +1003,186 @@ impl EmailService for Service {
         let reply = ForgotPasswordResponse { success: true };
         Ok(Response::new(reply))
     }
+
+    /// Sends a password changed notification email
+    ///
+    /// This gRPC endpoint handles requests to notify users that their password was changed.
+    /// It creates an HTML email with the time of the change and a link to reset the password
+    /// if the change was not made by the user, and sends it via SMTP.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - gRPC request containing password changed details
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response<PasswordChangedResponse>)` - Success response indicating email was sent
+    /// * `Err(Status)` - gRPC error status if email sending failed
+    ///
+    /// # Request Fields
+    ///
+    /// * `username` - The username of the user
+    /// * `email` - The email address to send the notification to
+    /// * `changed_at` - When the password was changed, formatted for display
+    /// * `link` - The link to reset the password if the change was not made by the user
+    ///
+    /// # Response Fields
+    ///
+    /// * `success` - Boolean indicating whether the email was sent successfully
+    #[instrument(skip(self, request))]
+    async fn send_password_changed(
+        &self,
+        request: Request<PasswordChangedRequest>,
+    ) -> Result<Response<PasswordChangedResponse>, Status> {
+        let req = request.into_inner();
+        info!(
+            email = %req.email,
+            username = %req.username,
+            "Received request to send password changed email"
+        );
+
+        let message = self.create_password_changed_mail(&req).await.map_err(|e| {
+            error!(
+                email = %req.email,
+                username = %req.username,
+                error = %e,
+                "Failed to create password changed email"
+            );
+            Status::internal(format!("Could not create email: {}", e))
+        })?;
+
+        self.send_email(message).map_err(|e| {
+            error!(
+                email = %req.email,
+                username = %req.username,
+                error = %e,
+                "Failed to send password changed email"
+            );
+            Status::internal(format!("Could not send email: {}", e))
+        })?;
+
+        info!(
+            email = %req.email,
+            username = %req.username,
+            "Password changed email sent successfully"
+        );
+        let reply = PasswordChangedResponse { success: true };
+        Ok(Response::new(reply))
+    }
+
+    /// Sends an email rendered from a registered template
+    ///
+    /// This gRPC endpoint sends any registered template, so new email types only
+    /// need a template and no proto change. The typed endpoints above are kept
+    /// for existing clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - gRPC request containing the recipient, template name and variables
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response<TemplatedEmailResponse>)` - Success response indicating email was sent
+    /// * `Err(Status)` - `INVALID_ARGUMENT` for an unknown template, an invalid address or a
+    ///   missing unsubscribe token, or `INTERNAL` if the email could not be created or sent
+    ///
+    /// # Request Fields
+    ///
+    /// * `to` - The email address to send the email to
+    /// * `username` - The username of the user, available to the template as `username`
+    /// * `template_name` - The name of the registered template (e.g. "password_changed")
+    /// * `variables` - The values the template renders
+    /// * `language` - The user's language code, selects the template's translation; English if
+    ///   empty, unsupported or the template is not translated to it
+    /// * `from_name` - Display name for the sender, instead of the template's configured one
+    /// * `reply_to` - Address replies are sent to, instead of the template's configured one
+    /// * `unsubscribe_token` - Token identifying the user in the unsubscribe URL, required by
+    ///   non-transactional templates and ignored by transactional ones
+    ///
+    /// # Response Fields
+    ///
+    /// * `success` - Boolean indicating whether the email was sent successfully
+    #[instrument(skip(self, request))]
+    async fn send_templated_email(
+        &self,
+        request: Request<TemplatedEmailRequest>,
+    ) -> Result<Response<TemplatedEmailResponse>, Status> {
+        let req = request.into_inner();
+        info!(
+            email = %req.to,
+            username = %req.username,
+            template_name = %req.template_name,
+            "Received request to send templated email"
+        );
+
+        let data = req
+            .variables
+            .into_iter()
+            .map(|(name, value)| (name, Value::from(value)))
+            .collect();
+        let options = MailOptions {
+            sender: EmailSender {
+                from_name: Some(req.from_name).filter(|name| !name.is_empty()),
+                reply_to: Some(req.reply_to).filter(|reply_to| !reply_to.is_empty()),
+            },
+            unsubscribe_token: Some(req.unsubscribe_token).filter(|token| !token.is_empty()),
+        };
+        let message = self
+            .create_mail(
+                &req.to,
+                &req.username,
+                &req.template_name,
+                &req.language,
+                &options,
+                data,
+            )
+            .await
+            .map_err(|e| {
+                error!(
+                    email = %req.to,
+                    username = %req.username,
+                    template_name = %req.template_name,
+                    error = %e,
+                    "Failed to create templated email"
+                );
+                match e {
+                    EmailError::UnknownTemplate(_)
+                    | EmailError::AddressParse(_)
+                    | EmailError::InvalidUnsubscribeToken => {
+                        Status::invalid_argument(e.to_string())
+                    }
+                    _ => Status::internal(format!("Could not create email: {}", e)),
+                }
+            })?;
+
+        self.send_email(message).map_err(|e| {
+            error!(
+                email = %req.to,
+                username = %req.username,
+                template_name = %req.template_name,
+                error = %e,
+                "Failed to send templated email"
+            );
+            Status::internal(format!("Could not send email: {}", e))
+        })?;
+
+        info!(
+            email = %req.to,
+            username = %req.username,
+            template_name = %req.template_name,
+            "Templated email sent successfully"
+        );
+        let reply = TemplatedEmailResponse { success: true };
+        Ok(Response::new(reply))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
     use super::*;
 
     /// Renders the activation account email template with the given link
@@ -464,6 +1227,37 @@ mod tests {
             .map_err(|e| format!("Template rendering error: {}", e))
     }
 
+    /// Renders the password changed email template with the given details
+    ///
+    /// This is a helper function for testing template rendering logic.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The username to include in the email
+    /// * `changed_at` - The time of the change to include in the email
+    /// * `reset_link` - The password reset link to include in the email
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - Successfully rendered HTML template
+    /// * `Err(String)` - Error occurred during template rendering
+    fn render_password_changed_template(
+        username: &str,
+        changed_at: &str,
+        reset_link: &str,
+    ) -> Result<String, String> {
+        Handlebars::new()
+            .render_template(
+                PASSWORD_CHANGED_TEMPLATE,
+                &json!({
+                    "username": username,
+                    "changed_at": changed_at,
+                    "reset_link": reset_link
+                }),
+            )
+            .map_err(|e| format!("Template rendering error: {}", e))
+    }
+
     #[test]
     fn test_render_activate_account_template() {
         let activation_link = "https://example.com/activate?token=abc123";
@@ -528,4 +1322,643 @@ mod tests {
         let rendered = result.unwrap();
         assert!(rendered.contains("html"));
     }
+
+    #[test]
+    fn test_render_password_changed_template() {
+        let result = render_password_changed_template(
+            "alice",
+            "2025-11-10 12:00 UTC",
+            "https://example.com/login",
+        );
+
+        assert!(result.is_ok());
+        let rendered = result.unwrap();
+        assert!(rendered.contains("html"));
+        assert!(rendered.contains("alice"));
+        assert!(rendered.contains("2025-11-10 12:00 UTC"));
+        assert!(rendered.contains("https://example.com/login"));
+        assert!(rendered.contains("wasn't"));
+    }
+
+    #[test]
+    fn test_render_password_changed_template_escapes_username() {
+        let result =
+            render_password_changed_template("<b>alice</b>", "", "https://example.com/login");
+
+        assert!(result.is_ok());
+        let rendered = result.unwrap();
+        assert!(!rendered.contains("<b>alice</b>"));
+        assert!(rendered.contains("&lt;b&gt;alice&lt;/b&gt;"));
+    }
+
+    #[test]
+    fn test_build_handlebars_embedded_templates() {
+        let handlebars = build_handlebars(None).unwrap();
+
+        for template in &TEMPLATES {
+            assert!(handlebars.has_template(template.name));
+            for translation in template.translations {
+                assert!(handlebars.has_template(&template.html_name(translation.language)));
+                assert!(handlebars.has_template(&template.plain_name(translation.language)));
+            }
+        }
+        assert!(handlebars.has_template("activate_account_es"));
+        assert!(handlebars.has_template("forgot_password_plain_ro"));
+        assert!(!handlebars.dev_mode());
+    }
+
+    #[test]
+    fn test_build_handlebars_reloads_templates_from_disk() {
+        let template_dir =
+            std::env::temp_dir().join(format!("brewget-email-templates-{}", std::process::id()));
+        std::fs::create_dir_all(&template_dir).unwrap();
+        for template in &TEMPLATES {
+            for translation in template.translations {
+                std::fs::write(
+                    template_dir.join(template.file_name(translation.language)),
+                    translation.source,
+                )
+                .unwrap();
+            }
+        }
+
+        let handlebars = build_handlebars(Some(&template_dir)).unwrap();
+        assert!(handlebars.dev_mode());
+
+        let data = json!({ "username": "alice" });
+        let rendered = handlebars.render("password_changed", &data).unwrap();
+        assert!(rendered.contains("alice"));
+
+        // Changes on disk apply to the next render without rebuilding
+        std::fs::write(
+            template_dir.join("password_changed_template.en.html"),
+            "Edited for {{username}}",
+        )
+        .unwrap();
+        let rendered = handlebars.render("password_changed", &data).unwrap();
+        assert_eq!(rendered, "Edited for alice");
+
+        std::fs::remove_dir_all(&template_dir).unwrap();
+    }
+
+    #[test]
+    fn test_build_handlebars_missing_template_dir() {
+        let template_dir = std::env::temp_dir().join("brewget-email-templates-missing");
+        assert!(build_handlebars(Some(&template_dir)).is_err());
+    }
+
+    #[test]
+    fn test_render_templates_show_logo_only_when_configured() {
+        let handlebars = build_handlebars(None).unwrap();
+
+        for template in &TEMPLATES {
+            for translation in template.translations {
+                let name = template.html_name(translation.language);
+                let with_logo = handlebars.render(&name, &json!({ "logo": true })).unwrap();
+                assert!(with_logo.contains("cid:logo"));
+
+                let without_logo = handlebars.render(&name, &json!({})).unwrap();
+                assert!(!without_logo.contains("cid:logo"));
+            }
+        }
+    }
+
+    /// Creates a service that never connects to its SMTP relay
+    fn service() -> Service {
+        service_with_senders(HashMap::new())
+    }
+
+    /// Creates a service with sender overrides that never connects to its SMTP relay
+    fn service_with_senders(email_senders: HashMap<String, EmailSender>) -> Service {
+        Service::new(Arc::new(Config {
+            email_grpc_port: 9001,
+            email_http_port: 8001,
+            smtp_email: "noreply@example.com".to_string(),
+            smtp_name: "brewget".to_string(),
+            smtp_relay: "localhost".to_string(),
+            smtp_username: "username".to_string(),
+            smtp_password: "password".to_string(),
+            email_template_dir: None,
+            email_logo_path: None,
+            email_senders,
+            email_unsubscribe_url: Some("https://example.com/settings/unsubscribe".to_string()),
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_from_registered_template() {
+        let data = Map::from_iter([("reset_link".into(), "https://example.com/login".into())]);
+        let message = service()
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "password_changed",
+                "fr",
+                &MailOptions::default(),
+                data,
+            )
+            .await
+            .unwrap();
+
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("Subject: Your password was changed"));
+        assert!(formatted.contains("To: alice <alice@example.com>"));
+        assert!(formatted.contains("From: brewget <noreply@example.com>"));
+        assert!(!formatted.contains("Reply-To"));
+        assert!(formatted.contains("multipart/alternative"));
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_with_configured_sender() {
+        let service = service_with_senders(HashMap::from([(
+            "activate_account".to_string(),
+            EmailSender {
+                from_name: Some("BrewGet Security".to_string()),
+                reply_to: Some("security@example.com".to_string()),
+            },
+        )]));
+
+        let message = service
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "activate_account",
+                "",
+                &MailOptions::default(),
+                Map::new(),
+            )
+            .await
+            .unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("From: \"BrewGet Security\" <noreply@example.com>"));
+        assert!(formatted.contains("Reply-To: security@example.com"));
+
+        let message = service
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "forgot_password",
+                "",
+                &MailOptions::default(),
+                Map::new(),
+            )
+            .await
+            .unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("From: brewget <noreply@example.com>"));
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_request_sender_overrides_configured_one() {
+        let service = service_with_senders(HashMap::from([(
+            "activate_account".to_string(),
+            EmailSender {
+                from_name: Some("BrewGet Security".to_string()),
+                reply_to: Some("security@example.com".to_string()),
+            },
+        )]));
+        let sender = EmailSender {
+            from_name: Some("BrewGet Reports".to_string()),
+            reply_to: None,
+        };
+
+        let message = service
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "activate_account",
+                "",
+                &MailOptions {
+                    sender,
+                    ..Default::default()
+                },
+                Map::new(),
+            )
+            .await
+            .unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("From: \"BrewGet Reports\" <noreply@example.com>"));
+        assert!(formatted.contains("Reply-To: security@example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_invalid_reply_to() {
+        let sender = EmailSender {
+            from_name: None,
+            reply_to: Some("not an address".to_string()),
+        };
+        let result = service()
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "activate_account",
+                "",
+                &MailOptions {
+                    sender,
+                    ..Default::default()
+                },
+                Map::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(EmailError::AddressParse(_))));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_configured_reply_to() {
+        let config = Config {
+            email_grpc_port: 9001,
+            email_http_port: 8001,
+            smtp_email: "noreply@example.com".to_string(),
+            smtp_name: "brewget".to_string(),
+            smtp_relay: "localhost".to_string(),
+            smtp_username: "username".to_string(),
+            smtp_password: "password".to_string(),
+            email_template_dir: None,
+            email_logo_path: None,
+            email_senders: HashMap::from([(
+                "activate_account".to_string(),
+                EmailSender {
+                    from_name: None,
+                    reply_to: Some("not an address".to_string()),
+                },
+            )]),
+            email_unsubscribe_url: None,
+        };
+        assert!(Service::new(Arc::new(config)).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_sender_for_unknown_template() {
+        let config = Config {
+            email_grpc_port: 9001,
+            email_http_port: 8001,
+            smtp_email: "noreply@example.com".to_string(),
+            smtp_name: "brewget".to_string(),
+            smtp_relay: "localhost".to_string(),
+            smtp_username: "username".to_string(),
+            smtp_password: "password".to_string(),
+            email_template_dir: None,
+            email_logo_path: None,
+            email_senders: HashMap::from([(
+                "activate_acount".to_string(),
+                EmailSender {
+                    from_name: Some("BrewGet Security".to_string()),
+                    reply_to: None,
+                },
+            )]),
+            email_unsubscribe_url: None,
+        };
+        assert!(Service::new(Arc::new(config)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_unknown_template() {
+        let result = service()
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "weekly_summary",
+                "",
+                &MailOptions::default(),
+                Map::new(),
+            )
+            .await;
+
+        assert!(
+            matches!(result, Err(EmailError::UnknownTemplate(name)) if name == "weekly_summary")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_transactional_has_no_unsubscribe_headers() {
+        let options = MailOptions {
+            unsubscribe_token: Some("abc123".to_string()),
+            ..Default::default()
+        };
+
+        for template in &TEMPLATES {
+            assert!(template.transactional);
+            let message = service()
+                .create_mail(
+                    "alice@example.com",
+                    "alice",
+                    template.name,
+                    "",
+                    &options,
+                    Map::new(),
+                )
+                .await
+                .unwrap();
+
+            let formatted = String::from_utf8(message.formatted()).unwrap();
+            assert!(!formatted.contains("List-Unsubscribe"));
+        }
+    }
+
+    #[test]
+    fn test_unsubscribe_url() {
+        assert_eq!(
+            unsubscribe_url("https://example.com/settings/unsubscribe", "abc-123_x").unwrap(),
+            "https://example.com/settings/unsubscribe?token=abc-123_x"
+        );
+        assert_eq!(
+            unsubscribe_url("https://example.com/unsubscribe?list=summary", "abc").unwrap(),
+            "https://example.com/unsubscribe?list=summary&token=abc"
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_url_rejects_invalid_tokens() {
+        for token in ["", "abc&admin=true", "a b", "ä"] {
+            assert!(matches!(
+                unsubscribe_url("https://example.com/unsubscribe", token),
+                Err(EmailError::InvalidUnsubscribeToken)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_with_unsubscribe_headers() {
+        let message = with_unsubscribe_headers(
+            Message::builder()
+                .from("noreply@example.com".parse().unwrap())
+                .to("alice@example.com".parse().unwrap())
+                .subject("Your weekly summary"),
+            "https://example.com/unsubscribe?token=abc".to_string(),
+        )
+        .body("summary".to_string())
+        .unwrap();
+
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(
+            formatted.contains("List-Unsubscribe: <https://example.com/unsubscribe?token=abc>")
+        );
+        assert!(formatted.contains("List-Unsubscribe-Post: List-Unsubscribe=One-Click"));
+        assert_eq!(
+            message.headers().get::<ListUnsubscribe>(),
+            Some(ListUnsubscribe(
+                "https://example.com/unsubscribe?token=abc".to_string()
+            ))
+        );
+        assert_eq!(
+            message.headers().get::<ListUnsubscribePost>(),
+            Some(ListUnsubscribePost)
+        );
+    }
+
+    #[test]
+    fn test_find_template() {
+        let template = find_template("password_changed").unwrap();
+        assert_eq!(
+            template.translation(Language::En).subject,
+            "Your password was changed"
+        );
+        assert!(find_template("weekly_summary").is_none());
+    }
+
+    #[test]
+    fn test_templates_have_english_first() {
+        for template in &TEMPLATES {
+            assert_eq!(template.translations[0].language, Language::En);
+        }
+    }
+
+    #[test]
+    fn test_email_language() {
+        assert_eq!(email_language("es"), Language::Es);
+        assert_eq!(email_language("RO"), Language::Ro);
+        assert_eq!(email_language("ja"), Language::En);
+        assert_eq!(email_language(""), Language::En);
+    }
+
+    #[test]
+    fn test_translation_falls_back_to_english() {
+        let template = find_template("password_changed").unwrap();
+        assert_eq!(template.translation(Language::De).language, Language::En);
+
+        let template = find_template("forgot_password").unwrap();
+        assert_eq!(template.translation(Language::De).language, Language::De);
+    }
+
+    #[test]
+    fn test_render_translated_templates_in_every_language() {
+        let links = [
+            ("activate_account", "activation_link"),
+            ("forgot_password", "forgot_password_link"),
+        ];
+
+        for (name, link_variable) in links {
+            let template = find_template(name).unwrap();
+            for &language in Language::all() {
+                let translation = template.translation(language);
+                assert_eq!(translation.language, language);
+
+                let rendered = Handlebars::new()
+                    .render_template(
+                        translation.source,
+                        &json!({ link_variable: "https://example.com/link/abc123" }),
+                    )
+                    .unwrap();
+                assert!(rendered.contains(&format!("<html lang=\"{}\"", language)));
+                assert!(rendered.contains(&format!("<title>{}</title>", translation.subject)));
+                assert!(rendered.contains("https://example.com/link/abc123"));
+                if language != Language::En {
+                    assert!(!rendered.contains("Copy"));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_translated_plain_templates() {
+        let handlebars = build_handlebars(None).unwrap();
+        let data = json!({ "activation_link": "https://example.com/activate?token=abc" });
+
+        let plain = handlebars
+            .render("activate_account_plain_es", &data)
+            .unwrap();
+        assert_eq!(
+            plain,
+            "Usa el siguiente enlace para activar tu cuenta: https://example.com/activate?token=abc"
+        );
+        let plain = handlebars
+            .render("activate_account_plain_de", &data)
+            .unwrap();
+        assert_eq!(
+            plain,
+            "Verwende den folgenden Link, um dein Konto zu aktivieren: https://example.com/activate?token=abc"
+        );
+    }
+
+    /// Returns the subject of an email
+    fn subject(message: &Message) -> String {
+        message
+            .headers()
+            .get::<lettre::message::header::Subject>()
+            .unwrap()
+            .as_ref()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_create_activate_account_mail_in_every_language() {
+        let service = service();
+
+        for &language in Language::all() {
+            let request = ActivateAccountRequest {
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                link: "https://example.com/activate?token=abc".to_string(),
+                language: language.to_string(),
+            };
+            let message = service
+                .create_activate_account_mail(&request)
+                .await
+                .unwrap();
+
+            let expected = find_template("activate_account")
+                .unwrap()
+                .translation(language)
+                .subject;
+            assert_eq!(subject(&message), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_forgot_password_mail_in_every_language() {
+        let service = service();
+
+        for &language in Language::all() {
+            let request = ForgotPasswordRequest {
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                link: "https://example.com/reset?token=abc".to_string(),
+                language: language.to_string(),
+            };
+            let message = service.create_forgot_password_mail(&request).await.unwrap();
+
+            let expected = find_template("forgot_password")
+                .unwrap()
+                .translation(language)
+                .subject;
+            assert_eq!(subject(&message), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_mail_unknown_language_falls_back_to_english() {
+        let service = service();
+
+        for language in ["", "ja", "pt-BR"] {
+            let request = ActivateAccountRequest {
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                link: "https://example.com/activate?token=abc".to_string(),
+                language: language.to_string(),
+            };
+            let message = service
+                .create_activate_account_mail(&request)
+                .await
+                .unwrap();
+            assert_eq!(subject(&message), "Activate your account");
+        }
+
+        // A template not translated to a supported language is sent in English
+        let message = service
+            .create_mail(
+                "alice@example.com",
+                "alice",
+                "password_changed",
+                "es",
+                &MailOptions::default(),
+                Map::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(subject(&message), "Your password was changed");
+    }
+
+    #[test]
+    fn test_render_plain_templates_without_escaping() {
+        let handlebars = build_handlebars(None).unwrap();
+
+        let plain = handlebars
+            .render(
+                "activate_account_plain",
+                &json!({ "activation_link": "https://example.com/activate?token=abc&param=value" }),
+            )
+            .unwrap();
+        assert_eq!(
+            plain,
+            "Use the following link to activate your account: https://example.com/activate?token=abc&param=value"
+        );
+
+        let plain = handlebars
+            .render(
+                "password_changed_plain",
+                &json!({
+                    "username": "alice",
+                    "changed_at": "2025-11-10 12:00 UTC",
+                    "reset_link": "https://example.com/login"
+                }),
+            )
+            .unwrap();
+        assert!(plain.contains("alice"));
+        assert!(plain.contains("2025-11-10 12:00 UTC"));
+        assert!(plain.ends_with("https://example.com/login"));
+    }
+
+    #[test]
+    fn test_load_logo() {
+        let logo_path =
+            std::env::temp_dir().join(format!("brewget-email-logo-{}.PNG", std::process::id()));
+        std::fs::write(&logo_path, b"\x89PNG").unwrap();
+
+        let logo = load_logo(&logo_path).unwrap();
+        assert_eq!(logo.content, b"\x89PNG");
+        assert_eq!(logo.content_type, ContentType::parse("image/png").unwrap());
+
+        std::fs::remove_file(&logo_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_logo_unsupported_type() {
+        let logo_path = std::env::temp_dir().join("brewget-email-logo.svg");
+        let err = load_logo(&logo_path).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_load_logo_missing_file() {
+        let logo_path = std::env::temp_dir().join("brewget-email-logo-missing.png");
+        assert!(load_logo(&logo_path).is_err());
+    }
+
+    #[test]
+    fn test_build_body_without_logo() {
+        let body = build_body("plain".to_string(), "<p>html</p>".to_string(), None);
+        let formatted = String::from_utf8(body.formatted()).unwrap();
+
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(!formatted.contains("multipart/related"));
+        assert!(!formatted.contains("Content-ID"));
+    }
+
+    #[test]
+    fn test_build_body_with_inline_logo() {
+        let logo = Logo {
+            content: b"\x89PNG".to_vec(),
+            content_type: ContentType::parse("image/png").unwrap(),
+        };
+        let body = build_body("plain".to_string(), "<p>html</p>".to_string(), Some(&logo));
+        let formatted = String::from_utf8(body.formatted()).unwrap();
+
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("multipart/related"));
+        assert!(formatted.contains("Content-ID: <logo>"));
+        assert!(formatted.contains("Content-Disposition: inline"));
+        assert!(formatted.contains("image/png"));
+    }
 }
@@ -0,0 +1,167 @@
+use std::env::var;
+
+use axum::http::Uri;
+
+/// Application configuration loaded from environment variables
+///
+/// # Fields
+///
+/// ## Server Configuration
+/// * `gateway_http_port` - Port number for the HTTP server to listen on
+/// * `cors_url` - Allowed CORS origin URL for frontend integration
+///
+/// ## Upstream Configuration
+/// * `auth_service_url` - Base URL of the auth service HTTP API, `/auth/*` is forwarded to it
+/// * `email_service_url` - Base URL of the email service HTTP API, `/email/*` is forwarded to it
+/// * `settings_service_url` - Base URL of the settings service HTTP API, `/settings/*` is
+///   forwarded to it
+/// * `transaction_service_url` - Base URL of the transaction service HTTP API, `/transactions/*`
+///   is forwarded to it
+/// * `upstream_timeout_ms` - Milliseconds a service gets to start answering a forwarded request
+///   (default: 30000)
+///
+/// ## Authentication Configuration
+/// * `auth_hostname` - Hostname of the auth service for gRPC communication
+/// * `auth_grpc_port` - Port number for the auth service gRPC server
+/// * `internal_grpc_secret` - Shared secret sent to the settings and transaction services with
+///   the verified user of a request
+/// * `token_cache_ttl_seconds` - Seconds a token verified by the auth service is trusted without
+///   verifying it again, capped at the token's expiry, 0 to disable the cache (default: 0)
+#[derive(Clone)]
+pub struct Config {
+    pub gateway_http_port: u32,
+    pub cors_url: String,
+    pub auth_service_url: String,
+    pub email_service_url: String,
+    pub settings_service_url: String,
+    pub transaction_service_url: String,
+    pub upstream_timeout_ms: u64,
+    pub auth_hostname: String,
+    pub auth_grpc_port: u32,
+    pub internal_grpc_secret: String,
+    pub token_cache_ttl_seconds: u64,
+}
+
+impl Config {
+    /// Initializes configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `GATEWAY_HTTP_PORT` - Must be a valid u32 port number
+    /// - `CORS_URL` - Allowed CORS origin URL
+    /// - `AUTH_SERVICE_URL` - Auth service base URL, e.g. `http://localhost:8000`
+    /// - `EMAIL_SERVICE_URL` - Email service base URL, e.g. `http://localhost:8001`
+    /// - `SETTINGS_SERVICE_URL` - Settings service base URL, e.g. `http://localhost:8002`
+    /// - `TRANSACTION_SERVICE_URL` - Transaction service base URL, e.g. `http://localhost:8003`
+    /// - `GATEWAY_UPSTREAM_TIMEOUT_MS` - Upstream response timeout in milliseconds (optional,
+    ///   defaults to 30000)
+    /// - `AUTH_HOSTNAME` - Auth service hostname
+    /// - `AUTH_GRPC_PORT` - Must be a valid u32 port number
+    /// - `INTERNAL_GRPC_SECRET` - Shared secret the settings and transaction services expect
+    /// - `TOKEN_CACHE_TTL_SECONDS` - Seconds a verified token is trusted without verifying it
+    ///   again (optional, defaults to 0, which disables the cache)
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if:
+    /// - Any required environment variable is missing
+    /// - `GATEWAY_HTTP_PORT` or `AUTH_GRPC_PORT` cannot be parsed as u32
+    /// - A service URL is not an `http://` URL
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Config` instance with all values loaded from environment variables.
+    pub fn init() -> Self {
+        let gateway_http_port = var("GATEWAY_HTTP_PORT")
+            .map(|port| port.parse::<u32>())
+            .expect("GATEWAY_HTTP_PORT must be provided.")
+            .expect("GATEWAY_HTTP_PORT must be an u32.");
+        let cors_url = var("CORS_URL").expect("CORS_URL must be provided.");
+        let auth_service_url = service_url("AUTH_SERVICE_URL");
+        let email_service_url = service_url("EMAIL_SERVICE_URL");
+        let settings_service_url = service_url("SETTINGS_SERVICE_URL");
+        let transaction_service_url = service_url("TRANSACTION_SERVICE_URL");
+        let upstream_timeout_ms = var("GATEWAY_UPSTREAM_TIMEOUT_MS")
+            .ok()
+            .and_then(|timeout| timeout.parse::<u64>().ok())
+            .unwrap_or(30_000);
+        let auth_hostname = var("AUTH_HOSTNAME").expect("AUTH_HOSTNAME must be provided.");
+        let auth_grpc_port = var("AUTH_GRPC_PORT")
+            .map(|port| port.parse::<u32>())
+            .expect("AUTH_GRPC_PORT must be provided.")
+            .expect("AUTH_GRPC_PORT must be an u32.");
+        let internal_grpc_secret =
+            var("INTERNAL_GRPC_SECRET").expect("INTERNAL_GRPC_SECRET must be provided.");
+        let token_cache_ttl_seconds = var("TOKEN_CACHE_TTL_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        Self {
+            gateway_http_port,
+            cors_url,
+            auth_service_url,
+            email_service_url,
+            settings_service_url,
+            transaction_service_url,
+            upstream_timeout_ms,
+            auth_hostname,
+            auth_grpc_port,
+            internal_grpc_secret,
+            token_cache_ttl_seconds,
+        }
+    }
+}
+
+/// Reads the base URL of a service from an environment variable
+///
+/// # Arguments
+/// * `name` - Name of the environment variable
+///
+/// # Panics
+///
+/// Panics if the variable is missing or is not an `http://` URL
+///
+/// # Returns
+/// The URL without a trailing slash
+fn service_url(name: &str) -> String {
+    let url = var(name).unwrap_or_else(|_| panic!("{} must be provided.", name));
+    if !is_valid_service_url(&url) {
+        panic!("{} must be an http:// URL.", name);
+    }
+
+    url.trim_end_matches('/').to_string()
+}
+
+/// Checks that a service URL can be forwarded to
+///
+/// The services are reached over plain HTTP inside the deployment, so only
+/// `http://` URLs with a host are accepted.
+///
+/// # Arguments
+/// * `url` - The URL to check
+///
+/// # Returns
+/// `true` if the URL is an `http://` URL with a host
+fn is_valid_service_url(url: &str) -> bool {
+    url.parse::<Uri>()
+        .is_ok_and(|uri| uri.scheme_str() == Some("http") && uri.host().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_service_urls_are_valid() {
+        assert!(is_valid_service_url("http://localhost:8000"));
+        assert!(is_valid_service_url("http://auth-service:8000/"));
+    }
+
+    #[test]
+    fn test_other_service_urls_are_invalid() {
+        assert!(!is_valid_service_url("https://auth-service:8000"));
+        assert!(!is_valid_service_url("localhost:8000"));
+        assert!(!is_valid_service_url("not a url"));
+    }
+}
@@ -0,0 +1,166 @@
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header, header::InvalidHeaderValue};
+use chrono::{DateTime, Utc};
+use shared_types::{
+    Error, TranslationKey,
+    identity::{INTERNAL_SECRET_HEADER, USER_ID_HEADER},
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+use crate::{
+    grpc::auth_service::service::{VerifyTokenRequest, auth_service_client::AuthServiceClient},
+    token_cache::{TokenCache, VerifiedToken},
+};
+
+/// Verifies the access tokens of requests and tells the services who sent them
+///
+/// The settings and transaction services trust the `X-User-Id` header only
+/// when the internal secret comes with it, so they do not each ask the auth
+/// service again.
+///
+/// # Fields
+///
+/// * `auth_service` - Client of the auth service gRPC server
+/// * `internal_secret` - The internal shared secret, sent with the verified user
+/// * `token_cache` - TTL cache of access tokens verified by the auth service
+pub struct Identity {
+    auth_service: AuthServiceClient<Channel>,
+    internal_secret: HeaderValue,
+    token_cache: TokenCache,
+}
+
+impl Identity {
+    /// Creates the token verification of the gateway
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_service` - Client of the auth service gRPC server
+    /// * `internal_secret` - The internal shared secret
+    /// * `token_cache_ttl_seconds` - How long a verified token is trusted at most, 0 disables
+    ///   caching
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Identity)` - The token verification
+    /// * `Err(InvalidHeaderValue)` - If the secret cannot be sent as a header
+    pub fn new(
+        auth_service: AuthServiceClient<Channel>,
+        internal_secret: &str,
+        token_cache_ttl_seconds: u64,
+    ) -> Result<Self, InvalidHeaderValue> {
+        let mut internal_secret = HeaderValue::from_str(internal_secret)?;
+        internal_secret.set_sensitive(true);
+
+        Ok(Self {
+            auth_service,
+            internal_secret,
+            token_cache: TokenCache::new(token_cache_ttl_seconds),
+        })
+    }
+
+    /// Adds the verified user of a request to its headers
+    ///
+    /// Requests without a Bearer token are left alone, so the service decides
+    /// whether the route needs a user. The identity headers must already be
+    /// stripped from the client's request.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - Headers of the request forwarded to the service
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the request has no token or its token is valid
+    /// * `Err(Error)` - 401 if the token is invalid or expired, 500 if the auth service is
+    ///   unreachable
+    pub async fn identify(&self, headers: &mut HeaderMap) -> Result<(), Error> {
+        let Some(token) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(String::from)
+        else {
+            return Ok(());
+        };
+
+        let user_id = self
+            .token_cache
+            .get_or_verify(&token, || self.verify_token(&token))
+            .await?;
+        tracing::debug!("Token verified for user: {}", user_id);
+
+        let user_id =
+            HeaderValue::from_str(&user_id.to_string()).expect("A UUID is a valid header value");
+        headers.insert(USER_ID_HEADER, user_id);
+        headers.insert(INTERNAL_SECRET_HEADER, self.internal_secret.clone());
+        Ok(())
+    }
+
+    /// Verifies a token with the auth service
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The access token to verify
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifiedToken)` - The user the token belongs to and when the token expires
+    /// * `Err(Error)` - 401 if the token is invalid or expired, 500 if the auth service is
+    ///   unreachable
+    async fn verify_token(&self, token: &str) -> Result<VerifiedToken, Error> {
+        let request = tonic::Request::new(VerifyTokenRequest {
+            token: token.to_string(),
+        });
+
+        let response = self
+            .auth_service
+            .clone()
+            .verify_token(request)
+            .await
+            .map_err(|e| -> Error {
+                tracing::error!("Failed to verify token: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    TranslationKey::InternalServerError,
+                )
+                    .into()
+            })?
+            .into_inner();
+
+        let user_id = response.user_id.ok_or_else(|| -> Error {
+            let error_reason = response.error_reason.as_deref().unwrap_or("TOKEN_INVALID");
+            tracing::warn!("Token validation failed - {}", error_reason);
+
+            if error_reason == "TOKEN_EXPIRED" {
+                (StatusCode::UNAUTHORIZED, TranslationKey::TokenExpired).into()
+            } else {
+                (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid).into()
+            }
+        })?;
+
+        let user_id = Uuid::parse_str(&user_id).map_err(|e| -> Error {
+            tracing::error!("Invalid user ID format: {}", e);
+            (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid).into()
+        })?;
+
+        // Without an expiry the token is treated as expiring now, so it is never cached
+        let expires_at = response
+            .expires_at
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+            .unwrap_or_else(Utc::now);
+
+        Ok(VerifiedToken {
+            user_id,
+            expires_at,
+        })
+    }
+}
+
+/// Removes the identity headers a client sent
+///
+/// Only the gateway may tell a service who sent a request, so these headers
+/// are dropped from every request before it is forwarded.
+pub fn remove_identity_headers(headers: &mut HeaderMap) {
+    headers.remove(USER_ID_HEADER);
+    headers.remove(INTERNAL_SECRET_HEADER);
+}
@@ -0,0 +1,58 @@
+use crate::{config::Config, routes::make_app};
+
+mod config;
+mod grpc;
+mod health;
+mod identity;
+mod proxy;
+mod routes;
+mod token_cache;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize tracing/logging
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    tracing::info!("🚀 Starting Gateway...");
+
+    // Load environment variables from .env file
+    dotenv::dotenv().ok();
+
+    // Initialize configuration from environment variables
+    let config = Config::init();
+    tracing::info!("✅ Configuration loaded successfully");
+    tracing::debug!(
+        http_port = config.gateway_http_port,
+        auth_service = %config.auth_service_url,
+        email_service = %config.email_service_url,
+        settings_service = %config.settings_service_url,
+        transaction_service = %config.transaction_service_url,
+        auth_grpc = %format!("{}:{}", config.auth_hostname, config.auth_grpc_port),
+        "Configuration details"
+    );
+
+    // Create the Axum application forwarding to every service
+    let app = make_app(&config)?;
+    tracing::info!("✅ HTTP routes configured");
+
+    // Bind TCP listener to the configured HTTP port
+    let http_listener =
+        tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.gateway_http_port))
+            .await
+            .expect("Could not bind TcpListener for HTTP.");
+    tracing::info!(
+        "🚀 HTTP Server started on port {}",
+        http_listener.local_addr()?.port()
+    );
+
+    axum::serve(http_listener, app)
+        .await
+        .expect("Could not serve axum server.");
+
+    Ok(())
+}
@@ -0,0 +1,448 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, Uri, header},
+    response::Response,
+    routing::any,
+};
+use hyper_util::client::legacy::{Client, connect::HttpConnector};
+use shared_types::{Error, TranslationKey};
+
+use crate::identity::{self, Identity};
+
+/// HTTP client the gateway forwards requests with
+pub type HttpClient = Client<HttpConnector, Body>;
+
+/// Headers that only apply to a single connection and are never forwarded
+const HOP_BY_HOP_HEADERS: [header::HeaderName; 7] = [
+    header::CONNECTION,
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+];
+
+/// A service requests are forwarded to
+///
+/// # Fields
+///
+/// * `url` - Base URL of the service, without a trailing slash
+/// * `client` - HTTP client used for the forwarded requests
+/// * `timeout` - How long the service gets to start answering
+/// * `identity` - Verifies the tokens of requests to services that need the user
+struct Upstream {
+    url: String,
+    client: HttpClient,
+    timeout: Duration,
+    identity: Option<Arc<Identity>>,
+}
+
+/// Creates a router forwarding every request to a service
+///
+/// The router is nested under the service's prefix, so the prefix is already
+/// stripped from the path that gets forwarded.
+///
+/// # Arguments
+///
+/// * `url` - Base URL of the service, without a trailing slash
+/// * `client` - HTTP client used for the forwarded requests
+/// * `timeout` - How long the service gets to start answering
+/// * `identity` - Verifies the tokens of requests and sends the service their user, `None` for
+///   services that verify tokens themselves
+///
+/// # Returns
+///
+/// Returns an Axum router forwarding all methods and paths to the service.
+pub fn get_router(
+    url: String,
+    client: HttpClient,
+    timeout: Duration,
+    identity: Option<Arc<Identity>>,
+) -> Router {
+    let upstream = Arc::new(Upstream {
+        url,
+        client,
+        timeout,
+        identity,
+    });
+
+    Router::new()
+        .route("/", any(forward))
+        .route("/{*path}", any(forward))
+        .with_state(upstream)
+}
+
+/// Creates the HTTP client shared by all upstreams
+pub fn make_client() -> HttpClient {
+    Client::builder(hyper_util::rt::TokioExecutor::new()).build_http()
+}
+
+/// Forwards a request to the service and returns its response
+///
+/// All end-to-end headers, including `Authorization`, are forwarded
+/// unchanged, except the identity headers a client sent. For services that
+/// need the user, the token is verified and the verified user is sent
+/// instead. CORS headers set by the service are dropped from the response,
+/// since the gateway answers CORS for every service.
+///
+/// # Arguments
+///
+/// * `upstream` - The service to forward to
+/// * `req` - The incoming request, with the service's prefix stripped
+///
+/// # Returns
+///
+/// * `Ok(Response)` - The response of the service
+/// * `Err(Error)` - 401 if the token is invalid, 502 if the service cannot be reached, 504 if it
+///   does not answer in time
+async fn forward(
+    State(upstream): State<Arc<Upstream>>,
+    mut req: Request,
+) -> Result<Response, Error> {
+    let path_and_query = req
+        .uri()
+        .path_and_query()
+        .map_or("/", |path_and_query| path_and_query.as_str());
+    let uri = format!("{}{}", upstream.url, path_and_query)
+        .parse::<Uri>()
+        .map_err(|e| -> Error {
+            tracing::warn!("Could not build upstream URI: {}", e);
+            (StatusCode::BAD_REQUEST, TranslationKey::SomethingWentWrong).into()
+        })?;
+
+    tracing::debug!("Forwarding {} {}", req.method(), uri);
+    *req.uri_mut() = uri;
+    remove_hop_by_hop_headers(req.headers_mut());
+    // The client sets the Host of the service from the URI
+    req.headers_mut().remove(header::HOST);
+    identity::remove_identity_headers(req.headers_mut());
+    if let Some(identity) = &upstream.identity {
+        identity.identify(req.headers_mut()).await?;
+    }
+
+    let response = tokio::time::timeout(upstream.timeout, upstream.client.request(req))
+        .await
+        .map_err(|_| -> Error {
+            tracing::error!("{} did not answer in time", upstream.url);
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                TranslationKey::InternalServerError,
+            )
+                .into()
+        })?
+        .map_err(|e| -> Error {
+            tracing::error!("Could not reach {}: {}", upstream.url, e);
+            (StatusCode::BAD_GATEWAY, TranslationKey::InternalServerError).into()
+        })?;
+
+    let (mut parts, body) = response.into_parts();
+    remove_hop_by_hop_headers(&mut parts.headers);
+    remove_cors_headers(&mut parts.headers);
+
+    Ok(Response::from_parts(parts, Body::new(body)))
+}
+
+/// Removes the headers that only apply to a single connection
+fn remove_hop_by_hop_headers(headers: &mut HeaderMap) {
+    for name in HOP_BY_HOP_HEADERS {
+        headers.remove(name);
+    }
+    headers.remove("keep-alive");
+}
+
+/// Removes the CORS headers of a service response
+fn remove_cors_headers(headers: &mut HeaderMap) {
+    let cors_headers: Vec<_> = headers
+        .keys()
+        .filter(|name| name.as_str().starts_with("access-control-"))
+        .cloned()
+        .collect();
+
+    for name in cors_headers {
+        headers.remove(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, http::HeaderValue, response::IntoResponse, routing::get};
+    use shared_types::identity::{INTERNAL_SECRET_HEADER, USER_ID_HEADER};
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Endpoint, Server};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::grpc::auth_service::service::{
+        GetUserInfoRequest, GetUserInfoResponse, VerifyTokenRequest, VerifyTokenResponse,
+        auth_service_client::AuthServiceClient,
+        auth_service_server::{AuthService, AuthServiceServer},
+    };
+
+    const USER_ID: &str = "6f1c2f4e-8b7a-4c1d-9e2f-3a4b5c6d7e8f";
+    const SPOOFED_USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+    /// Accepts only the token `valid`, which belongs to `USER_ID`
+    struct MockAuthService;
+
+    #[tonic::async_trait]
+    impl AuthService for MockAuthService {
+        async fn verify_token(
+            &self,
+            request: tonic::Request<VerifyTokenRequest>,
+        ) -> Result<tonic::Response<VerifyTokenResponse>, tonic::Status> {
+            let response = if request.get_ref().token == "valid" {
+                VerifyTokenResponse {
+                    user_id: Some(USER_ID.to_string()),
+                    error_reason: None,
+                    expires_at: Some(chrono::Utc::now().timestamp() + 60),
+                }
+            } else {
+                VerifyTokenResponse {
+                    user_id: None,
+                    error_reason: Some("TOKEN_INVALID".to_string()),
+                    expires_at: None,
+                }
+            };
+
+            Ok(tonic::Response::new(response))
+        }
+
+        async fn get_user_info(
+            &self,
+            _request: tonic::Request<GetUserInfoRequest>,
+        ) -> Result<tonic::Response<GetUserInfoResponse>, tonic::Status> {
+            Err(tonic::Status::unimplemented("not mocked"))
+        }
+    }
+
+    /// Reads a header of the request as a string
+    fn header_str(req: &Request, name: &str) -> Option<String> {
+        req.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    }
+
+    /// Echoes the path, query, Authorization and identity headers of the request
+    async fn echo(req: Request) -> impl IntoResponse {
+        (
+            [(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")],
+            Json(serde_json::json!({
+                "path": req.uri().path(),
+                "query": req.uri().query(),
+                "authorization": header_str(&req, header::AUTHORIZATION.as_str()),
+                "user_id": header_str(&req, USER_ID_HEADER),
+                "internal_secret": header_str(&req, INTERNAL_SECRET_HEADER),
+            })),
+        )
+    }
+
+    /// Never answers within the timeouts used by the tests
+    async fn hang() -> StatusCode {
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        StatusCode::OK
+    }
+
+    /// Starts a mock service and returns its base URL
+    async fn mock_service() -> String {
+        let app = Router::new().route("/slow", get(hang)).fallback(echo);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        format!("http://{}", address)
+    }
+
+    /// Starts a mock auth service and returns the token verification using it
+    async fn mock_identity() -> Arc<Identity> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(AuthServiceServer::new(MockAuthService))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        let channel = Endpoint::from_shared(url).unwrap().connect_lazy();
+        Arc::new(Identity::new(AuthServiceClient::new(channel), "secret", 0).unwrap())
+    }
+
+    fn gateway(url: String) -> Router {
+        gateway_with_identity(url, None)
+    }
+
+    fn gateway_with_identity(url: String, identity: Option<Arc<Identity>>) -> Router {
+        Router::new().nest(
+            "/settings",
+            get_router(url, make_client(), Duration::from_millis(500), identity),
+        )
+    }
+
+    async fn json_body(response: Response) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_forwards_path_query_and_authorization() {
+        let response = gateway(mock_service().await)
+            .oneshot(
+                Request::get("/settings/user/alarms?limit=5")
+                    .header(header::AUTHORIZATION, "Bearer token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["path"], "/user/alarms");
+        assert_eq!(body["query"], "limit=5");
+        assert_eq!(body["authorization"], "Bearer token");
+    }
+
+    #[tokio::test]
+    async fn test_forwards_prefix_root() {
+        let response = gateway(mock_service().await)
+            .oneshot(Request::get("/settings").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(json_body(response).await["path"], "/");
+    }
+
+    #[tokio::test]
+    async fn test_drops_service_cors_headers() {
+        let response = gateway(mock_service().await)
+            .oneshot(Request::get("/settings/user").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert!(
+            !response
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drops_spoofed_user_id() {
+        let response = gateway(mock_service().await)
+            .oneshot(
+                Request::get("/settings/user")
+                    .header(USER_ID_HEADER, SPOOFED_USER_ID)
+                    .header(INTERNAL_SECRET_HEADER, "secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert!(body["user_id"].is_null());
+        assert!(body["internal_secret"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_replaces_spoofed_user_id_with_verified_user() {
+        let response = gateway_with_identity(mock_service().await, Some(mock_identity().await))
+            .oneshot(
+                Request::get("/settings/user")
+                    .header(header::AUTHORIZATION, "Bearer valid")
+                    .header(USER_ID_HEADER, SPOOFED_USER_ID)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["user_id"], USER_ID);
+        assert_eq!(body["internal_secret"], "secret");
+    }
+
+    #[tokio::test]
+    async fn test_request_without_token_has_no_user() {
+        let response = gateway_with_identity(mock_service().await, Some(mock_identity().await))
+            .oneshot(
+                Request::get("/settings/user")
+                    .header(USER_ID_HEADER, SPOOFED_USER_ID)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(json_body(response).await["user_id"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_token_is_unauthorized() {
+        let response = gateway_with_identity(mock_service().await, Some(mock_identity().await))
+            .oneshot(
+                Request::get("/settings/user")
+                    .header(header::AUTHORIZATION, "Bearer invalid")
+                    .header(USER_ID_HEADER, SPOOFED_USER_ID)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_service_is_bad_gateway() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let response = gateway(url)
+            .oneshot(Request::get("/settings/user").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    }
+
+    #[tokio::test]
+    async fn test_slow_service_times_out() {
+        let response = gateway(mock_service().await)
+            .oneshot(Request::get("/settings/slow").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[test]
+    fn test_remove_cors_headers_keeps_other_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            HeaderValue::from_static("*"),
+        );
+        headers.insert(
+            header::ACCESS_CONTROL_EXPOSE_HEADERS,
+            HeaderValue::from_static("etag"),
+        );
+        headers.insert(header::ETAG, HeaderValue::from_static("\"1\""));
+
+        remove_cors_headers(&mut headers);
+
+        assert_eq!(headers.len(), 1);
+        assert!(headers.contains_key(header::ETAG));
+    }
+}
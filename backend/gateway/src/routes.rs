@@ -0,0 +1,170 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Router,
+    http::{
+        HeaderName, HeaderValue, Method,
+        header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MATCH, RETRY_AFTER},
+    },
+};
+use tonic::transport::Endpoint;
+use tower_http::cors::CorsLayer;
+
+use crate::{
+    config::Config, grpc::auth_service::service::auth_service_client::AuthServiceClient, health,
+    identity::Identity, proxy,
+};
+
+/// Header the settings service reads the device of a request from
+const DEVICE_ID_HEADER: &str = "x-device-id";
+
+/// Creates the Axum application forwarding to every service
+///
+/// CORS is answered here for all services, so preflight requests never
+/// reach them and their own CORS headers are dropped. Requests to the
+/// settings and transaction services get their token verified here, and the
+/// services trust the user the gateway sends them.
+///
+/// # Arguments
+///
+/// * `config` - Gateway configuration with the service URLs
+///
+/// # Returns
+///
+/// * `Ok(Router)` - The configured router
+/// * `Err(Box<dyn std::error::Error>)` - If `CORS_URL` or `INTERNAL_GRPC_SECRET` is not a valid
+///   header value, or the auth service URL is invalid
+///
+/// # Routes
+///
+/// - `/health` - Health check of the gateway
+/// - `/auth/*` - Forwarded to the auth service
+/// - `/email/*` - Forwarded to the email service
+/// - `/settings/*` - Forwarded to the settings service
+/// - `/transactions/*` - Forwarded to the transaction service
+pub fn make_app(config: &Config) -> Result<Router, Box<dyn std::error::Error>> {
+    let cors = HeaderValue::from_str(&config.cors_url)?;
+
+    let cors = CorsLayer::new()
+        .allow_origin(cors)
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PATCH,
+            Method::PUT,
+            Method::DELETE,
+        ])
+        .allow_credentials(true)
+        .allow_headers([
+            AUTHORIZATION,
+            ACCEPT,
+            CONTENT_TYPE,
+            IF_MATCH,
+            HeaderName::from_static(DEVICE_ID_HEADER),
+        ])
+        .expose_headers([ETAG, RETRY_AFTER]);
+
+    let client = proxy::make_client();
+    let timeout = Duration::from_millis(config.upstream_timeout_ms);
+
+    // Connect lazily, so the gateway starts before the auth service
+    let auth_service_url = format!("{}:{}", config.auth_hostname, config.auth_grpc_port);
+    let auth_channel = Endpoint::from_shared(auth_service_url)?
+        .timeout(timeout)
+        .connect_lazy();
+    let identity = Arc::new(Identity::new(
+        AuthServiceClient::new(auth_channel),
+        &config.internal_grpc_secret,
+        config.token_cache_ttl_seconds,
+    )?);
+
+    let upstream = |url: &String, identity: Option<&Arc<Identity>>| {
+        proxy::get_router(url.clone(), client.clone(), timeout, identity.cloned())
+    };
+
+    let router = Router::new()
+        .nest("/health", health::get_router())
+        .nest("/auth", upstream(&config.auth_service_url, None))
+        .nest("/email", upstream(&config.email_service_url, None))
+        .nest(
+            "/settings",
+            upstream(&config.settings_service_url, Some(&identity)),
+        )
+        .nest(
+            "/transactions",
+            upstream(&config.transaction_service_url, Some(&identity)),
+        )
+        .layer(cors);
+    Ok(router)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, extract::Request, http::StatusCode};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn config() -> Config {
+        // Nothing listens on port 9, so a forwarded request would fail
+        let unreachable = String::from("http://127.0.0.1:9");
+
+        Config {
+            gateway_http_port: 3000,
+            cors_url: "http://localhost:5173".into(),
+            auth_service_url: unreachable.clone(),
+            email_service_url: unreachable.clone(),
+            settings_service_url: unreachable.clone(),
+            transaction_service_url: unreachable,
+            upstream_timeout_ms: 500,
+            auth_hostname: "http://127.0.0.1".into(),
+            auth_grpc_port: 9,
+            internal_grpc_secret: "secret".into(),
+            token_cache_ttl_seconds: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight_is_answered_by_the_gateway() {
+        let response = make_app(&config())
+            .unwrap()
+            .oneshot(
+                Request::options("/settings/user")
+                    .header("origin", "http://localhost:5173")
+                    .header("access-control-request-method", "PATCH")
+                    .header("access-control-request-headers", "if-match,x-device-id")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["access-control-allow-origin"],
+            "http://localhost:5173"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_is_not_forwarded() {
+        let response = make_app(&config())
+            .unwrap()
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_prefix_is_not_found() {
+        let response = make_app(&config())
+            .unwrap()
+            .oneshot(Request::get("/unknown/path").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use moka::{Expiry, future::Cache};
+use shared_types::Error;
+use uuid::Uuid;
+
+/// Maximum number of verified tokens kept
+const TOKEN_CACHE_CAPACITY: u64 = 100_000;
+
+/// A token the auth service accepted
+///
+/// # Fields
+///
+/// * `user_id` - The ID of the user the token belongs to
+/// * `expires_at` - When the token itself expires
+#[derive(Clone, Copy, Debug)]
+pub struct VerifiedToken {
+    pub user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Expires each cached token no later than the token itself
+struct TokenExpiry;
+
+impl Expiry<String, VerifiedToken> for TokenExpiry {
+    fn expire_after_create(
+        &self,
+        _token: &String,
+        verified: &VerifiedToken,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(time_until(verified.expires_at, Utc::now()))
+    }
+}
+
+/// Returns how long until a token expires, zero if it already has
+fn time_until(expires_at: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+    (expires_at - now).to_std().unwrap_or(Duration::ZERO)
+}
+
+/// In-process cache of access tokens verified by the auth service
+///
+/// Only successful verifications are cached, so an invalid token is checked
+/// again on every request. A cached token stays accepted until its entry
+/// expires, even if the user logs out or is deactivated in the meantime, so
+/// the cache is disabled by default. An entry never outlives its token.
+///
+/// # Fields
+///
+/// * `cache` - The verified tokens, `None` if caching is disabled
+pub struct TokenCache {
+    cache: Option<Cache<String, VerifiedToken>>,
+}
+
+impl TokenCache {
+    /// Creates a token cache
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl_seconds` - How long a verified token is trusted at most, 0 disables caching
+    pub fn new(ttl_seconds: u64) -> Self {
+        let cache = (ttl_seconds > 0).then(|| {
+            Cache::builder()
+                .max_capacity(TOKEN_CACHE_CAPACITY)
+                .time_to_live(Duration::from_secs(ttl_seconds))
+                .expire_after(TokenExpiry)
+                .build()
+        });
+
+        Self { cache }
+    }
+
+    /// Returns the user of a token, verifying it on a miss
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The access token of the request
+    /// * `verify` - Verifies the token with the auth service
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Uuid)` - The ID of the user the token belongs to
+    /// * `Err(Error)` - The error returned by `verify`
+    pub async fn get_or_verify<F, Fut>(&self, token: &str, verify: F) -> Result<Uuid, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<VerifiedToken, Error>>,
+    {
+        let Some(cache) = &self.cache else {
+            return verify().await.map(|verified| verified.user_id);
+        };
+
+        if let Some(verified) = cache.get(token).await {
+            tracing::debug!("Token found in cache for user: {}", verified.user_id);
+            return Ok(verified.user_id);
+        }
+
+        let verified = verify().await?;
+        cache.insert(token.to_string(), verified).await;
+        Ok(verified.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::http::StatusCode;
+
+    use shared_types::TranslationKey;
+
+    use super::*;
+
+    fn verified(user_id: Uuid) -> VerifiedToken {
+        VerifiedToken {
+            user_id,
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repeated_requests_verify_once() {
+        let cache = TokenCache::new(60);
+        let user_id = Uuid::new_v4();
+        let verify_calls = AtomicUsize::new(0);
+
+        for _ in 0..5 {
+            let verified = cache
+                .get_or_verify("token", || async {
+                    verify_calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(verified(user_id))
+                })
+                .await
+                .unwrap();
+            assert_eq!(verified, user_id);
+        }
+
+        assert_eq!(verify_calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tokens_are_cached_separately() {
+        let cache = TokenCache::new(60);
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+
+        cache
+            .get_or_verify("first", || async { Ok(verified(first)) })
+            .await
+            .unwrap();
+        let verified = cache
+            .get_or_verify("second", || async { Ok(verified(second)) })
+            .await
+            .unwrap();
+
+        assert_eq!(verified, second);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_tokens_are_not_cached() {
+        let cache = TokenCache::new(60);
+        let verify_calls = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            let result = cache
+                .get_or_verify("expired", || async {
+                    verify_calls.fetch_add(1, Ordering::Relaxed);
+                    Err(Error::new(
+                        StatusCode::UNAUTHORIZED,
+                        TranslationKey::TokenExpired,
+                    ))
+                })
+                .await;
+            assert!(result.is_err());
+        }
+
+        assert_eq!(verify_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_always_verifies() {
+        let cache = TokenCache::new(0);
+        let user_id = Uuid::new_v4();
+        let verify_calls = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            cache
+                .get_or_verify("token", || async {
+                    verify_calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(verified(user_id))
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(verify_calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_are_not_served_from_cache() {
+        let cache = TokenCache::new(60);
+        let user_id = Uuid::new_v4();
+        let verify_calls = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            cache
+                .get_or_verify("token", || async {
+                    verify_calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(VerifiedToken {
+                        user_id,
+                        expires_at: Utc::now() - chrono::Duration::seconds(1),
+                    })
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(verify_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_time_until_token_expiry() {
+        let now = Utc::now();
+
+        assert_eq!(
+            time_until(now + chrono::Duration::seconds(30), now),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            time_until(now - chrono::Duration::seconds(30), now),
+            Duration::ZERO
+        );
+    }
+}
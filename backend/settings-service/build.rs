@@ -2,7 +2,7 @@ use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
     tonic_prost_build::configure()
-        .compile_protos(&["../proto/auth_service.proto"], &["../proto"])?;
+        .compile_protos(&["../proto/settings_service.proto"], &["../proto"])?;
 
     Ok(())
 }
@@ -0,0 +1,5 @@
+-- Restore the original currency constraint
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_currency;
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_currency CHECK (currency IN ('usd', 'eur', 'ron'));
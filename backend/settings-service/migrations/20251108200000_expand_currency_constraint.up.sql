@@ -0,0 +1,5 @@
+-- Allow every currency of the shared Currency enum (stored lowercase)
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_currency;
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_currency CHECK (currency IN ('usd', 'eur', 'gbp', 'cad', 'jpy', 'ron'));
@@ -0,0 +1,3 @@
+-- IANA timezone of the user, validated by the service
+ALTER TABLE user_settings
+ADD COLUMN timezone VARCHAR(64) NOT NULL DEFAULT 'UTC';
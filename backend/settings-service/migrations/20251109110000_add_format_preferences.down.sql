@@ -0,0 +1,6 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_number_format;
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_date_format;
+
+ALTER TABLE user_settings
+DROP COLUMN IF EXISTS number_format,
+DROP COLUMN IF EXISTS date_format;
@@ -0,0 +1,10 @@
+-- Date and number format preferences, values of the shared DateFormat and NumberFormat enums
+ALTER TABLE user_settings
+ADD COLUMN date_format VARCHAR(10) NOT NULL DEFAULT 'ISO',
+ADD COLUMN number_format VARCHAR(20) NOT NULL DEFAULT 'dot-decimal';
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_date_format CHECK (date_format IN ('ISO', 'DMY', 'MDY'));
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_number_format CHECK (number_format IN ('dot-decimal', 'comma-decimal'));
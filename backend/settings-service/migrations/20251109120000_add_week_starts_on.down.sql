@@ -0,0 +1,3 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_week_starts_on;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS week_starts_on;
@@ -0,0 +1,6 @@
+-- First day of the week used by weekly aggregations, value of the shared WeekStart enum
+ALTER TABLE user_settings
+ADD COLUMN week_starts_on VARCHAR(10) NOT NULL DEFAULT 'Monday';
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_week_starts_on CHECK (week_starts_on IN ('Monday', 'Sunday'));
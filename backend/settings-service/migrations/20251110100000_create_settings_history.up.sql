@@ -0,0 +1,17 @@
+-- Record every change made to a user's settings
+CREATE TABLE settings_history (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES user_settings(user_id) ON DELETE CASCADE,
+    changes JSONB NOT NULL,
+    source VARCHAR(20) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT valid_source CHECK (source IN ('update', 'reset'))
+);
+
+-- Index for listing a user's history newest-first
+CREATE INDEX idx_settings_history_user_changed_at ON settings_history(user_id, changed_at DESC);
+
+-- Index for the retention sweep
+CREATE INDEX idx_settings_history_changed_at ON settings_history(changed_at);
+
+COMMENT ON COLUMN settings_history.changes IS 'Changed fields mapped to their old and new values, e.g. {"currency": {"old": "usd", "new": "eur"}}';
@@ -0,0 +1,3 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_budget_period;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS budget_period;
@@ -0,0 +1,6 @@
+-- Default period of new budgets, value of the shared BudgetPeriod enum
+ALTER TABLE user_settings
+ADD COLUMN budget_period VARCHAR(10) NOT NULL DEFAULT 'Monthly';
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_budget_period CHECK (budget_period IN ('Weekly', 'Monthly'));
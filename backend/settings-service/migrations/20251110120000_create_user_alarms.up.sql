@@ -0,0 +1,23 @@
+-- Named alarms of a user, replacing the single alarm stored on user_settings
+CREATE TABLE user_alarms (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES user_settings(user_id) ON DELETE CASCADE,
+    label VARCHAR(50) NOT NULL,
+    time TIME NOT NULL,
+    offset_minutes INT NOT NULL DEFAULT 0,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    days_of_week SMALLINT NOT NULL DEFAULT 127,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT valid_days_of_week CHECK (days_of_week BETWEEN 1 AND 127)
+);
+
+-- Index for listing a user's alarms in creation order
+CREATE INDEX idx_user_alarms_user_created_at ON user_alarms(user_id, created_at, id);
+
+COMMENT ON COLUMN user_alarms.days_of_week IS 'Bitmask of the days the alarm rings on, bit 0 is Monday and bit 6 is Sunday';
+
+-- Carry enabled legacy alarms over, they become the user's first alarm
+INSERT INTO user_alarms (user_id, label, time, offset_minutes, enabled)
+SELECT user_id, 'Alarm', alarm_time, alarm_offset_minutes, alarm_set
+FROM user_settings
+WHERE alarm_set = TRUE;
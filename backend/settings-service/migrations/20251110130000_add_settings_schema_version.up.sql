@@ -0,0 +1,6 @@
+-- Version of the settings schema a row was written with
+-- Defaults to 1 so rows inserted by older service versions are upgraded on read
+ALTER TABLE user_settings ADD COLUMN schema_version INT NOT NULL DEFAULT 1;
+
+-- Existing rows already have every column of version 2
+UPDATE user_settings SET schema_version = 2;
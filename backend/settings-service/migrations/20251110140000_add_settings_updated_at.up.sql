@@ -0,0 +1,2 @@
+-- When the settings were last written, used as their version for If-Match checks
+ALTER TABLE user_settings ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
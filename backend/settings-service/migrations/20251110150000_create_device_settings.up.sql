@@ -0,0 +1,11 @@
+-- Settings overridden on a single device of a user
+CREATE TABLE device_settings (
+    user_id UUID NOT NULL REFERENCES user_settings(user_id) ON DELETE CASCADE,
+    device_id UUID NOT NULL,
+    overrides JSONB NOT NULL DEFAULT '{}',
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, device_id)
+);
+
+-- Index for removing devices that were not seen for the retention period
+CREATE INDEX idx_device_settings_last_seen_at ON device_settings(last_seen_at);
@@ -0,0 +1,11 @@
+-- Push notification tokens registered by the devices of a user
+CREATE TABLE push_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES user_settings(user_id) ON DELETE CASCADE,
+    platform VARCHAR(10) NOT NULL,
+    token TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT valid_push_platform CHECK (platform IN ('ios', 'android', 'web')),
+    CONSTRAINT unique_user_push_token UNIQUE (user_id, token)
+);
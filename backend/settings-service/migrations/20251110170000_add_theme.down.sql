@@ -0,0 +1,5 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_theme;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS theme;
+
+UPDATE user_settings SET schema_version = 2 WHERE schema_version = 3;
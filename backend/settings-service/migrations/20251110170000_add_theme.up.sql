@@ -0,0 +1,11 @@
+-- Color theme, value of the shared Theme enum; night_mode is kept in sync for older clients
+ALTER TABLE user_settings
+ADD COLUMN theme VARCHAR(10) NOT NULL DEFAULT 'light';
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_theme CHECK (theme IN ('light', 'dark', 'system'));
+
+-- Carry the night mode of existing users over, their rows now follow schema version 3
+UPDATE user_settings
+SET theme = CASE WHEN night_mode THEN 'dark' ELSE 'light' END,
+    schema_version = 3;
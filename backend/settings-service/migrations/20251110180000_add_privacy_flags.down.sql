@@ -0,0 +1,5 @@
+ALTER TABLE user_settings DROP COLUMN IF EXISTS crash_reports_enabled;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS analytics_enabled;
+
+UPDATE user_settings SET schema_version = 3 WHERE schema_version = 4;
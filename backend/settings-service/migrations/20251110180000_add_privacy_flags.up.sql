@@ -0,0 +1,7 @@
+-- Privacy flags other services and the frontend check before emitting telemetry
+ALTER TABLE user_settings
+ADD COLUMN analytics_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+ADD COLUMN crash_reports_enabled BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Existing users get the defaults, their rows now follow schema version 4
+UPDATE user_settings SET schema_version = 4;
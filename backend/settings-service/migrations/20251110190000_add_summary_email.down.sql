@@ -0,0 +1,9 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_summary_email_day;
+
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_summary_email_frequency;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS summary_email_day;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS summary_email_frequency;
+
+UPDATE user_settings SET schema_version = 4 WHERE schema_version = 5;
@@ -0,0 +1,18 @@
+-- Summary email schedule, frequency is a value of the shared SummaryEmailFrequency enum.
+-- The day is a weekday (1 = Monday) for Weekly and a day of the month for Monthly.
+ALTER TABLE user_settings
+ADD COLUMN summary_email_frequency VARCHAR(10) NOT NULL DEFAULT 'Off',
+ADD COLUMN summary_email_day INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_summary_email_frequency
+    CHECK (summary_email_frequency IN ('Off', 'Weekly', 'Monthly'));
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_summary_email_day CHECK (
+    summary_email_day BETWEEN 1 AND 28
+    AND (summary_email_frequency <> 'Weekly' OR summary_email_day <= 7)
+);
+
+-- Existing users get the defaults, their rows now follow schema version 5
+UPDATE user_settings SET schema_version = 5;
@@ -0,0 +1,13 @@
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_decimal_places_override;
+
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_thousands_separator;
+
+ALTER TABLE user_settings DROP CONSTRAINT IF EXISTS check_currency_symbol_position;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS decimal_places_override;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS thousands_separator;
+
+ALTER TABLE user_settings DROP COLUMN IF EXISTS currency_symbol_position;
+
+UPDATE user_settings SET schema_version = 5 WHERE schema_version = 6;
@@ -0,0 +1,27 @@
+-- How amounts are displayed, position and separator are values of the shared
+-- CurrencySymbolPosition and ThousandsSeparator enums. A NULL override shows
+-- the currency's decimal places.
+ALTER TABLE user_settings
+ADD COLUMN currency_symbol_position VARCHAR(10) NOT NULL DEFAULT 'Prefix',
+ADD COLUMN thousands_separator VARCHAR(10) NOT NULL DEFAULT 'Comma',
+ADD COLUMN decimal_places_override INTEGER;
+
+-- Existing users get the defaults of their currency
+UPDATE user_settings
+SET currency_symbol_position = 'Suffix', thousands_separator = 'Space'
+WHERE currency IN ('eur', 'ron');
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_currency_symbol_position
+    CHECK (currency_symbol_position IN ('Prefix', 'Suffix'));
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_thousands_separator
+    CHECK (thousands_separator IN ('Comma', 'Dot', 'Space', 'None'));
+
+ALTER TABLE user_settings
+ADD CONSTRAINT check_decimal_places_override
+    CHECK (decimal_places_override BETWEEN 0 AND 4);
+
+-- Their rows now follow schema version 6
+UPDATE user_settings SET schema_version = 6;
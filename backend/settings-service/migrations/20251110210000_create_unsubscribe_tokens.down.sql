@@ -0,0 +1,6 @@
+DELETE FROM settings_history WHERE source = 'unsubscribe';
+ALTER TABLE settings_history DROP CONSTRAINT valid_source;
+ALTER TABLE settings_history
+ADD CONSTRAINT valid_source CHECK (source IN ('update', 'reset'));
+
+DROP TABLE IF EXISTS unsubscribe_tokens;
@@ -0,0 +1,11 @@
+-- Tokens identifying users in the unsubscribe links of non-transactional emails
+CREATE TABLE unsubscribe_tokens (
+    user_id UUID PRIMARY KEY REFERENCES user_settings(user_id) ON DELETE CASCADE,
+    token VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Unsubscribing turns the summary emails off and is recorded in the history
+ALTER TABLE settings_history DROP CONSTRAINT valid_source;
+ALTER TABLE settings_history
+ADD CONSTRAINT valid_source CHECK (source IN ('update', 'reset', 'unsubscribe'));
@@ -1,7 +1,15 @@
 use sqlx::PgPool;
-use tokio::sync::Mutex;
+use uuid::Uuid;
 
-use crate::{Config, grpc::auth_service::service::auth_service_client::AuthServiceClient};
+use crate::{
+    Config, database,
+    models::{
+        rate_limit::WriteRateLimiter,
+        response::Error,
+        settings::{DefaultSettings, Settings},
+        settings_cache::SettingsCache,
+    },
+};
 
 /// Application state shared across all routes
 ///
@@ -11,7 +19,8 @@ use crate::{Config, grpc::auth_service::service::auth_service_client::AuthServic
 /// # Fields
 /// * `config` - Application configuration settings
 /// * `db` - PostgreSQL connection pool for async database operations
-/// * `auth_service` - A mutex for the AuthServiceClient gRPC
+/// * `write_rate_limiter` - Limits how often each user can write their settings and alarms
+/// * `settings_cache` - In-process cache of user settings, shared by the HTTP and gRPC servers
 ///
 /// # Usage
 /// ```rust
@@ -25,7 +34,8 @@ use crate::{Config, grpc::auth_service::service::auth_service_client::AuthServic
 pub struct AppState {
     pub config: Config,
     db: PgPool,
-    auth_service: Mutex<AuthServiceClient<tonic::transport::Channel>>,
+    write_rate_limiter: WriteRateLimiter,
+    settings_cache: SettingsCache,
 }
 
 impl AppState {
@@ -33,15 +43,15 @@ impl AppState {
     ///
     /// # Returns
     /// * `AppState` - the AppState that contains all the necessary configs
-    pub fn new(
-        config: Config,
-        db: PgPool,
-        auth_service: AuthServiceClient<tonic::transport::Channel>,
-    ) -> Self {
+    pub fn new(config: Config, db: PgPool) -> Self {
+        let write_rate_limiter = WriteRateLimiter::new(config.settings_writes_per_minute);
+        let settings_cache = SettingsCache::new(config.settings_cache_ttl_seconds);
+
         Self {
             config,
             db,
-            auth_service: Mutex::new(auth_service),
+            write_rate_limiter,
+            settings_cache,
         }
     }
 
@@ -59,19 +69,54 @@ impl AppState {
         &self.db
     }
 
-    /// Gets a lock on the auth service client
+    /// Gets a reference to the settings write rate limiter
     ///
     /// # Returns
-    /// * `MutexGuard<AuthServiceClient<Channel>>` - A locked auth service client
+    /// * `&WriteRateLimiter` - A reference to the write rate limiter
+    pub fn get_write_rate_limiter(&self) -> &WriteRateLimiter {
+        &self.write_rate_limiter
+    }
+
+    /// Gets the settings of a user through the settings cache
     ///
-    /// # Example
-    /// ```rust
-    /// let mut client = state.get_auth_service().await;
-    /// // Use client for gRPC calls
-    /// ```
-    pub async fn get_auth_service(
+    /// Creates default settings if the user has none, like
+    /// `database::settings::find_by_uuid`.
+    ///
+    /// # Arguments
+    /// * `user_id` - The UUID of the user whose settings to get
+    /// * `defaults` - The values used if the user has no settings yet
+    ///
+    /// # Returns
+    /// * `Ok(Settings)` - The user's settings
+    /// * `Err(Error)` - Database operation error
+    pub async fn get_user_settings(
         &self,
-    ) -> tokio::sync::MutexGuard<'_, AuthServiceClient<tonic::transport::Channel>> {
-        self.auth_service.lock().await
+        user_id: Uuid,
+        defaults: &DefaultSettings,
+    ) -> Result<Settings, Error> {
+        self.settings_cache
+            .get_or_load(user_id, || {
+                database::settings::find_by_uuid(user_id, defaults, &self.db)
+            })
+            .await
+    }
+
+    /// Removes the cached settings of a user
+    ///
+    /// Must be called after every successful write to the user's settings or
+    /// alarms, so the next read loads the new values.
+    ///
+    /// # Arguments
+    /// * `user_id` - The UUID of the user whose settings changed
+    pub async fn invalidate_user_settings(&self, user_id: Uuid) {
+        self.settings_cache.invalidate(user_id).await;
+    }
+
+    /// Gets the settings cache, e.g. to read its hit and miss counters
+    ///
+    /// # Returns
+    /// * `&SettingsCache` - A reference to the settings cache
+    pub fn get_settings_cache(&self) -> &SettingsCache {
+        &self.settings_cache
     }
 }
@@ -1,4 +1,9 @@
-use std::env::var;
+use std::{env::var, str::FromStr};
+
+use chrono_tz::Tz;
+use shared_types::enums::{Currency, Language};
+
+use crate::models::settings::DefaultSettings;
 
 /// Application configuration loaded from environment variables
 ///
@@ -10,6 +15,7 @@ use std::env::var;
 ///
 /// ## Server Configuration
 /// * `settings_http_port` - Port number for the HTTP server to listen on
+/// * `settings_grpc_port` - Port number for the internal gRPC server to listen on
 ///
 /// ## Database Configuration
 /// * `pg_url` - PostgreSQL server hostname or IP address
@@ -18,21 +24,39 @@ use std::env::var;
 /// * `pg_database` - Name of the settings-service database to connect to (default: brewget_settings)
 ///
 /// ## Security Configuration
-/// * `cors_url` - Allowed CORS origin URL for frontend integration
+/// * `internal_grpc_secret` - Shared secret other services must send to call the gRPC server,
+///   also sent by the gateway with the verified user of HTTP requests
 ///
-/// ## Service Integration
-/// * `auth_hostname` - Hostname of the auth service for gRPC communication
-/// * `auth_grpc_port` - Port number for the auth service gRPC server
+/// ## Settings Configuration
+/// * `default_settings` - Values of the settings created on a user's first request
+///   (default: en, USD, UTC, analytics enabled, crash reports disabled)
+/// * `settings_history_retention_days` - Days settings changes are kept, 0 to keep them forever
+///   (default: 365)
+/// * `max_alarms_per_user` - Maximum number of alarms a user can have, 0 for unlimited (default: 10)
+/// * `max_devices_per_user` - Maximum number of devices with settings overrides per user, 0 for
+///   unlimited (default: 20)
+/// * `device_settings_retention_days` - Days the overrides of an unused device are kept, 0 to keep
+///   them forever (default: 180)
+/// * `settings_writes_per_minute` - Settings, alarm, device and push token writes allowed per user
+///   and minute, 0 for unlimited (default: 30)
+/// * `settings_cache_ttl_seconds` - Seconds user settings are cached in memory, 0 to disable
+///   the cache (default: 60)
 #[derive(Clone)]
 pub struct Config {
     pub settings_http_port: u32,
+    pub settings_grpc_port: u32,
     pub pg_url: String,
     pub pg_username: String,
     pub pg_password: String,
     pub pg_database: String,
-    pub cors_url: String,
-    pub auth_hostname: String,
-    pub auth_grpc_port: u32,
+    pub internal_grpc_secret: String,
+    pub default_settings: DefaultSettings,
+    pub settings_history_retention_days: u32,
+    pub max_alarms_per_user: usize,
+    pub max_devices_per_user: usize,
+    pub device_settings_retention_days: u32,
+    pub settings_writes_per_minute: u32,
+    pub settings_cache_ttl_seconds: u64,
 }
 
 impl Config {
@@ -45,19 +69,38 @@ impl Config {
     ///
     /// The following environment variables must be set:
     /// - `SETTINGS_HTTP_PORT` - Must be a valid u32 port number
+    /// - `SETTINGS_GRPC_PORT` - Must be a valid u32 port number
     /// - `PG_URL` - PostgreSQL server URL
     /// - `PG_USERNAME` - Database username
     /// - `PG_PASSWORD` - Database password
     /// - `SETTINGS_PG_DATABASE` - Settings service database name (falls back to PG_DATABASE if not set)
-    /// - `CORS_URL` - Allowed CORS origin URL
-    /// - `AUTH_HOSTNAME` - Auth service hostname
-    /// - `AUTH_GRPC_PORT` - Must be a valid u32 port number
+    /// - `INTERNAL_GRPC_SECRET` - Shared secret for internal gRPC calls and gateway requests
+    ///
+    /// The following environment variables are optional:
+    /// - `DEFAULT_LANGUAGE` - Language of new users' settings (default: en)
+    /// - `DEFAULT_CURRENCY` - Currency of new users' settings (default: USD)
+    /// - `DEFAULT_TIMEZONE` - IANA timezone of new users' settings (default: UTC)
+    /// - `DEFAULT_ANALYTICS_ENABLED` - Whether new users allow usage analytics (default: true)
+    /// - `DEFAULT_CRASH_REPORTS_ENABLED` - Whether new users allow crash reports (default: false)
+    /// - `SETTINGS_HISTORY_RETENTION_DAYS` - Days settings changes are kept, 0 to keep them
+    ///   forever (default: 365)
+    /// - `MAX_ALARMS_PER_USER` - Maximum number of alarms a user can have, 0 for unlimited
+    ///   (default: 10)
+    /// - `MAX_DEVICES_PER_USER` - Maximum number of devices with settings overrides per user, 0
+    ///   for unlimited (default: 20)
+    /// - `DEVICE_SETTINGS_RETENTION_DAYS` - Days the overrides of an unused device are kept, 0 to
+    ///   keep them forever (default: 180)
+    /// - `SETTINGS_WRITES_PER_MINUTE` - Settings, alarm, device and push token writes allowed per
+    ///   user and minute, 0 for unlimited (default: 30)
+    /// - `SETTINGS_CACHE_TTL_SECONDS` - Seconds user settings are cached in memory, 0 to disable
+    ///   the cache (default: 60)
     ///
     /// # Panics
     ///
     /// This method will panic if:
     /// - Any required environment variable is missing
-    /// - `SETTINGS_HTTP_PORT` or `AUTH_GRPC_PORT` cannot be parsed as u32
+    /// - `SETTINGS_HTTP_PORT` or `SETTINGS_GRPC_PORT` cannot be parsed as u32
+    /// - `DEFAULT_LANGUAGE`, `DEFAULT_CURRENCY` or `DEFAULT_TIMEZONE` is set to an unsupported value
     ///
     /// # Returns
     ///
@@ -77,6 +120,10 @@ impl Config {
             .map(|val| val.parse::<u32>())
             .expect("SETTINGS_HTTP_PORT must be provided.")
             .expect("SETTINGS_HTTP_PORT must be a valid u32.");
+        let settings_grpc_port = var("SETTINGS_GRPC_PORT")
+            .map(|val| val.parse::<u32>())
+            .expect("SETTINGS_GRPC_PORT must be provided.")
+            .expect("SETTINGS_GRPC_PORT must be a valid u32.");
         let pg_url = var("PG_URL").expect("PG_URL must be provided.");
         let pg_username = var("PG_USERNAME").expect("PG_USERNAME must be provided.");
         let pg_password = var("PG_PASSWORD").expect("PG_PASSWORD must be provided.");
@@ -84,22 +131,77 @@ impl Config {
         let pg_database = var("SETTINGS_PG_DATABASE")
             .or_else(|_| var("PG_DATABASE"))
             .expect("SETTINGS_PG_DATABASE or PG_DATABASE must be provided.");
-        let cors_url = var("CORS_URL").expect("CORS_URL must be provided.");
-        let auth_hostname = var("AUTH_HOSTNAME").expect("AUTH_HOSTNAME must be provided.");
-        let auth_grpc_port = var("AUTH_GRPC_PORT")
-            .map(|val| val.parse::<u32>())
-            .expect("AUTH_GRPC_PORT must be provided.")
-            .expect("AUTH_GRPC_PORT must be a valid u32.");
+        let internal_grpc_secret =
+            var("INTERNAL_GRPC_SECRET").expect("INTERNAL_GRPC_SECRET must be provided.");
+        let default_settings = DefaultSettings {
+            language: var("DEFAULT_LANGUAGE")
+                .ok()
+                .map(|val| {
+                    Language::from_str(&val)
+                        .expect("DEFAULT_LANGUAGE must be a supported language.")
+                })
+                .unwrap_or(DefaultSettings::DEFAULT.language),
+            currency: var("DEFAULT_CURRENCY")
+                .ok()
+                .map(|val| {
+                    Currency::from_str(&val)
+                        .expect("DEFAULT_CURRENCY must be a supported currency.")
+                })
+                .unwrap_or(DefaultSettings::DEFAULT.currency),
+            timezone: var("DEFAULT_TIMEZONE")
+                .ok()
+                .map(|val| Tz::from_str(&val).expect("DEFAULT_TIMEZONE must be an IANA timezone."))
+                .unwrap_or(DefaultSettings::DEFAULT.timezone),
+            analytics_enabled: var("DEFAULT_ANALYTICS_ENABLED")
+                .ok()
+                .and_then(|val| val.parse::<bool>().ok())
+                .unwrap_or(DefaultSettings::DEFAULT.analytics_enabled),
+            crash_reports_enabled: var("DEFAULT_CRASH_REPORTS_ENABLED")
+                .ok()
+                .and_then(|val| val.parse::<bool>().ok())
+                .unwrap_or(DefaultSettings::DEFAULT.crash_reports_enabled),
+            ..DefaultSettings::DEFAULT
+        };
+        let settings_history_retention_days = var("SETTINGS_HISTORY_RETENTION_DAYS")
+            .ok()
+            .and_then(|days| days.parse::<u32>().ok())
+            .unwrap_or(365);
+        let max_alarms_per_user = var("MAX_ALARMS_PER_USER")
+            .ok()
+            .and_then(|max| max.parse::<usize>().ok())
+            .unwrap_or(10);
+        let max_devices_per_user = var("MAX_DEVICES_PER_USER")
+            .ok()
+            .and_then(|max| max.parse::<usize>().ok())
+            .unwrap_or(20);
+        let device_settings_retention_days = var("DEVICE_SETTINGS_RETENTION_DAYS")
+            .ok()
+            .and_then(|days| days.parse::<u32>().ok())
+            .unwrap_or(180);
+        let settings_writes_per_minute = var("SETTINGS_WRITES_PER_MINUTE")
+            .ok()
+            .and_then(|max| max.parse::<u32>().ok())
+            .unwrap_or(30);
+        let settings_cache_ttl_seconds = var("SETTINGS_CACHE_TTL_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse::<u64>().ok())
+            .unwrap_or(60);
 
         Self {
             settings_http_port,
+            settings_grpc_port,
             pg_url,
             pg_username,
             pg_password,
             pg_database,
-            cors_url,
-            auth_hostname,
-            auth_grpc_port,
+            internal_grpc_secret,
+            default_settings,
+            settings_history_retention_days,
+            max_alarms_per_user,
+            max_devices_per_user,
+            device_settings_retention_days,
+            settings_writes_per_minute,
+            settings_cache_ttl_seconds,
         }
     }
 }
@@ -1 +1,6 @@
+pub mod alarm;
+pub mod device_settings;
+pub mod push_token;
 pub mod settings;
+pub mod settings_history;
+pub mod unsubscribe_token;
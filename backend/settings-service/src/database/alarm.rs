@@ -0,0 +1,296 @@
+use axum::http::StatusCode;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::models::{
+    alarm::{Alarm, CreateAlarm, UpdateAlarm, is_alarm_limit_reached},
+    response::{Error, TranslationKey},
+};
+
+/// Finds all alarms of a user, oldest first
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose alarms to retrieve
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<Alarm>)` - The user's alarms
+/// * `Err(Error)` - Database operation error
+pub async fn find_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<Alarm>, Error> {
+    let alarms = sqlx::query_as::<_, Alarm>(
+        r#"
+        SELECT id, label, time, offset_minutes, enabled, days_of_week, created_at
+        FROM user_alarms
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(alarms)
+}
+
+/// Finds the first alarm of a user
+///
+/// The first alarm is the oldest one, it backs the legacy alarm fields of the settings.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose alarm to retrieve
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Option<Alarm>)` - The first alarm, or `None` if the user has no alarms
+/// * `Err(Error)` - Database operation error
+pub async fn find_first(user_id: Uuid, pool: &PgPool) -> Result<Option<Alarm>, Error> {
+    sqlx::query_as::<_, Alarm>(
+        r#"
+        SELECT id, label, time, offset_minutes, enabled, days_of_week, created_at
+        FROM user_alarms
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        LIMIT 1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.into())
+}
+
+/// Creates a new alarm for a user
+///
+/// The user's settings row is locked while the alarms are counted, so
+/// concurrent requests cannot exceed the limit. The settings row must exist
+/// and its `updated_at` is bumped in the same transaction.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user creating the alarm
+/// * `create_alarm` - The validated alarm creation data
+/// * `max_alarms` - Maximum number of alarms the user may have, 0 for unlimited
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Alarm)` - The newly created alarm
+/// * `Err(Error)` - Alarm limit reached or database operation error
+pub async fn create(
+    user_id: Uuid,
+    create_alarm: CreateAlarm,
+    max_alarms: usize,
+    pool: &PgPool,
+) -> Result<Alarm, Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("SELECT 1 FROM user_settings WHERE user_id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let (alarm_count,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM user_alarms WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+    if is_alarm_limit_reached(alarm_count as usize, max_alarms) {
+        return Err(
+            Error::new(StatusCode::CONFLICT, TranslationKey::AlarmLimitReached)
+                .with_param("limit", max_alarms),
+        );
+    }
+
+    let alarm = sqlx::query_as::<_, Alarm>(
+        r#"
+        INSERT INTO user_alarms (user_id, label, time, offset_minutes, enabled, days_of_week)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, label, time, offset_minutes, enabled, days_of_week, created_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(create_alarm.label.trim())
+    .bind(create_alarm.time)
+    .bind(create_alarm.offset_minutes)
+    .bind(create_alarm.enabled)
+    .bind(create_alarm.days_of_week)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    touch_settings(user_id, &mut tx).await?;
+    tx.commit().await?;
+
+    Ok(alarm)
+}
+
+/// Updates an alarm
+///
+/// The user's settings `updated_at` is bumped in the same transaction.
+///
+/// # Arguments
+///
+/// * `alarm_id` - The UUID of the alarm to update
+/// * `user_id` - The UUID of the user (for authorization)
+/// * `update_alarm` - The validated alarm update data
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Alarm)` - The updated alarm
+/// * `Err(Error)` - Alarm not found or database operation error
+pub async fn update(
+    alarm_id: Uuid,
+    user_id: Uuid,
+    update_alarm: UpdateAlarm,
+    pool: &PgPool,
+) -> Result<Alarm, Error> {
+    let mut tx = pool.begin().await?;
+
+    let alarm = sqlx::query_as::<_, Alarm>(
+        r#"
+        UPDATE user_alarms
+        SET
+            label = COALESCE($1, label),
+            time = COALESCE($2, time),
+            offset_minutes = COALESCE($3, offset_minutes),
+            enabled = COALESCE($4, enabled),
+            days_of_week = COALESCE($5, days_of_week)
+        WHERE id = $6 AND user_id = $7
+        RETURNING id, label, time, offset_minutes, enabled, days_of_week, created_at
+        "#,
+    )
+    .bind(update_alarm.label.as_deref().map(str::trim))
+    .bind(update_alarm.time)
+    .bind(update_alarm.offset_minutes)
+    .bind(update_alarm.enabled)
+    .bind(update_alarm.days_of_week)
+    .bind(alarm_id)
+    .bind(user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e: sqlx::Error| -> Error {
+        match e {
+            sqlx::Error::RowNotFound => {
+                (StatusCode::NOT_FOUND, TranslationKey::AlarmNotFound).into()
+            }
+            _ => e.into(),
+        }
+    })?;
+
+    touch_settings(user_id, &mut tx).await?;
+    tx.commit().await?;
+
+    Ok(alarm)
+}
+
+/// Deletes an alarm
+///
+/// If an alarm was deleted, the user's settings `updated_at` is bumped in the
+/// same transaction.
+///
+/// # Arguments
+///
+/// * `alarm_id` - The UUID of the alarm to delete
+/// * `user_id` - The UUID of the user (for authorization)
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows deleted (1 if successful)
+/// * `Err(Error)` - Database operation error
+pub async fn delete(alarm_id: Uuid, user_id: Uuid, pool: &PgPool) -> Result<usize, Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        r#"
+        DELETE FROM user_alarms
+        WHERE id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(alarm_id)
+    .bind(user_id)
+    .execute(&mut *tx)
+    .await?;
+
+    let deleted = result.rows_affected() as usize;
+    if deleted > 0 {
+        touch_settings(user_id, &mut tx).await?;
+    }
+    tx.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Bumps the `updated_at` of a user's settings after an alarm write
+///
+/// Alarms are part of the settings document, so every alarm write changes
+/// the settings `ETag` and clients holding an older one get a conflict.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose alarms changed
+/// * `conn` - Connection of the open alarm write transaction
+///
+/// # Returns
+///
+/// * `Ok(())` - Once the settings are marked as changed
+/// * `Err(Error)` - Database operation error
+async fn touch_settings(user_id: Uuid, conn: &mut PgConnection) -> Result<(), Error> {
+    sqlx::query(
+        r#"
+        UPDATE user_settings
+        SET updated_at = NOW()
+        WHERE user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Copies the legacy alarm fields of the settings onto the user's first alarm
+///
+/// Keeps older clients writing `alarm_set`, `alarm_time` and `alarm_offset_minutes`
+/// working. Users without alarms are left unchanged, the fields stay on the
+/// settings row.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose settings were updated
+/// * `conn` - Connection of the open settings update transaction
+///
+/// # Returns
+///
+/// * `Ok(())` - Once the first alarm, if any, is updated
+/// * `Err(Error)` - Database operation error
+pub async fn sync_first_with_legacy(user_id: Uuid, conn: &mut PgConnection) -> Result<(), Error> {
+    sqlx::query(
+        r#"
+        UPDATE user_alarms
+        SET
+            enabled = s.alarm_set,
+            time = s.alarm_time,
+            offset_minutes = s.alarm_offset_minutes
+        FROM user_settings s
+        WHERE s.user_id = $1
+          AND user_alarms.id = (
+            SELECT id FROM user_alarms
+            WHERE user_id = $1
+            ORDER BY created_at, id
+            LIMIT 1
+          )
+        "#,
+    )
+    .bind(user_id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use axum::http::StatusCode;
+use sqlx::{PgPool, types::Json};
+use uuid::Uuid;
+
+use crate::models::{
+    device_settings::{DeviceOverrides, DeviceSettings, is_device_limit_reached},
+    response::{Error, TranslationKey},
+};
+
+/// Marks a device as seen and returns its overrides
+///
+/// Used whenever a device reads its settings, so devices in use are not
+/// removed as stale.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user owning the device
+/// * `device_id` - The client generated UUID of the device
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Option<DeviceSettings>)` - The device's overrides, or `None` if it has none stored
+/// * `Err(Error)` - Database operation error
+pub async fn touch(
+    user_id: Uuid,
+    device_id: Uuid,
+    pool: &PgPool,
+) -> Result<Option<DeviceSettings>, Error> {
+    sqlx::query_as::<_, DeviceSettings>(
+        r#"
+        UPDATE device_settings
+        SET last_seen_at = NOW()
+        WHERE user_id = $1 AND device_id = $2
+        RETURNING device_id, overrides, last_seen_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(device_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.into())
+}
+
+/// Stores the overrides of a device, replacing any previous ones
+///
+/// The user's settings row is locked while the devices are counted, so
+/// concurrent requests cannot exceed the limit. Devices that already have
+/// overrides can always be updated. The settings row must exist.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user owning the device
+/// * `device_id` - The client generated UUID of the device
+/// * `overrides` - The validated overrides
+/// * `max_devices` - Maximum number of devices the user may have, 0 for unlimited
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(DeviceSettings)` - The stored overrides
+/// * `Err(Error)` - Device limit reached or database operation error
+pub async fn upsert(
+    user_id: Uuid,
+    device_id: Uuid,
+    overrides: DeviceOverrides,
+    max_devices: usize,
+    pool: &PgPool,
+) -> Result<DeviceSettings, Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("SELECT 1 FROM user_settings WHERE user_id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let (device_count, known_device): (i64, bool) = sqlx::query_as(
+        r#"
+        SELECT COUNT(*), COALESCE(BOOL_OR(device_id = $2), FALSE)
+        FROM device_settings
+        WHERE user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .bind(device_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if !known_device && is_device_limit_reached(device_count as usize, max_devices) {
+        return Err(
+            Error::new(StatusCode::CONFLICT, TranslationKey::DeviceLimitReached)
+                .with_param("limit", max_devices),
+        );
+    }
+
+    let device_settings = sqlx::query_as::<_, DeviceSettings>(
+        r#"
+        INSERT INTO device_settings (user_id, device_id, overrides)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, device_id)
+        DO UPDATE SET overrides = EXCLUDED.overrides, last_seen_at = NOW()
+        RETURNING device_id, overrides, last_seen_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(device_id)
+    .bind(Json(overrides))
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(device_settings)
+}
+
+/// Deletes the overrides of devices not seen for the retention period
+///
+/// # Arguments
+///
+/// * `retention_days` - Days a device is kept after it was last seen
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of devices deleted
+/// * `Err(Error)` - Database operation error
+pub async fn delete_stale(retention_days: u32, pool: &PgPool) -> Result<usize, Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM device_settings
+        WHERE last_seen_at < NOW() - make_interval(days => $1)
+        "#,
+    )
+    .bind(retention_days as i32)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() as usize)
+}
@@ -0,0 +1,108 @@
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::models::{
+    push_token::{PushToken, RegisterPushToken},
+    response::Error,
+};
+
+/// A registered push token and whether the registration created it
+#[derive(FromRow)]
+struct RegisteredPushToken {
+    #[sqlx(flatten)]
+    push_token: PushToken,
+    inserted: bool,
+}
+
+/// Finds all push tokens of a user, oldest first
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose tokens to retrieve
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<PushToken>)` - The user's push tokens
+/// * `Err(Error)` - Database operation error
+pub async fn find_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<PushToken>, Error> {
+    let push_tokens = sqlx::query_as::<_, PushToken>(
+        r#"
+        SELECT id, platform, token, created_at, last_seen_at
+        FROM push_tokens
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(push_tokens)
+}
+
+/// Registers a push token for a user
+///
+/// Registering a token the user already has does not add a second row, it
+/// updates the platform and bumps `last_seen_at` of the existing one. The
+/// settings row must exist.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user registering the token
+/// * `register_push_token` - The normalized registration data
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok((PushToken, bool))` - The registered token and `true` if it was not registered before
+/// * `Err(Error)` - Database operation error
+pub async fn register(
+    user_id: Uuid,
+    register_push_token: RegisterPushToken,
+    pool: &PgPool,
+) -> Result<(PushToken, bool), Error> {
+    let registered = sqlx::query_as::<_, RegisteredPushToken>(
+        r#"
+        INSERT INTO push_tokens (user_id, platform, token)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, token)
+        DO UPDATE SET platform = EXCLUDED.platform, last_seen_at = NOW()
+        RETURNING id, platform, token, created_at, last_seen_at, (xmax = 0) AS inserted
+        "#,
+    )
+    .bind(user_id)
+    .bind(&register_push_token.platform)
+    .bind(&register_push_token.token)
+    .fetch_one(pool)
+    .await?;
+
+    Ok((registered.push_token, registered.inserted))
+}
+
+/// Deletes a push token
+///
+/// # Arguments
+///
+/// * `push_token_id` - The UUID of the token registration to delete
+/// * `user_id` - The UUID of the user (for authorization)
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows deleted (1 if successful)
+/// * `Err(Error)` - Database operation error
+pub async fn delete(push_token_id: Uuid, user_id: Uuid, pool: &PgPool) -> Result<usize, Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM push_tokens
+        WHERE id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(push_token_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() as usize)
+}
@@ -1,33 +1,69 @@
+use axum::http::StatusCode;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::models::{
-    response::Error,
-    settings::{Settings, UpdateSettings},
+use crate::{
+    database,
+    models::{
+        response::{Error, TranslationKey},
+        settings::{DefaultSettings, Settings, UpdateSettings, if_match_satisfied},
+        settings_history::SettingsChangeSource,
+        settings_schema::CURRENT_SCHEMA_VERSION,
+    },
 };
 
-/// Inserts blank default settings for a new user
+/// Inserts default settings for a user unless they already have settings
 ///
-/// This function creates a new settings record with default values for a user.
-/// It's typically called when a new user registers and needs initial settings.
+/// Uses `ON CONFLICT DO NOTHING` so concurrent first requests for the same
+/// user cannot fail on the primary key.
 ///
 /// # Arguments
 ///
 /// * `insert_uuid` - The UUID of the user to create settings for
+/// * `defaults` - The values of the new settings row
 /// * `pool` - Database connection pool
 ///
 /// # Returns
 ///
-/// * `Ok(usize)` - Number of rows inserted (1 if successful)
+/// * `Ok(usize)` - Number of rows inserted (0 if settings already existed)
 /// * `Err(Error)` - Database operation error
-pub async fn insert_blank(insert_uuid: Uuid, pool: &PgPool) -> Result<usize, Error> {
+pub async fn insert_default(
+    insert_uuid: Uuid,
+    defaults: &DefaultSettings,
+    pool: &PgPool,
+) -> Result<usize, Error> {
     sqlx::query(
         r#"
-        INSERT INTO user_settings (user_id)
-        VALUES ($1)
+        INSERT INTO user_settings
+            (user_id, language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode,
+             theme, timezone, date_format, number_format, week_starts_on, budget_period,
+             analytics_enabled, crash_reports_enabled, summary_email_frequency, summary_email_day,
+             currency_symbol_position, thousands_separator, schema_version)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                $19, $20)
+        ON CONFLICT (user_id) DO NOTHING
         "#,
     )
     .bind(insert_uuid)
+    .bind(defaults.language_code())
+    .bind(defaults.currency_code())
+    .bind(defaults.alarm_set)
+    .bind(defaults.alarm_time)
+    .bind(defaults.alarm_offset_minutes)
+    .bind(defaults.theme.night_mode())
+    .bind(defaults.theme.as_str())
+    .bind(defaults.timezone_name())
+    .bind(defaults.date_format.as_str())
+    .bind(defaults.number_format.as_str())
+    .bind(defaults.week_starts_on.as_str())
+    .bind(defaults.budget_period.as_str())
+    .bind(defaults.analytics_enabled)
+    .bind(defaults.crash_reports_enabled)
+    .bind(defaults.summary_email_frequency.as_str())
+    .bind(defaults.summary_email_day)
+    .bind(defaults.currency_symbol_position().as_str())
+    .bind(defaults.thousands_separator().as_str())
+    .bind(CURRENT_SCHEMA_VERSION)
     .execute(pool)
     .await
     .map(|result| result.rows_affected() as usize)
@@ -43,6 +79,7 @@ pub async fn insert_blank(insert_uuid: Uuid, pool: &PgPool) -> Result<usize, Err
 /// # Arguments
 ///
 /// * `find_uuid` - The UUID of the user to find settings for
+/// * `defaults` - The values used if the user has no settings yet
 /// * `pool` - Database connection pool
 ///
 /// # Returns
@@ -53,58 +90,81 @@ pub async fn insert_blank(insert_uuid: Uuid, pool: &PgPool) -> Result<usize, Err
 /// # Behavior
 ///
 /// 1. First attempts to find existing settings for the user
-/// 2. If no settings exist, creates default settings using `insert_blank`
-/// 3. Returns the settings (either found or newly created)
-pub async fn find_by_uuid(find_uuid: Uuid, pool: &PgPool) -> Result<Settings, Error> {
-    let mut result = sqlx::query_as::<_, Settings>(
+/// 2. If no settings exist, creates default settings using `insert_default`
+/// 3. Upgrades rows written with an older schema version
+/// 4. Maps the user's first alarm onto the legacy alarm fields
+/// 5. Returns the settings (either found or newly created)
+pub async fn find_by_uuid(
+    find_uuid: Uuid,
+    defaults: &DefaultSettings,
+    pool: &PgPool,
+) -> Result<Settings, Error> {
+    let settings = match find_existing(find_uuid, pool).await? {
+        Some(settings) => settings,
+        None => {
+            insert_default(find_uuid, defaults, pool).await?;
+
+            find_existing(find_uuid, pool)
+                .await?
+                .ok_or_else(|| Error::from(sqlx::Error::RowNotFound))?
+        }
+    };
+
+    let first_alarm = database::alarm::find_first(find_uuid, pool).await?;
+
+    Ok(settings.upgraded().with_legacy_alarm(first_alarm.as_ref()))
+}
+
+/// Finds the settings row of a user without creating one
+async fn find_existing(find_uuid: Uuid, pool: &PgPool) -> Result<Option<Settings>, Error> {
+    sqlx::query_as::<_, Settings>(
         r#"
-        SELECT user_id, language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode
+        SELECT user_id, language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode,
+            theme, timezone, date_format, number_format, week_starts_on, budget_period,
+            analytics_enabled, crash_reports_enabled, summary_email_frequency, summary_email_day,
+            currency_symbol_position, thousands_separator, decimal_places_override,
+            schema_version, updated_at
         FROM user_settings
         WHERE user_id = $1
         "#,
     )
     .bind(find_uuid)
-    .fetch_one(pool)
-    .await;
-
-    if result.is_err() {
-        insert_blank(find_uuid, pool).await?;
-        result = sqlx::query_as::<_, Settings>(
-            r#"
-            SELECT user_id, language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode
-            FROM user_settings
-            WHERE user_id = $1
-            "#,
-        )
-        .bind(find_uuid)
-        .fetch_one(pool)
-        .await;
-    }
-
-    Ok(result?)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.into())
 }
 
 /// Updates user settings with new values
 ///
 /// This function performs a partial update of user settings. Only the fields
 /// provided in the `UpdateSettings` struct will be updated, leaving other
-/// fields unchanged.
+/// fields unchanged. The changed fields are recorded in the settings history
+/// in the same transaction; updates that change nothing are not recorded.
+/// Updates writing the legacy alarm fields also update the user's first alarm.
+/// A currency change also moves the amount display to the new currency's
+/// defaults, see `Settings::with_currency_defaults`. The row is stamped with
+/// the current schema version.
 ///
 /// # Arguments
 ///
 /// * `uuid` - The UUID of the user whose settings to update
 /// * `update_settings` - The settings update data (only non-None fields will be updated)
+/// * `source` - Where the change came from, recorded in the history
+/// * `if_match` - The `If-Match` header of the request, the update is only applied if it
+///   matches the entity tag of the stored settings
 /// * `pool` - Database connection pool
 ///
 /// # Returns
 ///
 /// * `Ok(usize)` - Number of rows updated (1 if successful)
-/// * `Err(Error)` - Database operation error
+/// * `Err(Error)` - 412 `SettingsModified` if `if_match` does not match, 400 with the invalid
+///   `field` as param if the summary email day does not fit the resulting frequency or the
+///   thousands separator matches the resulting decimal separator, or database operation error
 ///
 /// # Example
 ///
 /// ```rust
-/// use settings_service::models::settings::UpdateSettings;
+/// use settings_service::models::{settings::UpdateSettings, settings_history::SettingsChangeSource};
 /// use chrono::NaiveTime;
 ///
 /// let update = UpdateSettings {
@@ -115,14 +175,58 @@ pub async fn find_by_uuid(find_uuid: Uuid, pool: &PgPool) -> Result<Settings, Er
 /// };
 ///
 /// // Only language, night_mode, and alarm_time will be updated
-/// update(uuid, update, pool).await?;
+/// update(uuid, update, SettingsChangeSource::Update, None, pool).await?;
 /// ```
 pub async fn update(
     uuid: Uuid,
     update_settings: UpdateSettings,
+    source: SettingsChangeSource,
+    if_match: Option<&str>,
     pool: &PgPool,
 ) -> Result<usize, Error> {
-    // Build a dynamic query based on which fields are Some
+    let mut tx = pool.begin().await?;
+
+    // Lock the row so the recorded old values match the ones being replaced
+    let Some(current) = sqlx::query_as::<_, Settings>(
+        r#"
+        SELECT user_id, language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode,
+            theme, timezone, date_format, number_format, week_starts_on, budget_period,
+            analytics_enabled, crash_reports_enabled, summary_email_frequency, summary_email_day,
+            currency_symbol_position, thousands_separator, decimal_places_override,
+            schema_version, updated_at
+        FROM user_settings
+        WHERE user_id = $1
+        FOR UPDATE
+        "#,
+    )
+    .bind(uuid)
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(0);
+    };
+
+    if let Some(if_match) = if_match
+        && !if_match_satisfied(if_match, &current.etag())
+    {
+        return Err((
+            StatusCode::PRECONDITION_FAILED,
+            TranslationKey::SettingsModified,
+        )
+            .into());
+    }
+
+    let update_settings = current.with_currency_defaults(update_settings);
+    current
+        .validate_update(&update_settings)
+        .map_err(|invalid| -> Error {
+            Error::new(StatusCode::BAD_REQUEST, invalid.translation_key)
+                .with_param("field", invalid.field)
+        })?;
+
+    let changes = current.changes(&update_settings);
+    let touches_legacy_alarm = update_settings.touches_legacy_alarm();
+
     let result = sqlx::query(
         r#"
         UPDATE user_settings
@@ -132,8 +236,23 @@ pub async fn update(
             alarm_set = COALESCE($3, alarm_set),
             alarm_time = COALESCE($4, alarm_time),
             alarm_offset_minutes = COALESCE($5, alarm_offset_minutes),
-            night_mode = COALESCE($6, night_mode)
-        WHERE user_id = $7
+            night_mode = COALESCE($6, night_mode),
+            theme = COALESCE($7, theme),
+            timezone = COALESCE($8, timezone),
+            date_format = COALESCE($9, date_format),
+            number_format = COALESCE($10, number_format),
+            week_starts_on = COALESCE($11, week_starts_on),
+            budget_period = COALESCE($12, budget_period),
+            analytics_enabled = COALESCE($13, analytics_enabled),
+            crash_reports_enabled = COALESCE($14, crash_reports_enabled),
+            summary_email_frequency = COALESCE($15, summary_email_frequency),
+            summary_email_day = COALESCE($16, summary_email_day),
+            currency_symbol_position = COALESCE($17, currency_symbol_position),
+            thousands_separator = COALESCE($18, thousands_separator),
+            decimal_places_override = CASE WHEN $19 THEN $20 ELSE decimal_places_override END,
+            schema_version = $21,
+            updated_at = NOW()
+        WHERE user_id = $22
         "#,
     )
     .bind(update_settings.language)
@@ -142,6 +261,61 @@ pub async fn update(
     .bind(update_settings.alarm_time)
     .bind(update_settings.alarm_offset_minutes)
     .bind(update_settings.night_mode)
+    .bind(update_settings.theme)
+    .bind(update_settings.timezone)
+    .bind(update_settings.date_format)
+    .bind(update_settings.number_format)
+    .bind(update_settings.week_starts_on)
+    .bind(update_settings.budget_period)
+    .bind(update_settings.analytics_enabled)
+    .bind(update_settings.crash_reports_enabled)
+    .bind(update_settings.summary_email_frequency)
+    .bind(update_settings.summary_email_day)
+    .bind(update_settings.currency_symbol_position)
+    .bind(update_settings.thousands_separator)
+    .bind(update_settings.decimal_places_override.is_some())
+    .bind(update_settings.decimal_places_override.flatten())
+    .bind(CURRENT_SCHEMA_VERSION)
+    .bind(uuid)
+    .execute(&mut *tx)
+    .await?;
+
+    if touches_legacy_alarm {
+        database::alarm::sync_first_with_legacy(uuid, &mut tx).await?;
+    }
+
+    if !changes.is_empty() {
+        database::settings_history::insert(uuid, changes, source, &mut *tx).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected() as usize)
+}
+
+/// Deletes the settings of a user
+///
+/// Used when the user's account is removed. Deleting settings that do not
+/// exist is not an error, so the call can be retried safely. The settings
+/// history is removed with the row by its `ON DELETE CASCADE`.
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID of the user whose settings to delete
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows deleted (0 or 1)
+/// * `Err(sqlx::Error)` - Database operation error, kept raw so callers can tell
+///   transient failures apart
+pub async fn delete(uuid: Uuid, pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM user_settings
+        WHERE user_id = $1
+        "#,
+    )
     .bind(uuid)
     .execute(pool)
     .await?;
@@ -0,0 +1,105 @@
+use sqlx::{PgPool, Postgres, types::Json};
+use uuid::Uuid;
+
+use crate::models::{
+    response::Error,
+    settings_history::{SettingsChangeSource, SettingsHistoryEntry},
+};
+
+/// Records a change of a user's settings
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose settings changed
+/// * `changes` - Changed fields mapped to their old and new values
+/// * `source` - Where the change came from
+/// * `executor` - Database connection pool or transaction
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows inserted (1 if successful)
+/// * `Err(Error)` - Database operation error
+pub async fn insert<'a, E>(
+    user_id: Uuid,
+    changes: serde_json::Map<String, serde_json::Value>,
+    source: SettingsChangeSource,
+    executor: E,
+) -> Result<usize, Error>
+where
+    E: sqlx::Executor<'a, Database = Postgres>,
+{
+    let result = sqlx::query(
+        r#"
+        INSERT INTO settings_history (user_id, changes, source)
+        VALUES ($1, $2, $3)
+        "#,
+    )
+    .bind(user_id)
+    .bind(Json(changes))
+    .bind(source.as_str())
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() as usize)
+}
+
+/// Fetches the settings history of a user, newest first
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose history to retrieve
+/// * `limit` - Maximum number of entries to return
+/// * `offset` - Number of newer entries to skip
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<SettingsHistoryEntry>)` - The history entries, ordered by most recent first
+/// * `Err(Error)` - Database operation error
+pub async fn find_by_user_id(
+    user_id: Uuid,
+    limit: i64,
+    offset: i64,
+    pool: &PgPool,
+) -> Result<Vec<SettingsHistoryEntry>, Error> {
+    sqlx::query_as::<_, SettingsHistoryEntry>(
+        r#"
+        SELECT id, changes, source, changed_at
+        FROM settings_history
+        WHERE user_id = $1
+        ORDER BY changed_at DESC, id
+        LIMIT $2 OFFSET $3
+        "#,
+    )
+    .bind(user_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.into())
+}
+
+/// Deletes history entries older than the retention period
+///
+/// # Arguments
+///
+/// * `retention_days` - Number of days entries are kept
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows deleted
+/// * `Err(Error)` - Database operation error
+pub async fn delete_older_than(retention_days: u32, pool: &PgPool) -> Result<usize, Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM settings_history
+        WHERE changed_at < NOW() - make_interval(days => $1)
+        "#,
+    )
+    .bind(retention_days as i32)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() as usize)
+}
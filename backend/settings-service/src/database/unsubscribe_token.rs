@@ -0,0 +1,72 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{response::Error, unsubscribe};
+
+/// Gets the unsubscribe token of a user, creating it on first use
+///
+/// A user keeps the same token, so links in older emails keep working. The
+/// settings row must exist.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(String)` - The user's unsubscribe token
+/// * `Err(Error)` - Database operation error
+pub async fn get_or_create(user_id: Uuid, pool: &PgPool) -> Result<String, Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO unsubscribe_tokens (user_id, token)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO NOTHING
+        "#,
+    )
+    .bind(user_id)
+    .bind(unsubscribe::new_token())
+    .execute(pool)
+    .await?;
+
+    let token = sqlx::query_scalar(
+        r#"
+        SELECT token
+        FROM unsubscribe_tokens
+        WHERE user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(token)
+}
+
+/// Finds the user an unsubscribe token belongs to
+///
+/// # Arguments
+///
+/// * `token` - The token from the unsubscribe link
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Some(Uuid))` - The user the token belongs to
+/// * `Ok(None)` - If no user has the token
+/// * `Err(Error)` - Database operation error
+pub async fn find_user(token: &str, pool: &PgPool) -> Result<Option<Uuid>, Error> {
+    let user_id = sqlx::query_scalar(
+        r#"
+        SELECT user_id
+        FROM unsubscribe_tokens
+        WHERE token = $1
+        "#,
+    )
+    .bind(token)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(user_id)
+}
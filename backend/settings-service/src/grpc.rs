@@ -1 +1 @@
-pub mod auth_service;
+pub mod settings_service;
@@ -0,0 +1,248 @@
+pub mod service {
+    tonic::include_proto!("settings_service");
+}
+
+use std::sync::Arc;
+
+use shared_types::grpc::{authorize, database_status};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::{AppState, database, models::settings::Settings};
+
+use service::{
+    DeleteUserDataRequest, DeleteUserDataResponse, GetPushTokensRequest, GetPushTokensResponse,
+    GetUnsubscribeTokenRequest, GetUnsubscribeTokenResponse, GetUserSettingsRequest,
+    GetUserSettingsResponse, PushToken, settings_service_server::SettingsService,
+};
+
+/// gRPC service exposing user settings to other internal services
+pub struct SettingsServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl SettingsServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl SettingsService for SettingsServiceImpl {
+    /// Returns the language, currency, timezone, format, week, budget, alarm, privacy, summary
+    /// email and amount display preferences of a user
+    ///
+    /// Users without settings get the default settings created, like on `GET /user`.
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<GetUserSettingsResponse>)` - The user's settings
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn get_user_settings(
+        &self,
+        request: Request<GetUserSettingsRequest>,
+    ) -> Result<Response<GetUserSettingsResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized user settings request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::debug!("Received user settings request for user: {}", user_id);
+
+        let settings = self
+            .state
+            .get_user_settings(user_id, &self.state.config.default_settings)
+            .await
+            .map_err(|_| {
+                tracing::error!("Failed to fetch settings for user {}", user_id);
+                Status::internal("Failed to fetch settings")
+            })?;
+
+        Ok(Response::new(GetUserSettingsResponse::from(&settings)))
+    }
+
+    /// Deletes the settings of a removed user
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<DeleteUserDataResponse>)` - Whether a settings row was deleted
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn delete_user_data(
+        &self,
+        request: Request<DeleteUserDataRequest>,
+    ) -> Result<Response<DeleteUserDataResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized delete user data request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::info!("Deleting settings of removed user {}", user_id);
+
+        let deleted = database::settings::delete(user_id, self.state.get_database_pool())
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete settings for user {}: {}", user_id, e);
+                database_status(&e, "Failed to delete settings")
+            })?;
+        self.state.invalidate_user_settings(user_id).await;
+
+        Ok(Response::new(DeleteUserDataResponse {
+            deleted: deleted > 0,
+        }))
+    }
+
+    /// Returns the push tokens registered by the devices of a user
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<GetPushTokensResponse>)` - The user's push tokens, oldest first
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn get_push_tokens(
+        &self,
+        request: Request<GetPushTokensRequest>,
+    ) -> Result<Response<GetPushTokensResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized push tokens request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::debug!("Received push tokens request for user: {}", user_id);
+
+        let push_tokens =
+            database::push_token::find_all_by_user(user_id, self.state.get_database_pool())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Failed to fetch push tokens for user {}", user_id);
+                    Status::internal("Failed to fetch push tokens")
+                })?;
+
+        Ok(Response::new(GetPushTokensResponse {
+            push_tokens: push_tokens
+                .into_iter()
+                .map(|push_token| PushToken {
+                    id: push_token.id.to_string(),
+                    platform: push_token.platform,
+                    token: push_token.token,
+                    last_seen_at: push_token.last_seen_at.to_rfc3339(),
+                })
+                .collect(),
+        }))
+    }
+
+    /// Returns the token identifying a user in the unsubscribe links of emails
+    ///
+    /// The token is created on first use, together with default settings if
+    /// the user has none yet.
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<GetUnsubscribeTokenResponse>)` - The user's unsubscribe token
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn get_unsubscribe_token(
+        &self,
+        request: Request<GetUnsubscribeTokenRequest>,
+    ) -> Result<Response<GetUnsubscribeTokenResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized unsubscribe token request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::debug!("Received unsubscribe token request for user: {}", user_id);
+
+        // The token references the settings row, so make sure it exists
+        self.state
+            .get_user_settings(user_id, &self.state.config.default_settings)
+            .await
+            .map_err(|_| {
+                tracing::error!("Failed to fetch settings for user {}", user_id);
+                Status::internal("Failed to fetch settings")
+            })?;
+
+        let token =
+            database::unsubscribe_token::get_or_create(user_id, self.state.get_database_pool())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Failed to get unsubscribe token for user {}", user_id);
+                    Status::internal("Failed to get unsubscribe token")
+                })?;
+
+        Ok(Response::new(GetUnsubscribeTokenResponse { token }))
+    }
+}
+
+impl From<&Settings> for GetUserSettingsResponse {
+    fn from(settings: &Settings) -> Self {
+        Self {
+            language: settings.language().to_string(),
+            currency: settings.currency().to_string(),
+            timezone: settings.timezone().to_string(),
+            date_format: settings.date_format().to_string(),
+            number_format: settings.number_format().to_string(),
+            week_starts_on: settings.week_starts_on().to_string(),
+            budget_period: settings.budget_period().to_string(),
+            alarm_set: settings.alarm_set(),
+            alarm_time: settings.alarm_time().format("%H:%M:%S").to_string(),
+            alarm_offset_minutes: settings.alarm_offset_minutes(),
+            analytics_enabled: settings.analytics_enabled(),
+            crash_reports_enabled: settings.crash_reports_enabled(),
+            summary_email_frequency: settings.summary_email_frequency().to_string(),
+            summary_email_day: settings.summary_email_day(),
+            currency_symbol_position: settings.currency_symbol_position().to_string(),
+            thousands_separator: settings.thousands_separator().to_string(),
+            decimal_places_override: settings.decimal_places_override(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_settings_response_from_settings() {
+        let settings: Settings = serde_json::from_value(serde_json::json!({
+            "user_id": "550e8400-e29b-41d4-a716-446655440000",
+            "language": "ro",
+            "currency": "ron",
+            "alarm_set": true,
+            "alarm_time": "07:30:00",
+            "alarm_offset_minutes": 5,
+            "night_mode": false,
+            "theme": "light",
+            "timezone": "Europe/Bucharest",
+            "date_format": "DMY",
+            "number_format": "comma-decimal",
+            "week_starts_on": "Monday",
+            "budget_period": "Monthly",
+            "analytics_enabled": false,
+            "crash_reports_enabled": true,
+            "summary_email_frequency": "Weekly",
+            "summary_email_day": 5,
+            "currency_symbol_position": "Suffix",
+            "thousands_separator": "Dot",
+            "decimal_places_override": 0,
+            "schema_version": 6,
+            "updated_at": "2025-11-10T12:00:00Z"
+        }))
+        .unwrap();
+
+        let response = GetUserSettingsResponse::from(&settings);
+        assert!(!response.analytics_enabled);
+        assert!(response.crash_reports_enabled);
+        assert_eq!(response.summary_email_frequency, "Weekly");
+        assert_eq!(response.summary_email_day, 5);
+        assert_eq!(response.currency_symbol_position, "Suffix");
+        assert_eq!(response.thousands_separator, "Dot");
+        assert_eq!(response.decimal_places_override, Some(0));
+        assert_eq!(response.language, "ro");
+        assert_eq!(response.alarm_time, "07:30:00");
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{config::Config, routes::make_app};
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    config::Config,
+    grpc::settings_service::{
+        SettingsServiceImpl, service::settings_service_server::SettingsServiceServer,
+    },
+    routes::{make_app, make_state},
+};
 
 mod app_state;
 mod config;
@@ -9,6 +17,12 @@ mod routes;
 
 pub use app_state::AppState;
 
+/// How often settings history older than the retention period is removed
+const SETTINGS_HISTORY_SWEEP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How often the overrides of devices unused for the retention period are removed
+const DEVICE_SETTINGS_SWEEP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing/logging
@@ -27,36 +41,133 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize configuration from environment variables
     let config = Config::init();
     tracing::info!("✅ Configuration loaded successfully");
-    tracing::debug!("HTTP port: {}", config.settings_http_port);
     tracing::debug!(
-        "Auth service: {}:{}",
-        config.auth_hostname,
-        config.auth_grpc_port
+        "HTTP port: {}, gRPC port: {}",
+        config.settings_http_port,
+        config.settings_grpc_port
     );
 
-    // Bind TCP listener to the configured port
-    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.settings_http_port))
-        .await
-        .expect("Could not bind TcpListener.");
+    // Bind TCP listener to the configured HTTP port
+    let http_listener =
+        tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.settings_http_port))
+            .await
+            .expect("Could not bind TcpListener for HTTP.");
     tracing::info!(
         "✅ HTTP listener bound to port {}",
         config.settings_http_port
     );
 
+    // Bind gRPC server to the configured gRPC port
+    let grpc_addr: std::net::SocketAddr = format!("0.0.0.0:{}", config.settings_grpc_port)
+        .parse()
+        .expect("Invalid gRPC address");
+    tracing::info!("✅ gRPC address configured: {}", grpc_addr);
+
+    // Create the shared state used by both the HTTP routes and the gRPC service
+    let state = make_state(config).await;
+
     // Create the Axum application with all routes and middleware
-    let app = make_app(config).await.expect("Could not create app.");
-    tracing::info!("✅ Routes and middleware configured");
+    let app = make_app(state.clone());
+    tracing::info!("✅ HTTP routes configured");
+
+    // Periodically remove settings history older than the retention period
+    if state.config.settings_history_retention_days > 0 {
+        tokio::spawn(sweep_settings_history(state.clone()));
+        tracing::info!(
+            "✅ Settings history kept for {} days",
+            state.config.settings_history_retention_days
+        );
+    }
+
+    // Periodically remove the overrides of devices that are no longer used
+    if state.config.device_settings_retention_days > 0 {
+        tokio::spawn(sweep_device_settings(state.clone()));
+        tracing::info!(
+            "✅ Unused device settings kept for {} days",
+            state.config.device_settings_retention_days
+        );
+    }
+
+    let settings_service = SettingsServiceImpl::new(state);
+    tracing::info!("✅ gRPC service initialized");
 
     tracing::info!(
-        "🚀 Server started successfully on port {}",
-        listener.local_addr()?.port()
+        "🚀 HTTP Server started on port {}",
+        http_listener.local_addr()?.port()
     );
-    tracing::info!("📡 Server accepting connections");
+    tracing::info!("🚀 gRPC Server starting on port {}", grpc_addr.port());
 
-    // Start serving HTTP requests
-    axum::serve(listener, app)
-        .await
-        .expect("Could not serve axum server.");
+    // Spawn HTTP server
+    let http_server = tokio::spawn(async move {
+        tracing::info!("📡 HTTP server accepting connections");
+        axum::serve(http_listener, app)
+            .await
+            .expect("Could not serve axum server.");
+    });
+
+    // Spawn gRPC server
+    let grpc_server = tokio::spawn(async move {
+        tracing::info!("📡 gRPC server accepting connections");
+        tonic::transport::Server::builder()
+            .add_service(SettingsServiceServer::new(settings_service))
+            .serve(grpc_addr)
+            .await
+            .expect("Could not serve gRPC server");
+    });
+
+    // Wait for both servers
+    tracing::info!("✅ Both servers are running");
+    tokio::try_join!(http_server, grpc_server)?;
 
     Ok(())
 }
+
+/// Deletes expired settings history once per sweep interval
+///
+/// Failures are logged and retried on the next run.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+async fn sweep_settings_history(state: Arc<AppState>) {
+    let mut interval = tokio::time::interval(SETTINGS_HISTORY_SWEEP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match database::settings_history::delete_older_than(
+            state.config.settings_history_retention_days,
+            state.get_database_pool(),
+        )
+        .await
+        {
+            Ok(deleted) => tracing::info!("Removed {} expired settings history entries", deleted),
+            Err(_) => tracing::error!("Failed to remove expired settings history entries"),
+        }
+    }
+}
+
+/// Deletes the overrides of stale devices once per sweep interval
+///
+/// Failures are logged and retried on the next run.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+async fn sweep_device_settings(state: Arc<AppState>) {
+    let mut interval = tokio::time::interval(DEVICE_SETTINGS_SWEEP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match database::device_settings::delete_stale(
+            state.config.device_settings_retention_days,
+            state.get_database_pool(),
+        )
+        .await
+        {
+            Ok(deleted) => tracing::info!("Removed settings of {} stale devices", deleted),
+            Err(_) => tracing::error!("Failed to remove settings of stale devices"),
+        }
+    }
+}
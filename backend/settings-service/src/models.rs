@@ -1,2 +1,11 @@
+pub mod alarm;
+pub mod device_settings;
+pub mod meta;
+pub mod push_token;
+pub mod rate_limit;
 pub mod response;
 pub mod settings;
+pub mod settings_cache;
+pub mod settings_history;
+pub mod settings_schema;
+pub mod unsubscribe;
@@ -0,0 +1,513 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::response::TranslationKey;
+
+/// Maximum length of an alarm label in characters
+pub const ALARM_LABEL_MAX_LENGTH: usize = 50;
+
+/// Days of week bitmask with every day set, bit 0 is Monday and bit 6 is Sunday
+pub const EVERY_DAY: i16 = 0b111_1111;
+
+/// Longest wall-clock gap a DST change can skip, in minutes
+const MAX_DST_GAP_MINUTES: i64 = 180;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Represents a named alarm of a user
+///
+/// This struct maps to the `user_alarms` table.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier of the alarm
+/// * `label` - Name of the alarm (e.g., "Morning budget check")
+/// * `time` - The local time the alarm rings at
+/// * `offset_minutes` - Additional offset in minutes for the alarm
+/// * `enabled` - Whether the alarm is active
+/// * `days_of_week` - Bitmask of the days the alarm rings on, bit 0 is Monday and bit 6 is Sunday
+/// * `created_at` - When the alarm was created, the oldest alarm is the user's first alarm
+#[derive(FromRow, Clone, Debug, Serialize)]
+pub struct Alarm {
+    pub id: Uuid,
+    pub label: String,
+    pub time: NaiveTime,
+    pub offset_minutes: i32,
+    pub enabled: bool,
+    pub days_of_week: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Alarm {
+    /// Computes the next instant this alarm rings at
+    ///
+    /// # Arguments
+    ///
+    /// * `timezone` - The user's timezone the alarm time is local to
+    /// * `now` - The current instant
+    ///
+    /// # Returns
+    ///
+    /// The next trigger after `now`, or `None` if the alarm is disabled
+    pub fn next_trigger(&self, timezone: Tz, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.enabled {
+            return None;
+        }
+
+        next_trigger(
+            self.time,
+            self.offset_minutes,
+            self.days_of_week,
+            timezone,
+            now,
+        )
+    }
+}
+
+/// The next time one of the user's alarms rings
+///
+/// # Fields
+///
+/// * `alarm_id` - The alarm that rings, `None` for the legacy alarm of the settings
+/// * `trigger_at` - The instant the alarm rings at, in UTC
+/// * `local_time` - The same instant as wall-clock time in the user's timezone
+#[derive(Debug, Serialize)]
+pub struct NextAlarm {
+    pub alarm_id: Option<Uuid>,
+    pub trigger_at: DateTime<Utc>,
+    pub local_time: DateTime<FixedOffset>,
+}
+
+impl NextAlarm {
+    /// Creates the next alarm response for a trigger instant
+    ///
+    /// # Arguments
+    ///
+    /// * `alarm_id` - The alarm that rings, `None` for the legacy alarm
+    /// * `trigger_at` - The instant the alarm rings at
+    /// * `timezone` - The user's timezone, used for the local time
+    pub fn new(alarm_id: Option<Uuid>, trigger_at: DateTime<Utc>, timezone: Tz) -> Self {
+        Self {
+            alarm_id,
+            trigger_at,
+            local_time: trigger_at.with_timezone(&timezone).fixed_offset(),
+        }
+    }
+}
+
+/// Finds the alarm that rings next
+///
+/// # Arguments
+///
+/// * `alarms` - The user's alarms
+/// * `timezone` - The user's timezone the alarm times are local to
+/// * `now` - The current instant
+///
+/// # Returns
+///
+/// The earliest trigger of the enabled alarms, or `None` if no alarm is enabled
+pub fn next_alarm(alarms: &[Alarm], timezone: Tz, now: DateTime<Utc>) -> Option<NextAlarm> {
+    alarms
+        .iter()
+        .filter_map(|alarm| Some((alarm.id, alarm.next_trigger(timezone, now)?)))
+        .min_by_key(|(_, trigger_at)| *trigger_at)
+        .map(|(alarm_id, trigger_at)| NextAlarm::new(Some(alarm_id), trigger_at, timezone))
+}
+
+/// Computes the next instant an alarm rings at
+///
+/// The alarm rings at `time` plus `offset_minutes` of wall-clock time in the
+/// user's timezone, on the days set in `days_of_week`. The days apply to
+/// `time`, so an offset can move the trigger to the previous or next day.
+/// Wall-clock times repeated by a DST change ring at their first occurrence,
+/// times skipped by one ring at the end of the gap.
+///
+/// # Arguments
+///
+/// * `time` - The local time of the alarm
+/// * `offset_minutes` - Minutes added to the alarm time, may be negative
+/// * `days_of_week` - Bitmask of the days the alarm rings on, bit 0 is Monday
+/// * `timezone` - The user's timezone
+/// * `now` - The current instant
+///
+/// # Returns
+///
+/// The first trigger strictly after `now`, or `None` if no day is set
+pub fn next_trigger(
+    time: NaiveTime,
+    offset_minutes: i32,
+    days_of_week: i16,
+    timezone: Tz,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let today = now.with_timezone(&timezone).date_naive();
+    // Check enough days around today for the offset to move a trigger past now
+    let spread = i64::from(offset_minutes.unsigned_abs() / MINUTES_PER_DAY) + 1;
+
+    (-spread..=7 + spread)
+        .filter_map(|days| today.checked_add_signed(Duration::days(days)))
+        .filter(|date| days_of_week & (1 << date.weekday().num_days_from_monday()) != 0)
+        .filter_map(|date| {
+            let local = date.and_time(time) + Duration::minutes(offset_minutes.into());
+            resolve_local(local, timezone)
+        })
+        .filter(|trigger_at| *trigger_at > now)
+        .min()
+}
+
+/// Converts a wall-clock time to an instant, moving times skipped by DST past the gap
+fn resolve_local(local: NaiveDateTime, timezone: Tz) -> Option<DateTime<Utc>> {
+    (0..=MAX_DST_GAP_MINUTES)
+        .find_map(|minutes| {
+            timezone
+                .from_local_datetime(&(local + Duration::minutes(minutes)))
+                .earliest()
+        })
+        .map(|trigger_at| trigger_at.with_timezone(&Utc))
+}
+
+/// Represents a request to create a new alarm
+///
+/// # Fields
+///
+/// * `label` - Name of the alarm
+/// * `time` - The local time the alarm rings at
+/// * `offset_minutes` - Additional offset in minutes (defaults to 0)
+/// * `enabled` - Whether the alarm is active (defaults to true)
+/// * `days_of_week` - Bitmask of the days the alarm rings on (defaults to every day)
+#[derive(Deserialize)]
+pub struct CreateAlarm {
+    pub label: String,
+    pub time: NaiveTime,
+    #[serde(default)]
+    pub offset_minutes: i32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_days_of_week")]
+    pub days_of_week: i16,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_days_of_week() -> i16 {
+    EVERY_DAY
+}
+
+impl CreateAlarm {
+    /// Validates the alarm creation data
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the data is valid
+    /// * `Err(TranslationKey)` - `AlarmLabelInvalid` or `AlarmDaysInvalid`
+    pub fn validate(&self) -> Result<(), TranslationKey> {
+        validate_alarm_label(&self.label)?;
+        validate_days_of_week(self.days_of_week)
+    }
+}
+
+/// Represents updates to an alarm
+///
+/// All fields are optional, allowing for selective updates without affecting
+/// unchanged fields.
+///
+/// # Fields
+///
+/// * `label` - Optional new name
+/// * `time` - Optional new time
+/// * `offset_minutes` - Optional new offset
+/// * `enabled` - Optional new active status
+/// * `days_of_week` - Optional new days bitmask
+#[derive(Deserialize)]
+pub struct UpdateAlarm {
+    pub label: Option<String>,
+    pub time: Option<NaiveTime>,
+    pub offset_minutes: Option<i32>,
+    pub enabled: Option<bool>,
+    pub days_of_week: Option<i16>,
+}
+
+impl UpdateAlarm {
+    /// Validates the alarm update data
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the data is valid
+    /// * `Err(TranslationKey)` - `AlarmLabelInvalid` or `AlarmDaysInvalid`
+    pub fn validate(&self) -> Result<(), TranslationKey> {
+        if let Some(label) = &self.label {
+            validate_alarm_label(label)?;
+        }
+        if let Some(days_of_week) = self.days_of_week {
+            validate_days_of_week(days_of_week)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates an alarm label
+///
+/// Leading and trailing whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `label` - The label to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - If the label is between 1 and `ALARM_LABEL_MAX_LENGTH` characters
+/// * `Err(TranslationKey)` - `AlarmLabelInvalid` if the label is empty or too long
+pub fn validate_alarm_label(label: &str) -> Result<(), TranslationKey> {
+    let length = label.trim().chars().count();
+    if length == 0 || length > ALARM_LABEL_MAX_LENGTH {
+        return Err(TranslationKey::AlarmLabelInvalid);
+    }
+
+    Ok(())
+}
+
+/// Validates a days of week bitmask
+///
+/// # Arguments
+///
+/// * `days_of_week` - The bitmask to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - If at least one day and only the seven week days are set
+/// * `Err(TranslationKey)` - `AlarmDaysInvalid` otherwise
+pub fn validate_days_of_week(days_of_week: i16) -> Result<(), TranslationKey> {
+    if !(1..=EVERY_DAY).contains(&days_of_week) {
+        return Err(TranslationKey::AlarmDaysInvalid);
+    }
+
+    Ok(())
+}
+
+/// Checks whether a user already has the maximum number of alarms
+///
+/// # Arguments
+///
+/// * `alarm_count` - Number of alarms the user currently has
+/// * `max_alarms` - Maximum number of alarms allowed, 0 for unlimited
+///
+/// # Returns
+///
+/// `true` if another alarm may not be created
+pub fn is_alarm_limit_reached(alarm_count: usize, max_alarms: usize) -> bool {
+    max_alarms != 0 && alarm_count >= max_alarms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_alarm_defaults() {
+        let alarm: CreateAlarm =
+            serde_json::from_str(r#"{ "label": "Morning budget check", "time": "08:00:00" }"#)
+                .unwrap();
+
+        assert_eq!(alarm.offset_minutes, 0);
+        assert!(alarm.enabled);
+        assert_eq!(alarm.days_of_week, EVERY_DAY);
+        assert!(alarm.validate().is_ok());
+    }
+
+    #[test]
+    fn test_create_alarm_requires_time() {
+        assert!(serde_json::from_str::<CreateAlarm>(r#"{ "label": "Review" }"#).is_err());
+    }
+
+    #[test]
+    fn test_validate_alarm_label() {
+        assert!(validate_alarm_label("Month end review").is_ok());
+        assert!(validate_alarm_label(&"a".repeat(ALARM_LABEL_MAX_LENGTH)).is_ok());
+        assert_eq!(
+            validate_alarm_label("   "),
+            Err(TranslationKey::AlarmLabelInvalid)
+        );
+        assert!(validate_alarm_label(&"a".repeat(ALARM_LABEL_MAX_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_days_of_week() {
+        // Monday to Friday
+        assert!(validate_days_of_week(0b001_1111).is_ok());
+        assert!(validate_days_of_week(EVERY_DAY).is_ok());
+        assert_eq!(
+            validate_days_of_week(0),
+            Err(TranslationKey::AlarmDaysInvalid)
+        );
+        assert!(validate_days_of_week(128).is_err());
+        assert!(validate_days_of_week(-1).is_err());
+    }
+
+    #[test]
+    fn test_update_alarm_validate() {
+        let update: UpdateAlarm = serde_json::from_str(r#"{ "enabled": false }"#).unwrap();
+        assert!(update.validate().is_ok());
+
+        let update: UpdateAlarm = serde_json::from_str(r#"{ "label": "" }"#).unwrap();
+        assert_eq!(update.validate(), Err(TranslationKey::AlarmLabelInvalid));
+
+        let update: UpdateAlarm = serde_json::from_str(r#"{ "days_of_week": 0 }"#).unwrap();
+        assert_eq!(update.validate(), Err(TranslationKey::AlarmDaysInvalid));
+    }
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().to_utc()
+    }
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_next_trigger_later_today() {
+        let next = next_trigger(at(8, 0), 0, EVERY_DAY, Tz::UTC, utc("2025-11-10T07:00:00Z"));
+        assert_eq!(next, Some(utc("2025-11-10T08:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_rolls_over_when_passed_today() {
+        let next = next_trigger(
+            at(8, 0),
+            0,
+            EVERY_DAY,
+            Tz::Europe__Berlin,
+            utc("2025-11-10T07:00:00Z"),
+        );
+        // 08:00 CET was 07:00 UTC, so the alarm rings tomorrow
+        assert_eq!(next, Some(utc("2025-11-11T07:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_across_spring_forward() {
+        // Berlin moves from CET to CEST on 2025-03-30
+        let next = next_trigger(
+            at(8, 0),
+            0,
+            EVERY_DAY,
+            Tz::Europe__Berlin,
+            utc("2025-03-29T10:00:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-03-30T06:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_in_spring_forward_gap() {
+        // 02:30 does not exist in Berlin on 2025-03-30, the clock jumps to 03:00 CEST
+        let next = next_trigger(
+            at(2, 30),
+            0,
+            EVERY_DAY,
+            Tz::Europe__Berlin,
+            utc("2025-03-29T12:00:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-03-30T01:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_in_fall_back_overlap() {
+        // 02:30 happens twice in Berlin on 2025-10-26, the first one is CEST
+        let next = next_trigger(
+            at(2, 30),
+            0,
+            EVERY_DAY,
+            Tz::Europe__Berlin,
+            utc("2025-10-25T12:00:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-10-26T00:30:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_across_fall_back_in_new_york() {
+        // New York moves from EDT to EST on 2025-11-02
+        let next = next_trigger(
+            at(7, 0),
+            0,
+            EVERY_DAY,
+            Tz::America__New_York,
+            utc("2025-11-01T12:00:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-11-02T12:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_offset_moves_to_next_day() {
+        let next = next_trigger(
+            at(23, 50),
+            20,
+            EVERY_DAY,
+            Tz::UTC,
+            utc("2025-11-10T23:55:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-11-11T00:10:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_negative_offset() {
+        let next = next_trigger(
+            at(8, 0),
+            -15,
+            EVERY_DAY,
+            Tz::UTC,
+            utc("2025-11-10T07:50:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-11-11T07:45:00Z")));
+    }
+
+    #[test]
+    fn test_next_trigger_only_on_selected_days() {
+        // 2025-11-10 is a Monday, the alarm only rings on Saturdays
+        let next = next_trigger(
+            at(9, 0),
+            0,
+            0b010_0000,
+            Tz::UTC,
+            utc("2025-11-10T07:00:00Z"),
+        );
+        assert_eq!(next, Some(utc("2025-11-15T09:00:00Z")));
+        assert_eq!(
+            next_trigger(at(9, 0), 0, 0, Tz::UTC, utc("2025-11-10T07:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_next_alarm_skips_disabled_alarms() {
+        let alarm = |hour, enabled| Alarm {
+            id: Uuid::new_v4(),
+            label: "Alarm".to_string(),
+            time: at(hour, 0),
+            offset_minutes: 0,
+            enabled,
+            days_of_week: EVERY_DAY,
+            created_at: Utc::now(),
+        };
+        let now = utc("2025-11-10T07:00:00Z");
+
+        let alarms = vec![alarm(8, false), alarm(10, true), alarm(9, true)];
+        let next = next_alarm(&alarms, Tz::Europe__Berlin, now).unwrap();
+
+        assert_eq!(next.alarm_id, Some(alarms[2].id));
+        assert_eq!(next.trigger_at, utc("2025-11-10T08:00:00Z"));
+        assert_eq!(next.local_time.to_rfc3339(), "2025-11-10T09:00:00+01:00");
+
+        assert!(next_alarm(&[alarm(8, false)], Tz::UTC, now).is_none());
+    }
+
+    #[test]
+    fn test_is_alarm_limit_reached() {
+        assert!(!is_alarm_limit_reached(9, 10));
+        assert!(is_alarm_limit_reached(10, 10));
+        assert!(!is_alarm_limit_reached(100, 0));
+    }
+}
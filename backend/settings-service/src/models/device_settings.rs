@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared_types::enums::Language;
+use sqlx::{FromRow, types::Json};
+use uuid::Uuid;
+
+use crate::models::{
+    response::TranslationKey,
+    settings::{InvalidSetting, Settings, resolve_theme},
+};
+
+/// Settings a single device can override
+///
+/// Fields left out keep the value of the user's settings. Only these fields
+/// can be overridden, any other field is rejected.
+///
+/// # Fields
+///
+/// * `night_mode` - Optional night mode of the device, deprecated in favor of `theme`
+/// * `theme` - Optional color theme of the device
+/// * `language` - Optional language code of the device
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeviceOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub night_mode: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl DeviceOverrides {
+    /// Validates the theme and language against the supported values
+    ///
+    /// The theme and `night_mode` are made consistent like in a settings update.
+    ///
+    /// # Returns
+    /// * `Ok(DeviceOverrides)` - The overrides with the theme and language normalized
+    /// * `Err(InvalidSetting)` - `InvalidTheme` or `InvalidLanguage` for an unsupported value
+    pub fn normalized(mut self) -> Result<Self, InvalidSetting> {
+        resolve_theme(&mut self.theme, &mut self.night_mode)?;
+
+        if let Some(language) = &self.language {
+            let language = Language::from_str(language)
+                .map_err(|_| InvalidSetting::new("language", TranslationKey::InvalidLanguage))?;
+            self.language = Some(language.as_str().to_string());
+        }
+
+        Ok(self)
+    }
+}
+
+/// The settings overrides of a device
+///
+/// This struct maps to the `device_settings` table.
+///
+/// # Fields
+///
+/// * `device_id` - The client generated identifier of the device
+/// * `overrides` - The settings the device overrides
+/// * `last_seen_at` - When the device last read or wrote its settings
+#[derive(FromRow, Debug, Serialize)]
+pub struct DeviceSettings {
+    pub device_id: Uuid,
+    pub overrides: Json<DeviceOverrides>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// The user's settings as seen by a device
+///
+/// # Fields
+///
+/// * `settings` - The user's settings with the device's overrides applied
+/// * `overridden` - Names of the fields whose value comes from the device
+///
+/// # Example
+///
+/// ```json
+/// {
+///     "user_id": "550e8400-e29b-41d4-a716-446655440000",
+///     "language": "en",
+///     "night_mode": true,
+///     "theme": "dark",
+///     "overridden": ["night_mode", "theme"]
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct MergedSettings {
+    #[serde(flatten)]
+    pub settings: Settings,
+    pub overridden: Vec<&'static str>,
+}
+
+/// Checks whether a user already has the maximum number of devices
+///
+/// # Arguments
+///
+/// * `device_count` - Number of devices the user currently has overrides for
+/// * `max_devices` - Maximum number of devices allowed, 0 for unlimited
+///
+/// # Returns
+///
+/// `true` if overrides for another device may not be stored
+pub fn is_device_limit_reached(device_count: usize, max_devices: usize) -> bool {
+    max_devices != 0 && device_count >= max_devices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_overrides_deserialization() {
+        let overrides: DeviceOverrides = serde_json::from_str(r#"{ "night_mode": true }"#).unwrap();
+        assert_eq!(overrides.night_mode, Some(true));
+        assert_eq!(overrides.language, None);
+
+        let overrides: DeviceOverrides = serde_json::from_str("{}").unwrap();
+        assert_eq!(overrides, DeviceOverrides::default());
+    }
+
+    #[test]
+    fn test_device_overrides_reject_other_fields() {
+        assert!(serde_json::from_str::<DeviceOverrides>(r#"{ "currency": "EUR" }"#).is_err());
+    }
+
+    #[test]
+    fn test_device_overrides_serialization_skips_missing_fields() {
+        let overrides = DeviceOverrides {
+            night_mode: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&overrides).unwrap(),
+            r#"{"night_mode":false}"#
+        );
+    }
+
+    #[test]
+    fn test_device_overrides_normalized() {
+        let overrides = DeviceOverrides {
+            language: Some("RO".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            overrides.normalized().unwrap().language.as_deref(),
+            Some("ro")
+        );
+
+        let overrides = DeviceOverrides {
+            language: Some("xx".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            overrides.normalized(),
+            Err(InvalidSetting::new(
+                "language",
+                TranslationKey::InvalidLanguage
+            ))
+        );
+    }
+
+    #[test]
+    fn test_device_overrides_normalized_theme() {
+        let overrides = DeviceOverrides {
+            theme: Some("System".to_string()),
+            ..Default::default()
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(overrides.theme.as_deref(), Some("system"));
+        assert_eq!(overrides.night_mode, Some(false));
+
+        let overrides = DeviceOverrides {
+            theme: Some("sepia".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            overrides.normalized(),
+            Err(InvalidSetting::new("theme", TranslationKey::InvalidTheme))
+        );
+    }
+
+    #[test]
+    fn test_is_device_limit_reached() {
+        assert!(!is_device_limit_reached(19, 20));
+        assert!(is_device_limit_reached(20, 20));
+        assert!(!is_device_limit_reached(100, 0));
+    }
+}
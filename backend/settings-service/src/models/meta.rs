@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use shared_types::enums::{
+    BudgetPeriod, Currency, CurrencySymbolPosition, DateFormat, Language, NumberFormat,
+    SummaryEmailFrequency, Theme, ThousandsSeparator, WalletType, WeekStart,
+};
+
+/// The values of every enum the backend accepts
+///
+/// Lets the frontend build its dropdowns from the backend instead of
+/// hardcoding them. Each list holds the `as_str()` values in `all()` order,
+/// the labels map values to their display name in `language`.
+///
+/// # Fields
+///
+/// * `currencies` - Supported currency codes (e.g., "USD")
+/// * `languages` - Supported language codes (e.g., "en")
+/// * `wallet_types` - Supported wallet types (e.g., "Savings")
+/// * `date_formats` - Supported date formats (e.g., "ISO")
+/// * `number_formats` - Supported number formats (e.g., "dot-decimal")
+/// * `week_starts` - Supported first days of the week (e.g., "Monday")
+/// * `budget_periods` - Supported budget periods (e.g., "Monthly")
+/// * `themes` - Supported color themes (e.g., "dark")
+/// * `summary_email_frequencies` - Supported summary email frequencies (e.g., "Weekly")
+/// * `currency_symbol_positions` - Supported currency symbol positions (e.g., "Suffix")
+/// * `thousands_separators` - Supported thousands separators (e.g., "Space")
+/// * `language` - The language of the labels
+/// * `wallet_type_labels` - Display name of each wallet type (e.g., "CreditCard": "Credit Card")
+#[derive(Serialize)]
+pub struct EnumValues {
+    pub currencies: Vec<&'static str>,
+    pub languages: Vec<&'static str>,
+    pub wallet_types: Vec<&'static str>,
+    pub date_formats: Vec<&'static str>,
+    pub number_formats: Vec<&'static str>,
+    pub week_starts: Vec<&'static str>,
+    pub budget_periods: Vec<&'static str>,
+    pub themes: Vec<&'static str>,
+    pub summary_email_frequencies: Vec<&'static str>,
+    pub currency_symbol_positions: Vec<&'static str>,
+    pub thousands_separators: Vec<&'static str>,
+    pub language: Language,
+    pub wallet_type_labels: BTreeMap<&'static str, &'static str>,
+}
+
+impl EnumValues {
+    /// Collects the values of every supported enum
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the labels
+    pub fn supported(language: Language) -> Self {
+        Self {
+            currencies: Currency::all().iter().map(Currency::as_str).collect(),
+            languages: Language::all().iter().map(Language::as_str).collect(),
+            wallet_types: WalletType::all().iter().map(WalletType::as_str).collect(),
+            date_formats: DateFormat::all().iter().map(DateFormat::as_str).collect(),
+            number_formats: NumberFormat::all()
+                .iter()
+                .map(NumberFormat::as_str)
+                .collect(),
+            week_starts: WeekStart::all().iter().map(WeekStart::as_str).collect(),
+            budget_periods: BudgetPeriod::all()
+                .iter()
+                .map(BudgetPeriod::as_str)
+                .collect(),
+            themes: Theme::all().iter().map(Theme::as_str).collect(),
+            summary_email_frequencies: SummaryEmailFrequency::all()
+                .iter()
+                .map(SummaryEmailFrequency::as_str)
+                .collect(),
+            currency_symbol_positions: CurrencySymbolPosition::all()
+                .iter()
+                .map(CurrencySymbolPosition::as_str)
+                .collect(),
+            thousands_separators: ThousandsSeparator::all()
+                .iter()
+                .map(ThousandsSeparator::as_str)
+                .collect(),
+            language,
+            wallet_type_labels: WalletType::all()
+                .iter()
+                .map(|wallet_type| (wallet_type.as_str(), wallet_type.display_name(language)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_enum_values() {
+        let values = EnumValues::supported(Language::En);
+
+        assert_eq!(values.currencies.len(), Currency::all().len());
+        assert!(values.currencies.contains(&"RON"));
+        assert!(values.languages.contains(&"en"));
+        assert_eq!(values.wallet_types[0], "Account");
+        assert_eq!(values.budget_periods, vec!["Weekly", "Monthly"]);
+        assert_eq!(values.themes, vec!["light", "dark", "system"]);
+        assert_eq!(
+            values.summary_email_frequencies,
+            vec!["Off", "Weekly", "Monthly"]
+        );
+        assert_eq!(values.currency_symbol_positions, vec!["Prefix", "Suffix"]);
+        assert_eq!(
+            values.thousands_separators,
+            vec!["Comma", "Dot", "Space", "None"]
+        );
+    }
+
+    #[test]
+    fn test_enum_values_serialization() {
+        let json = serde_json::to_value(EnumValues::supported(Language::En)).unwrap();
+
+        assert_eq!(json["week_starts"], serde_json::json!(["Monday", "Sunday"]));
+        assert!(
+            json["wallet_types"]
+                .as_array()
+                .unwrap()
+                .contains(&"CreditCard".into())
+        );
+        assert_eq!(json["language"], "en");
+    }
+
+    #[test]
+    fn test_localized_wallet_type_labels() {
+        let values = EnumValues::supported(Language::Es);
+
+        assert_eq!(values.wallet_type_labels.len(), WalletType::all().len());
+        assert_eq!(
+            values.wallet_type_labels["CreditCard"],
+            "Tarjeta de crédito"
+        );
+        assert_eq!(values.wallet_type_labels["Account"], "Cuenta");
+    }
+}
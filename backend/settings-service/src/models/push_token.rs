@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::response::TranslationKey;
+
+/// Maximum length of a push token in characters
+pub const PUSH_TOKEN_MAX_LENGTH: usize = 4096;
+
+/// Platforms push notifications can be delivered to
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PushPlatform {
+    /// Apple Push Notification service
+    Ios,
+    /// Firebase Cloud Messaging
+    Android,
+    /// Web Push
+    Web,
+}
+
+impl PushPlatform {
+    /// Returns the platform as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PushPlatform::Ios => "ios",
+            PushPlatform::Android => "android",
+            PushPlatform::Web => "web",
+        }
+    }
+
+    /// Returns all supported platforms
+    pub fn all() -> &'static [PushPlatform] {
+        &[PushPlatform::Ios, PushPlatform::Android, PushPlatform::Web]
+    }
+}
+
+impl std::fmt::Display for PushPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PushPlatform {
+    type Err = String;
+
+    /// Parses a platform case-insensitively (e.g. "ios" or "iOS")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PushPlatform::all()
+            .iter()
+            .find(|platform| platform.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported push platform: {}", s))
+    }
+}
+
+/// Represents a push notification token of a user's device
+///
+/// This struct maps to the `push_tokens` table.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier of the token registration
+/// * `platform` - The platform the token belongs to ("ios", "android" or "web")
+/// * `token` - The token issued by the platform's push service
+/// * `created_at` - When the token was first registered
+/// * `last_seen_at` - When the token was last registered
+#[derive(FromRow, Clone, Debug, Serialize)]
+pub struct PushToken {
+    pub id: Uuid,
+    pub platform: String,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// Represents a request to register a push token
+///
+/// # Fields
+///
+/// * `platform` - The platform the token belongs to
+/// * `token` - The token issued by the platform's push service
+#[derive(Deserialize)]
+pub struct RegisterPushToken {
+    pub platform: String,
+    pub token: String,
+}
+
+impl RegisterPushToken {
+    /// Validates the registration and normalizes the platform and token
+    ///
+    /// Leading and trailing whitespace of the token is ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RegisterPushToken)` - The registration with a lowercase platform and trimmed token
+    /// * `Err(TranslationKey)` - `InvalidPushPlatform` or `InvalidPushToken`
+    pub fn normalized(self) -> Result<Self, TranslationKey> {
+        let platform = PushPlatform::from_str(&self.platform)
+            .map_err(|_| TranslationKey::InvalidPushPlatform)?;
+
+        let token = self.token.trim();
+        let length = token.chars().count();
+        if length == 0 || length > PUSH_TOKEN_MAX_LENGTH {
+            return Err(TranslationKey::InvalidPushToken);
+        }
+
+        Ok(Self {
+            platform: platform.as_str().to_string(),
+            token: token.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registration(platform: &str, token: &str) -> RegisterPushToken {
+        RegisterPushToken {
+            platform: platform.into(),
+            token: token.into(),
+        }
+    }
+
+    #[test]
+    fn test_push_platform_from_str() {
+        assert_eq!(PushPlatform::from_str("ios").unwrap(), PushPlatform::Ios);
+        assert_eq!(
+            PushPlatform::from_str("Android").unwrap(),
+            PushPlatform::Android
+        );
+        assert_eq!(PushPlatform::from_str("WEB").unwrap(), PushPlatform::Web);
+        assert!(PushPlatform::from_str("windows").is_err());
+        assert!(PushPlatform::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_normalized_registration() {
+        let registration = registration("iOS", "  abc123  ").normalized().unwrap();
+
+        assert_eq!(registration.platform, "ios");
+        assert_eq!(registration.token, "abc123");
+    }
+
+    #[test]
+    fn test_normalized_rejects_unknown_platform() {
+        assert_eq!(
+            registration("blackberry", "abc123").normalized().err(),
+            Some(TranslationKey::InvalidPushPlatform)
+        );
+    }
+
+    #[test]
+    fn test_normalized_rejects_invalid_token() {
+        assert_eq!(
+            registration("android", "   ").normalized().err(),
+            Some(TranslationKey::InvalidPushToken)
+        );
+        assert_eq!(
+            registration("android", &"a".repeat(PUSH_TOKEN_MAX_LENGTH + 1))
+                .normalized()
+                .err(),
+            Some(TranslationKey::InvalidPushToken)
+        );
+        assert!(
+            registration("android", &"a".repeat(PUSH_TOKEN_MAX_LENGTH))
+                .normalized()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use uuid::Uuid;
+
+/// Period over which the write limit is counted
+const RATE_LIMIT_WINDOW_SECONDS: i64 = 60;
+
+/// Maximum number of users whose buckets are kept
+const RATE_LIMIT_CAPACITY: u64 = 100_000;
+
+/// A token bucket holding the writes a user may still make
+///
+/// The bucket holds at most `capacity` tokens and refills continuously at
+/// `capacity` tokens per minute, so bursts up to the limit are allowed.
+///
+/// # Fields
+///
+/// * `capacity` - Maximum number of tokens, the writes allowed per minute
+/// * `tokens` - Tokens currently available
+/// * `updated_at` - When the tokens were last refilled
+#[derive(Debug)]
+pub struct TokenBucket {
+    capacity: u32,
+    tokens: f64,
+    updated_at: DateTime<Utc>,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of writes per minute
+    /// * `now` - The current time
+    pub fn new(capacity: u32, now: DateTime<Utc>) -> Self {
+        Self {
+            capacity,
+            tokens: capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    /// Takes a token for a write if one is available
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The write may proceed
+    /// * `Err(u64)` - Seconds until a token is available
+    pub fn try_take(&mut self, now: DateTime<Utc>) -> Result<(), u64> {
+        let per_second = self.capacity as f64 / RATE_LIMIT_WINDOW_SECONDS as f64;
+        let elapsed = (now - self.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+
+        self.tokens = (self.tokens + elapsed * per_second).min(self.capacity as f64);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(((1.0 - self.tokens) / per_second).ceil().max(1.0) as u64)
+    }
+}
+
+/// Limits how many settings writes each user can make per minute
+///
+/// Buckets are dropped once a user has been idle for a whole window, when
+/// they would be full again anyway.
+///
+/// # Fields
+///
+/// * `writes_per_minute` - Writes allowed per user and minute, 0 for unlimited
+/// * `buckets` - The token bucket of each user
+pub struct WriteRateLimiter {
+    writes_per_minute: u32,
+    buckets: Cache<Uuid, Arc<Mutex<TokenBucket>>>,
+}
+
+impl WriteRateLimiter {
+    /// Creates a new rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `writes_per_minute` - Writes allowed per user and minute, 0 for unlimited
+    ///
+    /// # Returns
+    ///
+    /// A new `WriteRateLimiter`
+    pub fn new(writes_per_minute: u32) -> Self {
+        let buckets = Cache::builder()
+            .max_capacity(RATE_LIMIT_CAPACITY)
+            .time_to_idle(Duration::from_secs(RATE_LIMIT_WINDOW_SECONDS as u64))
+            .build();
+
+        Self {
+            writes_per_minute,
+            buckets,
+        }
+    }
+
+    /// Records a write for a user if their limit allows it
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user making the write
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The write may proceed
+    /// * `Err(u64)` - Seconds the user has to wait before writing again
+    pub async fn try_acquire(&self, user_id: Uuid, now: DateTime<Utc>) -> Result<(), u64> {
+        if self.writes_per_minute == 0 {
+            return Ok(());
+        }
+
+        let bucket = self
+            .buckets
+            .get_with(user_id, async {
+                Arc::new(Mutex::new(TokenBucket::new(self.writes_per_minute, now)))
+            })
+            .await;
+
+        bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .try_take(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_up_to_capacity() {
+        let now = Utc::now();
+        let mut bucket = TokenBucket::new(3, now);
+
+        assert!(bucket.try_take(now).is_ok());
+        assert!(bucket.try_take(now).is_ok());
+        assert!(bucket.try_take(now).is_ok());
+        assert_eq!(bucket.try_take(now), Err(20));
+    }
+
+    #[test]
+    fn test_bucket_refills_over_time() {
+        let now = Utc::now();
+        let mut bucket = TokenBucket::new(30, now);
+        for _ in 0..30 {
+            bucket.try_take(now).unwrap();
+        }
+
+        assert_eq!(bucket.try_take(now + Duration::seconds(1)), Err(1));
+        assert!(bucket.try_take(now + Duration::seconds(2)).is_ok());
+        assert!(bucket.try_take(now + Duration::seconds(2)).is_err());
+    }
+
+    #[test]
+    fn test_bucket_does_not_exceed_capacity() {
+        let now = Utc::now();
+        let mut bucket = TokenBucket::new(2, now);
+        let later = now + Duration::hours(1);
+
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_is_per_user() {
+        let limiter = WriteRateLimiter::new(1);
+        let now = Utc::now();
+        let user_id = Uuid::new_v4();
+
+        assert!(limiter.try_acquire(user_id, now).await.is_ok());
+        assert_eq!(limiter.try_acquire(user_id, now).await, Err(60));
+        assert!(limiter.try_acquire(Uuid::new_v4(), now).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_disabled() {
+        let limiter = WriteRateLimiter::new(0);
+        let now = Utc::now();
+        let user_id = Uuid::new_v4();
+
+        for _ in 0..100 {
+            assert!(limiter.try_acquire(user_id, now).await.is_ok());
+        }
+    }
+}
@@ -1,8 +1,25 @@
-use chrono::NaiveTime;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use shared_types::{
+    enums::{
+        BudgetPeriod, Currency, CurrencySymbolPosition, DateFormat, Language, NumberFormat,
+        SummaryEmailFrequency, Theme, ThousandsSeparator, WeekStart,
+    },
+    money::{DisplayPrefs, MAX_DISPLAY_DECIMAL_PLACES},
+};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::models::{
+    alarm::Alarm,
+    device_settings::{DeviceOverrides, MergedSettings},
+    response::TranslationKey,
+    settings_schema::{self, CURRENT_SCHEMA_VERSION},
+};
+
 /// Represents user settings stored in the database
 ///
 /// This struct maps to the `user_settings` table and contains all user-specific
@@ -16,8 +33,26 @@ use uuid::Uuid;
 /// * `alarm_set` - Whether the user has enabled alarm notifications
 /// * `alarm_time` - The time when the alarm should trigger
 /// * `alarm_offset_minutes` - Additional offset in minutes for the alarm
-/// * `night_mode` - Whether the user has enabled dark/night mode
-#[derive(FromRow, Clone, Serialize)]
+/// * `night_mode` - Deprecated, whether the theme is dark; kept for older clients
+/// * `theme` - The user's color theme ("light", "dark" or "system")
+/// * `timezone` - The user's IANA timezone (e.g., "UTC", "America/New_York")
+/// * `date_format` - The user's preferred date format ("ISO", "DMY" or "MDY")
+/// * `number_format` - The user's preferred number format ("dot-decimal" or "comma-decimal")
+/// * `week_starts_on` - The first day of the user's week ("Monday" or "Sunday")
+/// * `budget_period` - The user's default budget period ("Weekly" or "Monthly")
+/// * `analytics_enabled` - Whether the user allows usage analytics
+/// * `crash_reports_enabled` - Whether the user allows crash reports
+/// * `summary_email_frequency` - How often the user gets summary emails ("Off", "Weekly" or
+///   "Monthly")
+/// * `summary_email_day` - The day summary emails are sent on, see `validate_summary_email`
+/// * `currency_symbol_position` - Where the currency symbol goes ("Prefix" or "Suffix")
+/// * `thousands_separator` - What separates groups of thousands ("Comma", "Dot", "Space" or
+///   "None")
+/// * `decimal_places_override` - Decimal places amounts are shown with, 0 to 4, or `None` for
+///   the currency's minor unit
+/// * `schema_version` - The settings schema version the row was written with
+/// * `updated_at` - When the settings were last written
+#[derive(FromRow, Clone, Serialize, Deserialize)]
 pub struct Settings {
     user_id: Uuid,
     language: String,
@@ -26,6 +61,445 @@ pub struct Settings {
     alarm_time: NaiveTime,
     alarm_offset_minutes: i32,
     night_mode: bool,
+    theme: String,
+    timezone: String,
+    date_format: String,
+    number_format: String,
+    week_starts_on: String,
+    budget_period: String,
+    analytics_enabled: bool,
+    crash_reports_enabled: bool,
+    summary_email_frequency: String,
+    summary_email_day: i32,
+    currency_symbol_position: String,
+    thousands_separator: String,
+    decimal_places_override: Option<i32>,
+    schema_version: i32,
+    updated_at: DateTime<Utc>,
+}
+
+impl Settings {
+    /// Returns the language code
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns the currency code
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Returns whether the daily alarm is enabled
+    pub fn alarm_set(&self) -> bool {
+        self.alarm_set
+    }
+
+    /// Returns the local time of the daily alarm
+    pub fn alarm_time(&self) -> NaiveTime {
+        self.alarm_time
+    }
+
+    /// Returns the alarm offset in minutes
+    pub fn alarm_offset_minutes(&self) -> i32 {
+        self.alarm_offset_minutes
+    }
+
+    /// Returns the IANA timezone name
+    pub fn timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    /// Returns the date format
+    pub fn date_format(&self) -> &str {
+        &self.date_format
+    }
+
+    /// Returns the number format
+    pub fn number_format(&self) -> &str {
+        &self.number_format
+    }
+
+    /// Returns the first day of the week
+    pub fn week_starts_on(&self) -> &str {
+        &self.week_starts_on
+    }
+
+    /// Returns the default budget period
+    pub fn budget_period(&self) -> &str {
+        &self.budget_period
+    }
+
+    /// Returns whether the user allows usage analytics
+    pub fn analytics_enabled(&self) -> bool {
+        self.analytics_enabled
+    }
+
+    /// Returns whether the user allows crash reports
+    pub fn crash_reports_enabled(&self) -> bool {
+        self.crash_reports_enabled
+    }
+
+    /// Returns how often summary emails are sent
+    pub fn summary_email_frequency(&self) -> &str {
+        &self.summary_email_frequency
+    }
+
+    /// Returns the day summary emails are sent on
+    pub fn summary_email_day(&self) -> i32 {
+        self.summary_email_day
+    }
+
+    /// Returns where the currency symbol goes
+    pub fn currency_symbol_position(&self) -> &str {
+        &self.currency_symbol_position
+    }
+
+    /// Returns what separates groups of thousands
+    pub fn thousands_separator(&self) -> &str {
+        &self.thousands_separator
+    }
+
+    /// Returns the decimal places amounts are shown with, if overridden
+    pub fn decimal_places_override(&self) -> Option<i32> {
+        self.decimal_places_override
+    }
+
+    /// Returns the preferences amounts are formatted with, see `format_amount`
+    ///
+    /// Stored values the service does not know fall back to the currency's
+    /// defaults.
+    pub fn display_prefs(&self) -> DisplayPrefs {
+        let defaults = DisplayPrefs::for_currency(
+            Currency::from_str(&self.currency).unwrap_or(DefaultSettings::DEFAULT.currency),
+        );
+
+        DisplayPrefs {
+            symbol_position: CurrencySymbolPosition::from_str(&self.currency_symbol_position)
+                .unwrap_or(defaults.symbol_position),
+            thousands_separator: ThousandsSeparator::from_str(&self.thousands_separator)
+                .unwrap_or(defaults.thousands_separator),
+            number_format: NumberFormat::from_str(&self.number_format)
+                .unwrap_or(defaults.number_format),
+            decimal_places: self
+                .decimal_places_override
+                .and_then(|places| u32::try_from(places).ok()),
+        }
+    }
+
+    /// Makes the amount display follow a currency change
+    ///
+    /// A symbol position or thousands separator still at the old currency's
+    /// default is switched to the new currency's default, unless the update
+    /// sets it. Values the user picked are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The normalized settings update
+    ///
+    /// # Returns
+    ///
+    /// The update, with the defaults of the new currency if it changes the currency
+    pub fn with_currency_defaults(&self, mut update: UpdateSettings) -> UpdateSettings {
+        let (Ok(old), Some(Ok(new))) = (
+            Currency::from_str(&self.currency),
+            update.currency.as_deref().map(Currency::from_str),
+        ) else {
+            return update;
+        };
+        if old == new {
+            return update;
+        }
+
+        if update.currency_symbol_position.is_none()
+            && self.currency_symbol_position == old.default_symbol_position().as_str()
+        {
+            update.currency_symbol_position =
+                Some(new.default_symbol_position().as_str().to_string());
+        }
+        if update.thousands_separator.is_none()
+            && self.thousands_separator == old.default_thousands_separator().as_str()
+        {
+            update.thousands_separator =
+                Some(new.default_thousands_separator().as_str().to_string());
+        }
+
+        update
+    }
+
+    /// Checks the combined settings an update would leave the user with
+    ///
+    /// The summary email frequency and day, and the number format and
+    /// thousands separator, are validated together, so fields missing from
+    /// the update are taken from the stored settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The normalized settings update
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The resulting settings are valid combinations
+    /// * `Err(InvalidSetting)` - `InvalidSummaryEmailDay` for a day the frequency does not have,
+    ///   or `InvalidThousandsSeparator` for a separator matching the decimal separator
+    pub fn validate_update(&self, update: &UpdateSettings) -> Result<(), InvalidSetting> {
+        let number_format = update
+            .number_format
+            .as_deref()
+            .unwrap_or(&self.number_format);
+        let number_format = NumberFormat::from_str(number_format).map_err(|_| {
+            InvalidSetting::new("number_format", TranslationKey::InvalidNumberFormat)
+        })?;
+        let thousands_separator = update
+            .thousands_separator
+            .as_deref()
+            .unwrap_or(&self.thousands_separator);
+        let thousands_separator =
+            ThousandsSeparator::from_str(thousands_separator).map_err(|_| {
+                InvalidSetting::new(
+                    "thousands_separator",
+                    TranslationKey::InvalidThousandsSeparator,
+                )
+            })?;
+        validate_separators(number_format, thousands_separator)?;
+
+        let frequency = update
+            .summary_email_frequency
+            .as_deref()
+            .unwrap_or(&self.summary_email_frequency);
+        let frequency = SummaryEmailFrequency::from_str(frequency).map_err(|_| {
+            InvalidSetting::new(
+                "summary_email_frequency",
+                TranslationKey::InvalidSummaryEmailFrequency,
+            )
+        })?;
+
+        validate_summary_email(
+            frequency,
+            update.summary_email_day.unwrap_or(self.summary_email_day),
+        )
+    }
+
+    /// Returns the entity tag of the stored settings
+    ///
+    /// Derived from `updated_at`, so it changes with every write.
+    pub fn etag(&self) -> String {
+        format!("\"{}\"", self.updated_at.timestamp_micros())
+    }
+
+    /// Upgrades a row written with an older schema version to the current one
+    ///
+    /// Rows inserted by older service versions miss the values of newer
+    /// fields; the registered upgrades fill them in. The row itself is
+    /// upgraded by its next write.
+    ///
+    /// # Returns
+    ///
+    /// The settings in the current schema, or unchanged if already current
+    pub fn upgraded(self) -> Self {
+        if self.schema_version >= CURRENT_SCHEMA_VERSION {
+            return self;
+        }
+
+        let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(&self) else {
+            return self;
+        };
+
+        settings_schema::upgrade(fields, self.schema_version)
+            .ok()
+            .and_then(|mut fields| {
+                fields.insert("schema_version".into(), CURRENT_SCHEMA_VERSION.into());
+                serde_json::from_value(serde_json::Value::Object(fields)).ok()
+            })
+            .unwrap_or(self)
+    }
+
+    /// Maps the user's first alarm onto the legacy alarm fields
+    ///
+    /// Alarms live in the `user_alarms` table; `alarm_set`, `alarm_time` and
+    /// `alarm_offset_minutes` are kept for older clients and mirror the first
+    /// alarm. Users without alarms keep the values stored on the settings row.
+    ///
+    /// # Arguments
+    ///
+    /// * `alarm` - The user's first alarm, if any
+    ///
+    /// # Returns
+    ///
+    /// The settings with the legacy alarm fields taken from the alarm
+    pub fn with_legacy_alarm(mut self, alarm: Option<&Alarm>) -> Self {
+        if let Some(alarm) = alarm {
+            self.alarm_set = alarm.enabled;
+            self.alarm_time = alarm.time;
+            self.alarm_offset_minutes = alarm.offset_minutes;
+        }
+
+        self
+    }
+
+    /// Applies the overrides of a device on top of the settings
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The device's overrides
+    ///
+    /// # Returns
+    ///
+    /// The merged settings and the names of the fields taken from the device
+    pub fn with_device_overrides(mut self, overrides: &DeviceOverrides) -> MergedSettings {
+        let mut overridden = Vec::new();
+
+        if let Some(night_mode) = overrides.night_mode {
+            self.night_mode = night_mode;
+            overridden.push("night_mode");
+        }
+        // Overrides stored before themes existed only carry night_mode
+        let theme = overrides.theme.clone().or_else(|| {
+            overrides
+                .night_mode
+                .map(|night_mode| Theme::from_night_mode(night_mode).as_str().to_string())
+        });
+        if let Some(theme) = theme {
+            self.theme = theme;
+            overridden.push("theme");
+        }
+        if let Some(language) = &overrides.language {
+            self.language = language.clone();
+            overridden.push("language");
+        }
+
+        MergedSettings {
+            settings: self,
+            overridden,
+        }
+    }
+
+    /// Computes the fields an update would change
+    ///
+    /// Fields missing from the update or set to their current value are not
+    /// included, so an update that changes nothing yields an empty map.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The normalized settings update
+    ///
+    /// # Returns
+    ///
+    /// The changed field names mapped to `{"old": ..., "new": ...}`
+    pub fn changes(&self, update: &UpdateSettings) -> serde_json::Map<String, serde_json::Value> {
+        let mut changes = serde_json::Map::new();
+
+        record_change(&mut changes, "language", &self.language, &update.language);
+        record_change(&mut changes, "currency", &self.currency, &update.currency);
+        record_change(
+            &mut changes,
+            "alarm_set",
+            &self.alarm_set,
+            &update.alarm_set,
+        );
+        record_change(
+            &mut changes,
+            "alarm_time",
+            &self.alarm_time,
+            &update.alarm_time,
+        );
+        record_change(
+            &mut changes,
+            "alarm_offset_minutes",
+            &self.alarm_offset_minutes,
+            &update.alarm_offset_minutes,
+        );
+        record_change(
+            &mut changes,
+            "night_mode",
+            &self.night_mode,
+            &update.night_mode,
+        );
+        record_change(&mut changes, "theme", &self.theme, &update.theme);
+        record_change(&mut changes, "timezone", &self.timezone, &update.timezone);
+        record_change(
+            &mut changes,
+            "date_format",
+            &self.date_format,
+            &update.date_format,
+        );
+        record_change(
+            &mut changes,
+            "number_format",
+            &self.number_format,
+            &update.number_format,
+        );
+        record_change(
+            &mut changes,
+            "week_starts_on",
+            &self.week_starts_on,
+            &update.week_starts_on,
+        );
+        record_change(
+            &mut changes,
+            "budget_period",
+            &self.budget_period,
+            &update.budget_period,
+        );
+        record_change(
+            &mut changes,
+            "analytics_enabled",
+            &self.analytics_enabled,
+            &update.analytics_enabled,
+        );
+        record_change(
+            &mut changes,
+            "crash_reports_enabled",
+            &self.crash_reports_enabled,
+            &update.crash_reports_enabled,
+        );
+        record_change(
+            &mut changes,
+            "summary_email_frequency",
+            &self.summary_email_frequency,
+            &update.summary_email_frequency,
+        );
+        record_change(
+            &mut changes,
+            "summary_email_day",
+            &self.summary_email_day,
+            &update.summary_email_day,
+        );
+        record_change(
+            &mut changes,
+            "currency_symbol_position",
+            &self.currency_symbol_position,
+            &update.currency_symbol_position,
+        );
+        record_change(
+            &mut changes,
+            "thousands_separator",
+            &self.thousands_separator,
+            &update.thousands_separator,
+        );
+        record_change(
+            &mut changes,
+            "decimal_places_override",
+            &self.decimal_places_override,
+            &update.decimal_places_override,
+        );
+
+        changes
+    }
+}
+
+/// Adds a field to the changes if the update sets it to a different value
+fn record_change<T: Serialize + PartialEq>(
+    changes: &mut serde_json::Map<String, serde_json::Value>,
+    field: &str,
+    old: &T,
+    new: &Option<T>,
+) {
+    if let Some(new) = new.as_ref().filter(|new| *new != old) {
+        changes.insert(
+            field.to_string(),
+            serde_json::json!({ "old": old, "new": new }),
+        );
+    }
 }
 
 /// Represents updates to user settings
@@ -40,8 +514,26 @@ pub struct Settings {
 /// * `alarm_set` - Optional alarm enabled status
 /// * `alarm_time` - Optional new alarm time
 /// * `alarm_offset_minutes` - Optional new alarm offset
-/// * `night_mode` - Optional night mode status
-#[derive(Deserialize)]
+/// * `night_mode` - Optional night mode status, deprecated in favor of `theme`
+/// * `theme` - Optional new color theme
+/// * `timezone` - Optional new IANA timezone
+/// * `date_format` - Optional new date format
+/// * `number_format` - Optional new number format
+/// * `week_starts_on` - Optional new first day of the week
+/// * `budget_period` - Optional new default budget period
+/// * `analytics_enabled` - Optional usage analytics opt-in
+/// * `crash_reports_enabled` - Optional crash reports opt-in
+/// * `summary_email_frequency` - Optional new summary email frequency
+/// * `summary_email_day` - Optional new summary email day
+/// * `currency_symbol_position` - Optional new currency symbol position
+/// * `thousands_separator` - Optional new thousands separator
+/// * `decimal_places_override` - Optional new decimal places override, `null` to use the
+///   currency's minor unit again
+///
+/// Unknown fields are rejected, so a client is never told an update of a
+/// setting the service does not support succeeded.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateSettings {
     pub language: Option<String>,
     pub currency: Option<String>,
@@ -49,6 +541,428 @@ pub struct UpdateSettings {
     pub alarm_time: Option<NaiveTime>,
     pub alarm_offset_minutes: Option<i32>,
     pub night_mode: Option<bool>,
+    pub theme: Option<String>,
+    pub timezone: Option<String>,
+    pub date_format: Option<String>,
+    pub number_format: Option<String>,
+    pub week_starts_on: Option<String>,
+    pub budget_period: Option<String>,
+    pub analytics_enabled: Option<bool>,
+    pub crash_reports_enabled: Option<bool>,
+    pub summary_email_frequency: Option<String>,
+    pub summary_email_day: Option<i32>,
+    pub currency_symbol_position: Option<String>,
+    pub thousands_separator: Option<String>,
+    #[serde(default, deserialize_with = "shared_types::de::nullable")]
+    pub decimal_places_override: Option<Option<i32>>,
+}
+
+/// Values of the settings row created on a user's first request
+///
+/// # Fields
+///
+/// * `language` - Default language preference
+/// * `currency` - Default currency preference
+/// * `alarm_set` - Whether alarm notifications start enabled
+/// * `alarm_time` - Default alarm time
+/// * `alarm_offset_minutes` - Default alarm offset in minutes
+/// * `theme` - Default color theme, `night_mode` is derived from it
+/// * `timezone` - Default IANA timezone
+/// * `date_format` - Default date format
+/// * `number_format` - Default number format
+/// * `week_starts_on` - Default first day of the week
+/// * `budget_period` - Default budget period
+/// * `analytics_enabled` - Whether usage analytics start enabled
+/// * `crash_reports_enabled` - Whether crash reports start enabled
+/// * `summary_email_frequency` - Default summary email frequency
+/// * `summary_email_day` - Default summary email day, valid for every frequency
+///
+/// The currency symbol position and thousands separator are derived from
+/// the currency, and amounts use the currency's decimal places.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DefaultSettings {
+    pub language: Language,
+    pub currency: Currency,
+    pub alarm_set: bool,
+    pub alarm_time: NaiveTime,
+    pub alarm_offset_minutes: i32,
+    pub theme: Theme,
+    pub timezone: Tz,
+    pub date_format: DateFormat,
+    pub number_format: NumberFormat,
+    pub week_starts_on: WeekStart,
+    pub budget_period: BudgetPeriod,
+    pub analytics_enabled: bool,
+    pub crash_reports_enabled: bool,
+    pub summary_email_frequency: SummaryEmailFrequency,
+    pub summary_email_day: i32,
+}
+
+impl DefaultSettings {
+    /// Built-in defaults, used for any value the configuration does not override
+    pub const DEFAULT: Self = Self {
+        language: Language::En,
+        currency: Currency::Usd,
+        alarm_set: false,
+        alarm_time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+        alarm_offset_minutes: 0,
+        theme: Theme::Light,
+        timezone: Tz::UTC,
+        date_format: DateFormat::Iso,
+        number_format: NumberFormat::DotDecimal,
+        week_starts_on: WeekStart::Monday,
+        budget_period: BudgetPeriod::Monthly,
+        analytics_enabled: true,
+        crash_reports_enabled: false,
+        summary_email_frequency: SummaryEmailFrequency::Off,
+        summary_email_day: 1,
+    };
+
+    /// Returns the defaults with the language preferred by the client
+    ///
+    /// Used when a user's settings row is created, so new users start in the
+    /// language their browser advertises. The configured language is kept if
+    /// the header is missing or names no supported language.
+    ///
+    /// # Arguments
+    ///
+    /// * `accept_language` - The optional `Accept-Language` header value
+    pub fn with_accept_language(self, accept_language: Option<&str>) -> Self {
+        match accept_language.and_then(Language::from_accept_language) {
+            Some(language) => Self { language, ..self },
+            None => self,
+        }
+    }
+
+    /// Returns the language code as stored in the database
+    pub fn language_code(&self) -> &'static str {
+        self.language.as_str()
+    }
+
+    /// Returns the currency code as stored in the database
+    pub fn currency_code(&self) -> String {
+        self.currency.as_str().to_lowercase()
+    }
+
+    /// Returns the timezone name as stored in the database
+    pub fn timezone_name(&self) -> &'static str {
+        self.timezone.name()
+    }
+
+    /// Returns the default currency symbol position of the currency
+    pub fn currency_symbol_position(&self) -> CurrencySymbolPosition {
+        self.currency.default_symbol_position()
+    }
+
+    /// Returns the default thousands separator of the currency
+    pub fn thousands_separator(&self) -> ThousandsSeparator {
+        self.currency.default_thousands_separator()
+    }
+
+    /// Returns an update setting every field to its default value
+    ///
+    /// Used to reset the settings of a user in place.
+    pub fn as_update(&self) -> UpdateSettings {
+        UpdateSettings {
+            language: Some(self.language_code().to_string()),
+            currency: Some(self.currency_code()),
+            alarm_set: Some(self.alarm_set),
+            alarm_time: Some(self.alarm_time),
+            alarm_offset_minutes: Some(self.alarm_offset_minutes),
+            night_mode: Some(self.theme.night_mode()),
+            theme: Some(self.theme.as_str().to_string()),
+            timezone: Some(self.timezone_name().to_string()),
+            date_format: Some(self.date_format.as_str().to_string()),
+            number_format: Some(self.number_format.as_str().to_string()),
+            week_starts_on: Some(self.week_starts_on.as_str().to_string()),
+            budget_period: Some(self.budget_period.as_str().to_string()),
+            analytics_enabled: Some(self.analytics_enabled),
+            crash_reports_enabled: Some(self.crash_reports_enabled),
+            summary_email_frequency: Some(self.summary_email_frequency.as_str().to_string()),
+            summary_email_day: Some(self.summary_email_day),
+            currency_symbol_position: Some(self.currency_symbol_position().as_str().to_string()),
+            thousands_separator: Some(self.thousands_separator().as_str().to_string()),
+            decimal_places_override: Some(None),
+        }
+    }
+}
+
+/// Checks an `If-Match` header against the entity tag of the stored settings
+///
+/// Weak tags are compared by their value and `*` matches any settings.
+///
+/// # Arguments
+///
+/// * `if_match` - The `If-Match` header value, a comma separated list of tags
+/// * `etag` - The entity tag of the stored settings
+///
+/// # Returns
+///
+/// `true` if one of the tags matches, so the update may proceed
+pub fn if_match_satisfied(if_match: &str, etag: &str) -> bool {
+    if_match
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+}
+
+/// Validates a theme and keeps it consistent with the deprecated `night_mode`
+///
+/// A theme sets `night_mode` to whether it is dark. Without a theme,
+/// `night_mode` selects the dark or light theme. If both are given the
+/// theme wins.
+///
+/// # Arguments
+///
+/// * `theme` - The requested theme, normalized to its enum value
+/// * `night_mode` - The requested night mode, set to match the theme
+///
+/// # Returns
+///
+/// * `Ok(())` - Once both values are consistent
+/// * `Err(InvalidSetting)` - `InvalidTheme` for an unsupported theme
+pub fn resolve_theme(
+    theme: &mut Option<String>,
+    night_mode: &mut Option<bool>,
+) -> Result<(), InvalidSetting> {
+    let resolved = match theme.as_deref() {
+        Some(theme) => Some(
+            Theme::from_str(theme)
+                .map_err(|_| InvalidSetting::new("theme", TranslationKey::InvalidTheme))?,
+        ),
+        None => night_mode.map(Theme::from_night_mode),
+    };
+
+    if let Some(resolved) = resolved {
+        *theme = Some(resolved.as_str().to_string());
+        *night_mode = Some(resolved.night_mode());
+    }
+
+    Ok(())
+}
+
+/// Last day of the month summary emails can be sent on, so every month has it
+pub const MAX_SUMMARY_EMAIL_MONTH_DAY: i32 = 28;
+
+/// Validates the day summary emails are sent on against their frequency
+///
+/// Weekly summaries take a day of the week, 1 (Monday) to 7 (Sunday). Monthly
+/// summaries take a day of the month, 1 to 28. While summaries are off the day
+/// is kept for when they are turned back on, so any day of the month is accepted.
+///
+/// # Arguments
+///
+/// * `frequency` - How often summary emails are sent
+/// * `day` - The day summary emails are sent on
+///
+/// # Returns
+///
+/// * `Ok(())` - The day is valid for the frequency
+/// * `Err(InvalidSetting)` - `InvalidSummaryEmailDay` for the `summary_email_day` field
+pub fn validate_summary_email(
+    frequency: SummaryEmailFrequency,
+    day: i32,
+) -> Result<(), InvalidSetting> {
+    let last_day = match frequency {
+        SummaryEmailFrequency::Weekly => 7,
+        SummaryEmailFrequency::Monthly | SummaryEmailFrequency::Off => MAX_SUMMARY_EMAIL_MONTH_DAY,
+    };
+
+    if !(1..=last_day).contains(&day) {
+        return Err(InvalidSetting::new(
+            "summary_email_day",
+            TranslationKey::InvalidSummaryEmailDay,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates that thousands are not separated by the decimal separator
+///
+/// # Arguments
+///
+/// * `number_format` - The number format, selecting the decimal separator
+/// * `thousands_separator` - What separates groups of thousands
+///
+/// # Returns
+///
+/// * `Ok(())` - Amounts can be read unambiguously
+/// * `Err(InvalidSetting)` - `InvalidThousandsSeparator` for the `thousands_separator` field
+pub fn validate_separators(
+    number_format: NumberFormat,
+    thousands_separator: ThousandsSeparator,
+) -> Result<(), InvalidSetting> {
+    if thousands_separator.as_char() == Some(number_format.decimal_separator()) {
+        return Err(InvalidSetting::new(
+            "thousands_separator",
+            TranslationKey::InvalidThousandsSeparator,
+        ));
+    }
+
+    Ok(())
+}
+
+/// A settings field with an unsupported value
+///
+/// # Fields
+///
+/// * `field` - Name of the invalid field
+/// * `translation_key` - The translation key describing the error
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidSetting {
+    pub field: &'static str,
+    pub translation_key: TranslationKey,
+}
+
+impl InvalidSetting {
+    /// Creates a new invalid setting error
+    pub fn new(field: &'static str, translation_key: TranslationKey) -> Self {
+        Self {
+            field,
+            translation_key,
+        }
+    }
+}
+
+/// Request body of the settings reset
+///
+/// # Fields
+///
+/// * `confirm` - Must be `true`, guards against accidental resets
+#[derive(Deserialize)]
+pub struct ResetSettings {
+    pub confirm: bool,
+}
+
+impl UpdateSettings {
+    /// Validates the language, currency, theme, timezone, formats, week start, budget period,
+    /// summary email and amount display settings against the supported values
+    ///
+    /// Valid codes are normalized to the lowercase form stored in the database,
+    /// timezones to their canonical IANA name and formats to their enum value.
+    /// The theme and `night_mode` are made consistent, see `resolve_theme`.
+    /// A summary email day sent with its frequency is checked against it, one
+    /// sent alone only against the days of a month; `Settings::validate_update`
+    /// checks it against the stored frequency. The thousands separator is
+    /// checked against a number format sent with it the same way.
+    ///
+    /// # Returns
+    /// * `Ok(UpdateSettings)` - The update with normalized values
+    /// * `Err(InvalidSetting)` - The first unsupported field with `InvalidLanguage`,
+    ///   `InvalidCurrency`, `InvalidTheme`, `InvalidTimezone`, `InvalidDateFormat`,
+    ///   `InvalidNumberFormat`, `InvalidWeekStart`, `InvalidBudgetPeriod`,
+    ///   `InvalidSummaryEmailFrequency`, `InvalidSummaryEmailDay`,
+    ///   `InvalidCurrencySymbolPosition`, `InvalidThousandsSeparator` or `InvalidDecimalPlaces`
+    pub fn normalized(mut self) -> Result<Self, InvalidSetting> {
+        if let Some(language) = &self.language {
+            let language = Language::from_str(language)
+                .map_err(|_| InvalidSetting::new("language", TranslationKey::InvalidLanguage))?;
+            self.language = Some(language.as_str().to_string());
+        }
+
+        if let Some(currency) = &self.currency {
+            let currency = Currency::from_str(currency)
+                .map_err(|_| InvalidSetting::new("currency", TranslationKey::InvalidCurrency))?;
+            self.currency = Some(currency.as_str().to_lowercase());
+        }
+
+        resolve_theme(&mut self.theme, &mut self.night_mode)?;
+
+        if let Some(timezone) = &self.timezone {
+            let timezone = Tz::from_str(timezone)
+                .map_err(|_| InvalidSetting::new("timezone", TranslationKey::InvalidTimezone))?;
+            self.timezone = Some(timezone.name().to_string());
+        }
+
+        if let Some(date_format) = &self.date_format {
+            let date_format = DateFormat::from_str(date_format).map_err(|_| {
+                InvalidSetting::new("date_format", TranslationKey::InvalidDateFormat)
+            })?;
+            self.date_format = Some(date_format.as_str().to_string());
+        }
+
+        if let Some(number_format) = &self.number_format {
+            let number_format = NumberFormat::from_str(number_format).map_err(|_| {
+                InvalidSetting::new("number_format", TranslationKey::InvalidNumberFormat)
+            })?;
+            self.number_format = Some(number_format.as_str().to_string());
+        }
+
+        if let Some(week_starts_on) = &self.week_starts_on {
+            let week_starts_on = WeekStart::from_str(week_starts_on).map_err(|_| {
+                InvalidSetting::new("week_starts_on", TranslationKey::InvalidWeekStart)
+            })?;
+            self.week_starts_on = Some(week_starts_on.as_str().to_string());
+        }
+
+        if let Some(budget_period) = &self.budget_period {
+            let budget_period = BudgetPeriod::from_str(budget_period).map_err(|_| {
+                InvalidSetting::new("budget_period", TranslationKey::InvalidBudgetPeriod)
+            })?;
+            self.budget_period = Some(budget_period.as_str().to_string());
+        }
+
+        let frequency = match &self.summary_email_frequency {
+            Some(frequency) => {
+                let frequency = SummaryEmailFrequency::from_str(frequency).map_err(|_| {
+                    InvalidSetting::new(
+                        "summary_email_frequency",
+                        TranslationKey::InvalidSummaryEmailFrequency,
+                    )
+                })?;
+                self.summary_email_frequency = Some(frequency.as_str().to_string());
+                Some(frequency)
+            }
+            None => None,
+        };
+
+        if let Some(day) = self.summary_email_day {
+            validate_summary_email(frequency.unwrap_or(SummaryEmailFrequency::Monthly), day)?;
+        }
+
+        if let Some(position) = &self.currency_symbol_position {
+            let position = CurrencySymbolPosition::from_str(position).map_err(|_| {
+                InvalidSetting::new(
+                    "currency_symbol_position",
+                    TranslationKey::InvalidCurrencySymbolPosition,
+                )
+            })?;
+            self.currency_symbol_position = Some(position.as_str().to_string());
+        }
+
+        if let Some(separator) = &self.thousands_separator {
+            let separator = ThousandsSeparator::from_str(separator).map_err(|_| {
+                InvalidSetting::new(
+                    "thousands_separator",
+                    TranslationKey::InvalidThousandsSeparator,
+                )
+            })?;
+            if let Some(number_format) = &self.number_format {
+                let number_format = NumberFormat::from_str(number_format).map_err(|_| {
+                    InvalidSetting::new("number_format", TranslationKey::InvalidNumberFormat)
+                })?;
+                validate_separators(number_format, separator)?;
+            }
+            self.thousands_separator = Some(separator.as_str().to_string());
+        }
+
+        if let Some(Some(places)) = self.decimal_places_override
+            && !(0..=MAX_DISPLAY_DECIMAL_PLACES as i32).contains(&places)
+        {
+            return Err(InvalidSetting::new(
+                "decimal_places_override",
+                TranslationKey::InvalidDecimalPlaces,
+            ));
+        }
+
+        Ok(self)
+    }
+
+    /// Returns whether the update writes any of the legacy alarm fields
+    pub fn touches_legacy_alarm(&self) -> bool {
+        self.alarm_set.is_some() || self.alarm_time.is_some() || self.alarm_offset_minutes.is_some()
+    }
 }
 
 #[cfg(test)]
@@ -69,6 +983,21 @@ mod tests {
             alarm_time,
             alarm_offset_minutes: 15,
             night_mode: false,
+            theme: "light".to_string(),
+            timezone: "UTC".to_string(),
+            date_format: "ISO".to_string(),
+            number_format: "dot-decimal".to_string(),
+            week_starts_on: "Monday".to_string(),
+            budget_period: "Monthly".to_string(),
+            analytics_enabled: true,
+            crash_reports_enabled: false,
+            summary_email_frequency: "Off".to_string(),
+            summary_email_day: 1,
+            currency_symbol_position: "Prefix".to_string(),
+            thousands_separator: "Comma".to_string(),
+            decimal_places_override: None,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            updated_at: DateTime::default(),
         };
 
         let serialized = serde_json::to_string(&settings).unwrap();
@@ -124,6 +1053,678 @@ mod tests {
         assert_eq!(update.alarm_time, None);
         assert_eq!(update.alarm_offset_minutes, None);
         assert_eq!(update.night_mode, None);
+        assert_eq!(update.theme, None);
+        assert_eq!(update.timezone, None);
+        assert_eq!(update.date_format, None);
+        assert_eq!(update.number_format, None);
+        assert_eq!(update.week_starts_on, None);
+        assert_eq!(update.budget_period, None);
+    }
+
+    #[test]
+    fn test_update_settings_rejects_unknown_fields() {
+        let json = r#"{ "language": "en", "font_size": 12 }"#;
+
+        let err = serde_json::from_str::<UpdateSettings>(json).err().unwrap();
+        assert!(err.to_string().contains("unknown field `font_size`"));
+    }
+
+    #[test]
+    fn test_update_settings_normalized() {
+        let json = r#"{ "language": "RO", "currency": "EUR" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.language, Some("ro".to_string()));
+        assert_eq!(update.currency, Some("eur".to_string()));
+    }
+
+    #[test]
+    fn test_update_settings_invalid_language() {
+        let json = r#"{ "language": "klingon" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "language",
+                translation_key: TranslationKey::InvalidLanguage
+            })
+        ));
+    }
+
+    #[test]
+    fn test_update_settings_valid_timezone() {
+        let json = r#"{ "timezone": "America/New_York" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.timezone, Some("America/New_York".to_string()));
+    }
+
+    #[test]
+    fn test_update_settings_invalid_timezone() {
+        let json = r#"{ "timezone": "Mars/Olympus_Mons" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "timezone",
+                translation_key: TranslationKey::InvalidTimezone
+            })
+        ));
+    }
+
+    #[test]
+    fn test_update_settings_formats_normalized() {
+        let json = r#"{ "date_format": "dmy", "number_format": "Comma-Decimal" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.date_format, Some("DMY".to_string()));
+        assert_eq!(update.number_format, Some("comma-decimal".to_string()));
+    }
+
+    #[test]
+    fn test_update_settings_invalid_formats() {
+        let update: UpdateSettings = serde_json::from_str(r#"{ "date_format": "YMD" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "date_format",
+                translation_key: TranslationKey::InvalidDateFormat
+            })
+        ));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "number_format": "space-decimal" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "number_format",
+                translation_key: TranslationKey::InvalidNumberFormat
+            })
+        ));
+    }
+
+    #[test]
+    fn test_update_settings_week_starts_on() {
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "week_starts_on": "sunday" }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.week_starts_on, Some("Sunday".to_string()));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "week_starts_on": "Friday" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "week_starts_on",
+                translation_key: TranslationKey::InvalidWeekStart
+            })
+        ));
+    }
+
+    #[test]
+    fn test_update_settings_budget_period() {
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "budget_period": "weekly" }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.budget_period, Some("Weekly".to_string()));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "budget_period": "Yearly" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "budget_period",
+                translation_key: TranslationKey::InvalidBudgetPeriod
+            })
+        ));
+    }
+
+    #[test]
+    fn test_validate_summary_email_valid_combinations() {
+        for day in 1..=28 {
+            assert_eq!(
+                validate_summary_email(SummaryEmailFrequency::Monthly, day),
+                Ok(())
+            );
+            assert_eq!(
+                validate_summary_email(SummaryEmailFrequency::Off, day),
+                Ok(())
+            );
+        }
+        for day in 1..=7 {
+            assert_eq!(
+                validate_summary_email(SummaryEmailFrequency::Weekly, day),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_summary_email_invalid_combinations() {
+        let invalid = Err(InvalidSetting::new(
+            "summary_email_day",
+            TranslationKey::InvalidSummaryEmailDay,
+        ));
+
+        // Days of the month past the end of a week are only valid for Monthly
+        assert_eq!(
+            validate_summary_email(SummaryEmailFrequency::Weekly, 8),
+            invalid
+        );
+        assert_eq!(
+            validate_summary_email(SummaryEmailFrequency::Weekly, 28),
+            invalid
+        );
+        for frequency in SummaryEmailFrequency::all() {
+            assert_eq!(validate_summary_email(*frequency, 0), invalid);
+            assert_eq!(validate_summary_email(*frequency, 29), invalid);
+        }
+    }
+
+    #[test]
+    fn test_update_settings_summary_email() {
+        let update: UpdateSettings = serde_json::from_str(
+            r#"{ "summary_email_frequency": "monthly", "summary_email_day": 15 }"#,
+        )
+        .unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.summary_email_frequency, Some("Monthly".to_string()));
+        assert_eq!(update.summary_email_day, Some(15));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "summary_email_frequency": "Daily" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "summary_email_frequency",
+                translation_key: TranslationKey::InvalidSummaryEmailFrequency
+            })
+        ));
+
+        let update: UpdateSettings = serde_json::from_str(
+            r#"{ "summary_email_frequency": "Weekly", "summary_email_day": 15 }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "summary_email_day",
+                translation_key: TranslationKey::InvalidSummaryEmailDay
+            })
+        ));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "summary_email_day": 31 }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "summary_email_day",
+                translation_key: TranslationKey::InvalidSummaryEmailDay
+            })
+        ));
+    }
+
+    #[test]
+    fn test_settings_validate_update_uses_stored_summary_email() {
+        let mut monthly = settings();
+        monthly.summary_email_frequency = "Monthly".to_string();
+        monthly.summary_email_day = 15;
+
+        // Switching to Weekly must also move the day into the week
+        let update = UpdateSettings {
+            summary_email_frequency: Some("Weekly".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            monthly.validate_update(&update),
+            Err(InvalidSetting::new(
+                "summary_email_day",
+                TranslationKey::InvalidSummaryEmailDay
+            ))
+        );
+
+        let update = UpdateSettings {
+            summary_email_frequency: Some("Weekly".to_string()),
+            summary_email_day: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(monthly.validate_update(&update), Ok(()));
+
+        // Turning summaries off keeps the day of the month
+        let update = UpdateSettings {
+            summary_email_frequency: Some("Off".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(monthly.validate_update(&update), Ok(()));
+
+        let mut weekly = settings();
+        weekly.summary_email_frequency = "Weekly".to_string();
+        weekly.summary_email_day = 5;
+
+        let update = UpdateSettings {
+            summary_email_day: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            weekly.validate_update(&update),
+            Err(InvalidSetting::new(
+                "summary_email_day",
+                TranslationKey::InvalidSummaryEmailDay
+            ))
+        );
+        assert_eq!(weekly.validate_update(&UpdateSettings::default()), Ok(()));
+    }
+
+    #[test]
+    fn test_update_settings_theme() {
+        let update: UpdateSettings = serde_json::from_str(r#"{ "theme": "Dark" }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.theme, Some("dark".to_string()));
+        assert_eq!(update.night_mode, Some(true));
+
+        // The theme wins over a conflicting night mode
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "theme": "system", "night_mode": true }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.theme, Some("system".to_string()));
+        assert_eq!(update.night_mode, Some(false));
+
+        let update: UpdateSettings = serde_json::from_str(r#"{ "theme": "sepia" }"#).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "theme",
+                translation_key: TranslationKey::InvalidTheme
+            })
+        ));
+    }
+
+    #[test]
+    fn test_update_settings_night_mode_selects_theme() {
+        let update: UpdateSettings = serde_json::from_str(r#"{ "night_mode": true }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.theme, Some("dark".to_string()));
+
+        let update: UpdateSettings = serde_json::from_str(r#"{ "night_mode": false }"#).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.theme, Some("light".to_string()));
+
+        let update = UpdateSettings::default().normalized().unwrap();
+        assert_eq!(update.theme, None);
+        assert_eq!(update.night_mode, None);
+    }
+
+    #[test]
+    fn test_theme_round_trips_through_settings() {
+        let update: UpdateSettings = serde_json::from_str(r#"{ "theme": "dark" }"#).unwrap();
+        let update = update.normalized().unwrap();
+
+        let mut settings = settings();
+        let changes = settings.changes(&update);
+        assert_eq!(
+            changes["theme"],
+            serde_json::json!({ "old": "light", "new": "dark" })
+        );
+
+        settings.theme = update.theme.unwrap();
+        settings.night_mode = update.night_mode.unwrap();
+        let json = serde_json::to_value(&settings).unwrap();
+        assert_eq!(json["theme"], "dark");
+        assert_eq!(json["night_mode"], true);
+    }
+
+    #[test]
+    fn test_update_settings_invalid_currency() {
+        let json = r#"{ "currency": "BTC" }"#;
+
+        let update: UpdateSettings = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            update.normalized(),
+            Err(InvalidSetting {
+                field: "currency",
+                translation_key: TranslationKey::InvalidCurrency
+            })
+        ));
+    }
+
+    #[test]
+    fn test_default_settings_codes() {
+        let defaults = DefaultSettings::DEFAULT;
+        assert_eq!(defaults.language_code(), "en");
+        assert_eq!(defaults.currency_code(), "usd");
+        assert!(!defaults.alarm_set);
+        assert_eq!(defaults.theme, Theme::Light);
+        assert_eq!(defaults.timezone_name(), "UTC");
+        assert_eq!(defaults.date_format, DateFormat::Iso);
+        assert_eq!(defaults.number_format, NumberFormat::DotDecimal);
+        assert_eq!(defaults.week_starts_on, WeekStart::Monday);
+        assert_eq!(defaults.budget_period, BudgetPeriod::Monthly);
+    }
+
+    #[test]
+    fn test_default_settings_with_accept_language() {
+        let defaults = DefaultSettings::DEFAULT;
+
+        assert_eq!(
+            defaults
+                .with_accept_language(Some("ro-RO,ro;q=0.9,en;q=0.8"))
+                .language,
+            Language::Ro
+        );
+        assert_eq!(
+            defaults.with_accept_language(Some("ja, zh-CN")).language,
+            Language::En
+        );
+        assert_eq!(defaults.with_accept_language(None), defaults);
+    }
+
+    #[test]
+    fn test_default_settings_as_update() {
+        let defaults = DefaultSettings {
+            currency: Currency::Eur,
+            ..DefaultSettings::DEFAULT
+        };
+        let update = defaults.as_update();
+
+        assert_eq!(update.language, Some("en".to_string()));
+        assert_eq!(update.currency, Some("eur".to_string()));
+        assert_eq!(update.alarm_set, Some(false));
+        assert_eq!(update.alarm_time, NaiveTime::from_hms_opt(7, 0, 0));
+        assert_eq!(update.alarm_offset_minutes, Some(0));
+        assert_eq!(update.night_mode, Some(false));
+        assert_eq!(update.theme, Some("light".to_string()));
+        assert_eq!(update.timezone, Some("UTC".to_string()));
+        assert_eq!(update.date_format, Some("ISO".to_string()));
+        assert_eq!(update.number_format, Some("dot-decimal".to_string()));
+        assert_eq!(update.week_starts_on, Some("Monday".to_string()));
+        assert_eq!(update.budget_period, Some("Monthly".to_string()));
+        assert_eq!(update.analytics_enabled, Some(true));
+        assert_eq!(update.crash_reports_enabled, Some(false));
+        assert_eq!(update.currency_symbol_position, Some("Suffix".to_string()));
+        assert_eq!(update.thousands_separator, Some("Space".to_string()));
+        assert_eq!(update.decimal_places_override, Some(None));
+    }
+
+    #[test]
+    fn test_update_settings_amount_display() {
+        let update: UpdateSettings = serde_json::from_str(
+            r#"{ "currency_symbol_position": "suffix", "thousands_separator": "dot", "decimal_places_override": 3 }"#,
+        )
+        .unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.currency_symbol_position, Some("Suffix".to_string()));
+        assert_eq!(update.thousands_separator, Some("Dot".to_string()));
+        assert_eq!(update.decimal_places_override, Some(Some(3)));
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "decimal_places_override": null }"#).unwrap();
+        assert_eq!(update.decimal_places_override, Some(None));
+        assert!(update.normalized().is_ok());
+
+        let update: UpdateSettings = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(update.decimal_places_override, None);
+    }
+
+    #[test]
+    fn test_update_settings_invalid_amount_display() {
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "currency_symbol_position": "Infix" }"#).unwrap();
+        assert_eq!(
+            update.normalized().err(),
+            Some(InvalidSetting::new(
+                "currency_symbol_position",
+                TranslationKey::InvalidCurrencySymbolPosition
+            ))
+        );
+
+        let update: UpdateSettings =
+            serde_json::from_str(r#"{ "thousands_separator": "Apostrophe" }"#).unwrap();
+        assert_eq!(
+            update.normalized().err(),
+            Some(InvalidSetting::new(
+                "thousands_separator",
+                TranslationKey::InvalidThousandsSeparator
+            ))
+        );
+
+        let update: UpdateSettings = serde_json::from_str(
+            r#"{ "number_format": "comma-decimal", "thousands_separator": "Comma" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            update.normalized().err(),
+            Some(InvalidSetting::new(
+                "thousands_separator",
+                TranslationKey::InvalidThousandsSeparator
+            ))
+        );
+
+        for places in [-1, 5] {
+            let update = UpdateSettings {
+                decimal_places_override: Some(Some(places)),
+                ..Default::default()
+            };
+            assert_eq!(
+                update.normalized().err(),
+                Some(InvalidSetting::new(
+                    "decimal_places_override",
+                    TranslationKey::InvalidDecimalPlaces
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_settings_validate_update_uses_stored_separators() {
+        // Stored thousands separator is a comma
+        let update = UpdateSettings {
+            number_format: Some("comma-decimal".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings().validate_update(&update),
+            Err(InvalidSetting::new(
+                "thousands_separator",
+                TranslationKey::InvalidThousandsSeparator
+            ))
+        );
+
+        let update = UpdateSettings {
+            number_format: Some("comma-decimal".to_string()),
+            thousands_separator: Some("Dot".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(settings().validate_update(&update), Ok(()));
+    }
+
+    #[test]
+    fn test_with_currency_defaults_follows_currency_change() {
+        let update = UpdateSettings {
+            currency: Some("eur".to_string()),
+            ..Default::default()
+        };
+        let update = settings().with_currency_defaults(update);
+        assert_eq!(update.currency_symbol_position, Some("Suffix".to_string()));
+        assert_eq!(update.thousands_separator, Some("Space".to_string()));
+
+        // Values the user picked, or sets in the same update, are kept
+        let mut customized = settings();
+        customized.thousands_separator = "None".to_string();
+        let update = UpdateSettings {
+            currency: Some("eur".to_string()),
+            currency_symbol_position: Some("Prefix".to_string()),
+            ..Default::default()
+        };
+        let update = customized.with_currency_defaults(update);
+        assert_eq!(update.currency_symbol_position, Some("Prefix".to_string()));
+        assert_eq!(update.thousands_separator, None);
+
+        // Without a currency change nothing is derived
+        let update = settings().with_currency_defaults(UpdateSettings {
+            currency: Some("usd".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(update.currency_symbol_position, None);
+        assert_eq!(update.thousands_separator, None);
+    }
+
+    #[test]
+    fn test_settings_display_prefs() {
+        let mut settings = settings();
+        settings.number_format = "comma-decimal".to_string();
+        settings.thousands_separator = "Dot".to_string();
+        settings.decimal_places_override = Some(0);
+
+        assert_eq!(
+            settings.display_prefs(),
+            DisplayPrefs {
+                symbol_position: CurrencySymbolPosition::Prefix,
+                thousands_separator: ThousandsSeparator::Dot,
+                number_format: NumberFormat::CommaDecimal,
+                decimal_places: Some(0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_settings_changes_records_decimal_places_override() {
+        let update = UpdateSettings {
+            decimal_places_override: Some(Some(3)),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings().changes(&update)["decimal_places_override"],
+            serde_json::json!({ "old": null, "new": 3 })
+        );
+
+        let mut settings = settings();
+        settings.decimal_places_override = Some(3);
+        let update = UpdateSettings {
+            decimal_places_override: Some(None),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.changes(&update)["decimal_places_override"],
+            serde_json::json!({ "old": 3, "new": null })
+        );
+    }
+
+    #[test]
+    fn test_reset_settings_deserialization() {
+        let reset: ResetSettings = serde_json::from_str(r#"{ "confirm": true }"#).unwrap();
+        assert!(reset.confirm);
+
+        assert!(serde_json::from_str::<ResetSettings>(r#"{}"#).is_err());
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            user_id: Uuid::new_v4(),
+            language: "en".to_string(),
+            currency: "usd".to_string(),
+            alarm_set: false,
+            alarm_time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            alarm_offset_minutes: 0,
+            night_mode: false,
+            theme: "light".to_string(),
+            timezone: "UTC".to_string(),
+            date_format: "ISO".to_string(),
+            number_format: "dot-decimal".to_string(),
+            week_starts_on: "Monday".to_string(),
+            budget_period: "Monthly".to_string(),
+            analytics_enabled: true,
+            crash_reports_enabled: false,
+            summary_email_frequency: "Off".to_string(),
+            summary_email_day: 1,
+            currency_symbol_position: "Prefix".to_string(),
+            thousands_separator: "Comma".to_string(),
+            decimal_places_override: None,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            updated_at: DateTime::default(),
+        }
+    }
+
+    #[test]
+    fn test_settings_changes_records_old_and_new_values() {
+        let settings = settings();
+        let update = UpdateSettings {
+            currency: Some("eur".to_string()),
+            alarm_time: Some(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
+            ..Default::default()
+        };
+
+        let changes = settings.changes(&update);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes["currency"],
+            serde_json::json!({ "old": "usd", "new": "eur" })
+        );
+        assert_eq!(
+            changes["alarm_time"],
+            serde_json::json!({ "old": "07:00:00", "new": "08:30:00" })
+        );
+    }
+
+    #[test]
+    fn test_privacy_flags_round_trip() {
+        let update: UpdateSettings = serde_json::from_str(
+            r#"{ "analytics_enabled": false, "crash_reports_enabled": true }"#,
+        )
+        .unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.analytics_enabled, Some(false));
+        assert_eq!(update.crash_reports_enabled, Some(true));
+
+        let changes = settings().changes(&update);
+        assert_eq!(
+            changes["analytics_enabled"],
+            serde_json::json!({ "old": true, "new": false })
+        );
+        assert_eq!(
+            changes["crash_reports_enabled"],
+            serde_json::json!({ "old": false, "new": true })
+        );
+
+        let serialized = serde_json::to_value(settings()).unwrap();
+        assert_eq!(serialized["analytics_enabled"], true);
+        assert_eq!(serialized["crash_reports_enabled"], false);
+    }
+
+    #[test]
+    fn test_settings_changes_of_two_updates() {
+        let settings = settings();
+
+        let first = settings.changes(&UpdateSettings {
+            night_mode: Some(true),
+            ..Default::default()
+        });
+        let second = settings.changes(&UpdateSettings {
+            language: Some("es".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(first.keys().collect::<Vec<_>>(), vec!["night_mode"]);
+        assert_eq!(second.keys().collect::<Vec<_>>(), vec!["language"]);
+    }
+
+    #[test]
+    fn test_settings_changes_ignores_unchanged_fields() {
+        let settings = settings();
+        let update = UpdateSettings {
+            language: Some("en".to_string()),
+            currency: Some("usd".to_string()),
+            night_mode: Some(false),
+            ..Default::default()
+        };
+
+        assert!(settings.changes(&update).is_empty());
+        assert!(settings.changes(&UpdateSettings::default()).is_empty());
     }
 
     #[test]
@@ -139,6 +1740,21 @@ mod tests {
             alarm_time,
             alarm_offset_minutes: 0,
             night_mode: true,
+            theme: "dark".to_string(),
+            timezone: "Europe/Berlin".to_string(),
+            date_format: "DMY".to_string(),
+            number_format: "comma-decimal".to_string(),
+            week_starts_on: "Sunday".to_string(),
+            budget_period: "Weekly".to_string(),
+            analytics_enabled: false,
+            crash_reports_enabled: true,
+            summary_email_frequency: "Off".to_string(),
+            summary_email_day: 1,
+            currency_symbol_position: "Prefix".to_string(),
+            thousands_separator: "Comma".to_string(),
+            decimal_places_override: None,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            updated_at: DateTime::default(),
         };
 
         let cloned = settings.clone();
@@ -148,4 +1764,161 @@ mod tests {
         let serialized_cloned = serde_json::to_string(&cloned).unwrap();
         assert_eq!(serialized_original, serialized_cloned);
     }
+
+    fn legacy_settings() -> Settings {
+        Settings {
+            user_id: Uuid::new_v4(),
+            language: "en".to_string(),
+            currency: "usd".to_string(),
+            alarm_set: false,
+            alarm_time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            alarm_offset_minutes: 0,
+            night_mode: false,
+            theme: "light".to_string(),
+            timezone: "UTC".to_string(),
+            date_format: "ISO".to_string(),
+            number_format: "dot-decimal".to_string(),
+            week_starts_on: "Monday".to_string(),
+            budget_period: "Monthly".to_string(),
+            analytics_enabled: true,
+            crash_reports_enabled: false,
+            summary_email_frequency: "Off".to_string(),
+            summary_email_day: 1,
+            currency_symbol_position: "Prefix".to_string(),
+            thousands_separator: "Comma".to_string(),
+            decimal_places_override: None,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            updated_at: DateTime::default(),
+        }
+    }
+
+    #[test]
+    fn test_with_legacy_alarm_uses_first_alarm() {
+        let alarm = Alarm {
+            id: Uuid::new_v4(),
+            label: "Morning".to_string(),
+            time: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            offset_minutes: 15,
+            enabled: true,
+            days_of_week: 0b001_1111,
+            created_at: chrono::Utc::now(),
+        };
+
+        let settings = legacy_settings().with_legacy_alarm(Some(&alarm));
+
+        assert!(settings.alarm_set());
+        assert_eq!(settings.alarm_time(), alarm.time);
+        assert_eq!(settings.alarm_offset_minutes(), 15);
+    }
+
+    #[test]
+    fn test_with_legacy_alarm_without_alarms_keeps_columns() {
+        let settings = legacy_settings().with_legacy_alarm(None);
+
+        assert!(!settings.alarm_set());
+        assert_eq!(
+            settings.alarm_time(),
+            NaiveTime::from_hms_opt(7, 0, 0).unwrap()
+        );
+        assert_eq!(settings.alarm_offset_minutes(), 0);
+    }
+
+    #[test]
+    fn test_upgraded_row_is_current() {
+        let mut settings = legacy_settings();
+        settings.schema_version = 1;
+
+        let settings = settings.upgraded();
+        assert_eq!(settings.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(settings.timezone(), "UTC");
+        assert_eq!(settings.language(), "en");
+    }
+
+    #[test]
+    fn test_etag_changes_with_updated_at() {
+        let mut settings = legacy_settings();
+        let etag = settings.etag();
+        assert_eq!(etag, "\"0\"");
+
+        settings.updated_at = DateTime::default() + chrono::Duration::microseconds(1);
+        assert_ne!(settings.etag(), etag);
+    }
+
+    #[test]
+    fn test_if_match_detects_lost_update() {
+        let mut settings = legacy_settings();
+        // Both tabs load the settings with the same tag
+        let first_tab = settings.etag();
+        let second_tab = settings.etag();
+
+        assert!(if_match_satisfied(&first_tab, &settings.etag()));
+        settings.updated_at = Utc::now();
+
+        // The second tab's tag no longer matches after the first tab saved
+        assert!(!if_match_satisfied(&second_tab, &settings.etag()));
+    }
+
+    #[test]
+    fn test_if_match_forms() {
+        let etag = "\"1731240000000000\"";
+
+        assert!(if_match_satisfied("*", etag));
+        assert!(if_match_satisfied("W/\"1731240000000000\"", etag));
+        assert!(if_match_satisfied("\"1\", \"1731240000000000\"", etag));
+        assert!(!if_match_satisfied("\"1\"", etag));
+        assert!(!if_match_satisfied("", etag));
+    }
+
+    #[test]
+    fn test_with_device_overrides() {
+        let overrides = DeviceOverrides {
+            night_mode: Some(true),
+            theme: Some("dark".to_string()),
+            language: None,
+        };
+
+        let merged = legacy_settings().with_device_overrides(&overrides);
+        assert_eq!(merged.overridden, vec!["night_mode", "theme"]);
+        assert!(merged.settings.night_mode);
+        assert_eq!(merged.settings.theme, "dark");
+        assert_eq!(merged.settings.language(), "en");
+
+        let json = serde_json::to_value(&merged).unwrap();
+        assert_eq!(json["night_mode"], true);
+        assert_eq!(json["theme"], "dark");
+        assert_eq!(
+            json["overridden"],
+            serde_json::json!(["night_mode", "theme"])
+        );
+    }
+
+    #[test]
+    fn test_with_device_overrides_without_overrides() {
+        let merged = legacy_settings().with_device_overrides(&DeviceOverrides::default());
+        assert!(merged.overridden.is_empty());
+        assert!(!merged.settings.night_mode);
+    }
+
+    #[test]
+    fn test_with_device_overrides_same_value_is_overridden() {
+        // The field is reported even if the device value equals the base value
+        let overrides = DeviceOverrides {
+            language: Some("en".to_string()),
+            ..Default::default()
+        };
+
+        let merged = legacy_settings().with_device_overrides(&overrides);
+        assert_eq!(merged.overridden, vec!["language"]);
+    }
+
+    #[test]
+    fn test_touches_legacy_alarm() {
+        assert!(!UpdateSettings::default().touches_legacy_alarm());
+
+        let update = UpdateSettings {
+            alarm_offset_minutes: Some(5),
+            ..Default::default()
+        };
+        assert!(update.touches_legacy_alarm());
+    }
 }
@@ -0,0 +1,231 @@
+use std::{
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use moka::future::Cache;
+use uuid::Uuid;
+
+use crate::models::{response::Error, settings::Settings};
+
+/// Maximum number of users whose settings are cached
+const SETTINGS_CACHE_CAPACITY: u64 = 10_000;
+
+/// In-process cache of user settings
+///
+/// Settings are populated on read and must be invalidated after every write
+/// to the settings or the alarms they mirror, so a read following a write
+/// never sees the old value. Hits and misses are counted for monitoring.
+///
+/// # Fields
+///
+/// * `cache` - The cached settings by user ID, `None` if caching is disabled
+/// * `hits` - Number of reads answered from the cache
+/// * `misses` - Number of reads that had to load the settings
+pub struct SettingsCache {
+    cache: Option<Cache<Uuid, Settings>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SettingsCache {
+    /// Creates a settings cache
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl_seconds` - How long settings stay cached, 0 disables caching
+    pub fn new(ttl_seconds: u64) -> Self {
+        let cache = (ttl_seconds > 0).then(|| {
+            Cache::builder()
+                .max_capacity(SETTINGS_CACHE_CAPACITY)
+                .time_to_live(Duration::from_secs(ttl_seconds))
+                .build()
+        });
+
+        Self {
+            cache,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the cached settings of a user, loading them on a miss
+    ///
+    /// Loaded settings are cached only if loading succeeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The UUID of the user whose settings to get
+    /// * `load` - Loads the settings from the database
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Settings)` - The user's settings
+    /// * `Err(Error)` - The error returned by `load`
+    pub async fn get_or_load<F, Fut>(&self, user_id: Uuid, load: F) -> Result<Settings, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Settings, Error>>,
+    {
+        let Some(cache) = &self.cache else {
+            return load().await;
+        };
+
+        if let Some(settings) = cache.get(&user_id).await {
+            let hits = self.hits.fetch_add(1, Ordering::Relaxed) + 1;
+            tracing::debug!("Settings cache hit for user {} (hits: {})", user_id, hits);
+            return Ok(settings);
+        }
+
+        let misses = self.misses.fetch_add(1, Ordering::Relaxed) + 1;
+        tracing::debug!(
+            "Settings cache miss for user {} (misses: {})",
+            user_id,
+            misses
+        );
+
+        let settings = load().await?;
+        cache.insert(user_id, settings.clone()).await;
+        Ok(settings)
+    }
+
+    /// Removes the cached settings of a user
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The UUID of the user whose settings changed
+    pub async fn invalidate(&self, user_id: Uuid) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate(&user_id).await;
+        }
+    }
+
+    /// Returns the number of reads answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of reads that had to load the settings
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    fn settings(user_id: Uuid, language: &str) -> Settings {
+        serde_json::from_value(serde_json::json!({
+            "user_id": user_id,
+            "language": language,
+            "currency": "usd",
+            "alarm_set": false,
+            "alarm_time": "07:00:00",
+            "alarm_offset_minutes": 0,
+            "night_mode": false,
+            "theme": "light",
+            "timezone": "UTC",
+            "date_format": "ISO",
+            "number_format": "dot-decimal",
+            "week_starts_on": "Monday",
+            "budget_period": "Monthly",
+            "analytics_enabled": true,
+            "crash_reports_enabled": false,
+            "summary_email_frequency": "Off",
+            "summary_email_day": 1,
+            "currency_symbol_position": "Prefix",
+            "thousands_separator": "Comma",
+            "schema_version": 3,
+            "updated_at": "2025-11-10T12:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_repeated_reads_hit_the_cache() {
+        let cache = SettingsCache::new(60);
+        let user_id = Uuid::new_v4();
+        let loads = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            let settings = cache
+                .get_or_load(user_id, || async {
+                    loads.fetch_add(1, Ordering::Relaxed);
+                    Ok(settings(user_id, "en"))
+                })
+                .await
+                .unwrap();
+            assert_eq!(settings.language(), "en");
+        }
+
+        assert_eq!(loads.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_after_invalidation_returns_the_new_value() {
+        let cache = SettingsCache::new(60);
+        let user_id = Uuid::new_v4();
+
+        cache
+            .get_or_load(user_id, || async { Ok(settings(user_id, "en")) })
+            .await
+            .unwrap();
+
+        // A write stores the new value and invalidates the cached one
+        cache.invalidate(user_id).await;
+        let settings = cache
+            .get_or_load(user_id, || async { Ok(settings(user_id, "ro")) })
+            .await
+            .unwrap();
+
+        assert_eq!(settings.language(), "ro");
+        assert_eq!(cache.misses(), 2);
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_loads_are_not_cached() {
+        let cache = SettingsCache::new(60);
+        let user_id = Uuid::new_v4();
+
+        let result = cache
+            .get_or_load(user_id, || async {
+                Err(Error::from(sqlx::Error::RowNotFound))
+            })
+            .await;
+        assert!(result.is_err());
+
+        let settings = cache
+            .get_or_load(user_id, || async { Ok(settings(user_id, "de")) })
+            .await
+            .unwrap();
+        assert_eq!(settings.language(), "de");
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_always_loads() {
+        let cache = SettingsCache::new(0);
+        let user_id = Uuid::new_v4();
+        let loads = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            cache
+                .get_or_load(user_id, || async {
+                    loads.fetch_add(1, Ordering::Relaxed);
+                    Ok(settings(user_id, "en"))
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(loads.load(Ordering::Relaxed), 2);
+        assert_eq!(cache.hits(), 0);
+    }
+}
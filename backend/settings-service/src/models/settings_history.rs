@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, types::Json};
+use uuid::Uuid;
+
+/// Where a settings change came from
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingsChangeSource {
+    /// A partial update through `POST /user`
+    Update,
+    /// A reset to the defaults through `POST /user/reset`
+    Reset,
+    /// Summary emails turned off through `POST /unsubscribe`
+    Unsubscribe,
+}
+
+impl SettingsChangeSource {
+    /// Returns the source as stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingsChangeSource::Update => "update",
+            SettingsChangeSource::Reset => "reset",
+            SettingsChangeSource::Unsubscribe => "unsubscribe",
+        }
+    }
+}
+
+/// A recorded change of a user's settings
+///
+/// # Fields
+///
+/// * `id` - Unique identifier of the history entry
+/// * `changes` - Changed fields mapped to `{"old": ..., "new": ...}`
+/// * `source` - Where the change came from ("update", "reset" or "unsubscribe")
+/// * `changed_at` - When the change was made
+#[derive(FromRow, Debug, Serialize)]
+pub struct SettingsHistoryEntry {
+    pub id: Uuid,
+    pub changes: Json<serde_json::Map<String, serde_json::Value>>,
+    pub source: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_source_matches_serialization() {
+        for source in [SettingsChangeSource::Update, SettingsChangeSource::Reset] {
+            let serialized = serde_json::to_string(&source).unwrap();
+            assert_eq!(serialized, format!("\"{}\"", source.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_history_entry_serializes_changes_inline() {
+        let mut changes = serde_json::Map::new();
+        changes.insert(
+            "currency".to_string(),
+            serde_json::json!({ "old": "usd", "new": "eur" }),
+        );
+
+        let entry = SettingsHistoryEntry {
+            id: Uuid::nil(),
+            changes: Json(changes),
+            source: SettingsChangeSource::Update.as_str().to_string(),
+            changed_at: DateTime::<Utc>::default(),
+        };
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["changes"]["currency"]["new"], "eur");
+        assert_eq!(json["source"], "update");
+    }
+}
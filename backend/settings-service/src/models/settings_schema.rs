@@ -0,0 +1,371 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use shared_types::enums::{Currency, Theme};
+
+use crate::models::{response::TranslationKey, settings::DefaultSettings};
+
+/// Current version of the settings schema
+///
+/// Bump whenever a settings field is added or renamed, and register the
+/// upgrade from the previous version in `UPGRADES`.
+pub const CURRENT_SCHEMA_VERSION: i32 = 6;
+
+/// An upgrade of a settings document from one schema version to the next
+///
+/// # Fields
+///
+/// * `from` - The version the upgrade applies to, it produces `from + 1`
+/// * `apply` - Rewrites the settings fields in place
+pub struct Upgrade {
+    pub from: i32,
+    pub apply: fn(&mut Map<String, Value>),
+}
+
+/// Upgrades between consecutive schema versions, oldest first
+///
+/// Schema versions:
+///
+/// 1. language, currency, alarm_set, alarm_time, alarm_offset_minutes, night_mode
+/// 2. adds timezone, date_format, number_format, week_starts_on, budget_period
+/// 3. adds theme, derived from night_mode
+/// 4. adds analytics_enabled and crash_reports_enabled
+/// 5. adds summary_email_frequency and summary_email_day
+/// 6. adds currency_symbol_position and thousands_separator, derived from currency, and
+///    decimal_places_override
+pub const UPGRADES: &[Upgrade] = &[
+    Upgrade {
+        from: 1,
+        apply: |settings| {
+            let defaults = DefaultSettings::DEFAULT;
+            let added = [
+                ("timezone", defaults.timezone_name()),
+                ("date_format", defaults.date_format.as_str()),
+                ("number_format", defaults.number_format.as_str()),
+                ("week_starts_on", defaults.week_starts_on.as_str()),
+                ("budget_period", defaults.budget_period.as_str()),
+            ];
+            for (field, value) in added {
+                settings.entry(field).or_insert_with(|| Value::from(value));
+            }
+        },
+    },
+    Upgrade {
+        from: 2,
+        apply: |settings| {
+            // Version 2 has no theme, a theme found on a row is the column default
+            let night_mode = settings
+                .get("night_mode")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            settings.insert(
+                "theme".into(),
+                Theme::from_night_mode(night_mode).as_str().into(),
+            );
+        },
+    },
+    Upgrade {
+        from: 3,
+        apply: |settings| {
+            let defaults = DefaultSettings::DEFAULT;
+            settings
+                .entry("analytics_enabled")
+                .or_insert(defaults.analytics_enabled.into());
+            settings
+                .entry("crash_reports_enabled")
+                .or_insert(defaults.crash_reports_enabled.into());
+        },
+    },
+    Upgrade {
+        from: 4,
+        apply: |settings| {
+            let defaults = DefaultSettings::DEFAULT;
+            settings
+                .entry("summary_email_frequency")
+                .or_insert(defaults.summary_email_frequency.as_str().into());
+            settings
+                .entry("summary_email_day")
+                .or_insert(defaults.summary_email_day.into());
+        },
+    },
+    Upgrade {
+        from: 5,
+        apply: |settings| {
+            let currency = settings
+                .get("currency")
+                .and_then(Value::as_str)
+                .and_then(|currency| Currency::from_str(currency).ok())
+                .unwrap_or(DefaultSettings::DEFAULT.currency);
+            settings
+                .entry("currency_symbol_position")
+                .or_insert(currency.default_symbol_position().as_str().into());
+            settings
+                .entry("thousands_separator")
+                .or_insert(currency.default_thousands_separator().as_str().into());
+            settings
+                .entry("decimal_places_override")
+                .or_insert(Value::Null);
+        },
+    },
+];
+
+/// Upgrades settings fields written with an older schema version to the current one
+///
+/// # Arguments
+///
+/// * `settings` - The settings fields
+/// * `version` - The schema version the fields were written with
+///
+/// # Returns
+///
+/// * `Ok(Map)` - The fields in the current schema
+/// * `Err(TranslationKey)` - `UnsupportedSettingsVersion` for unknown or newer versions
+pub fn upgrade(
+    mut settings: Map<String, Value>,
+    version: i32,
+) -> Result<Map<String, Value>, TranslationKey> {
+    if !(1..=CURRENT_SCHEMA_VERSION).contains(&version) {
+        return Err(TranslationKey::UnsupportedSettingsVersion);
+    }
+
+    for step in UPGRADES.iter().filter(|step| step.from >= version) {
+        (step.apply)(&mut settings);
+    }
+
+    Ok(settings)
+}
+
+/// A settings export document
+///
+/// The same document is accepted by the import, documents of older schema
+/// versions are upgraded first.
+///
+/// # Fields
+///
+/// * `schema_version` - The schema version of `settings`
+/// * `exported_at` - When the document was exported (only set on exports)
+/// * `settings` - The settings fields, without the user id
+///
+/// # Example
+///
+/// ```json
+/// {
+///     "schema_version": 1,
+///     "settings": {
+///         "language": "en",
+///         "currency": "usd",
+///         "night_mode": true
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettingsDocument {
+    pub schema_version: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<DateTime<Utc>>,
+    pub settings: Map<String, Value>,
+}
+
+impl SettingsDocument {
+    /// Creates an export document from the fields of the current settings
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The serialized settings in the current schema, the user id and row
+    ///   metadata are removed
+    ///
+    /// # Returns
+    ///
+    /// A new `SettingsDocument` stamped with the current time
+    pub fn export(mut settings: Map<String, Value>) -> Self {
+        settings.remove("user_id");
+        settings.remove("schema_version");
+        settings.remove("updated_at");
+
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            exported_at: Some(Utc::now()),
+            settings,
+        }
+    }
+
+    /// Returns the settings fields upgraded to the current schema
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map)` - The fields in the current schema
+    /// * `Err(TranslationKey)` - `UnsupportedSettingsVersion` for unknown or newer versions
+    pub fn upgraded(self) -> Result<Map<String, Value>, TranslationKey> {
+        upgrade(self.settings, self.schema_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::settings::UpdateSettings;
+
+    #[test]
+    fn test_settings_document_rejects_unknown_fields() {
+        let document = serde_json::from_value::<SettingsDocument>(json!({
+            "schema_version": 4,
+            "settings": {},
+            "checksum": "abc"
+        }));
+        assert!(document.is_err());
+    }
+
+    #[test]
+    fn test_upgrades_cover_every_version() {
+        let versions: Vec<i32> = UPGRADES.iter().map(|step| step.from).collect();
+        let expected: Vec<i32> = (1..CURRENT_SCHEMA_VERSION).collect();
+        assert_eq!(versions, expected);
+    }
+
+    #[test]
+    fn test_import_v1_document() {
+        let document: SettingsDocument = serde_json::from_value(json!({
+            "schema_version": 1,
+            "settings": {
+                "language": "es",
+                "currency": "eur",
+                "alarm_set": true,
+                "alarm_time": "08:00:00",
+                "alarm_offset_minutes": 10,
+                "night_mode": true
+            }
+        }))
+        .unwrap();
+
+        let settings = document.upgraded().unwrap();
+        assert_eq!(settings["timezone"], "UTC");
+        assert_eq!(settings["date_format"], "ISO");
+        assert_eq!(settings["number_format"], "dot-decimal");
+        assert_eq!(settings["week_starts_on"], "Monday");
+        assert_eq!(settings["budget_period"], "Monthly");
+        assert_eq!(settings["theme"], "dark");
+        assert_eq!(settings["language"], "es");
+        assert_eq!(settings["currency_symbol_position"], "Suffix");
+
+        let update: UpdateSettings = serde_json::from_value(Value::Object(settings)).unwrap();
+        let update = update.normalized().unwrap();
+        assert_eq!(update.currency.as_deref(), Some("eur"));
+        assert_eq!(update.alarm_offset_minutes, Some(10));
+        assert_eq!(update.budget_period.as_deref(), Some("Monthly"));
+    }
+
+    #[test]
+    fn test_upgrade_v2_derives_theme_from_night_mode() {
+        let settings = json!({ "night_mode": false, "theme": "system" });
+        let Value::Object(settings) = settings else {
+            unreachable!()
+        };
+
+        let settings = upgrade(settings, 2).unwrap();
+        assert_eq!(settings["theme"], "light");
+        assert_eq!(settings["night_mode"], false);
+    }
+
+    #[test]
+    fn test_upgrade_v3_adds_privacy_flags() {
+        let Value::Object(settings) = json!({ "language": "en" }) else {
+            unreachable!()
+        };
+        let settings = upgrade(settings, 3).unwrap();
+        assert_eq!(settings["analytics_enabled"], true);
+        assert_eq!(settings["crash_reports_enabled"], false);
+
+        let Value::Object(settings) = json!({ "analytics_enabled": false }) else {
+            unreachable!()
+        };
+        let settings = upgrade(settings, 3).unwrap();
+        assert_eq!(settings["analytics_enabled"], false);
+    }
+
+    #[test]
+    fn test_upgrade_v4_adds_summary_email() {
+        let Value::Object(settings) = json!({ "language": "en" }) else {
+            unreachable!()
+        };
+        let settings = upgrade(settings, 4).unwrap();
+        assert_eq!(settings["summary_email_frequency"], "Off");
+        assert_eq!(settings["summary_email_day"], 1);
+    }
+
+    #[test]
+    fn test_upgrade_v5_derives_amount_display_from_currency() {
+        let Value::Object(settings) = json!({ "currency": "eur" }) else {
+            unreachable!()
+        };
+        let settings = upgrade(settings, 5).unwrap();
+        assert_eq!(settings["currency_symbol_position"], "Suffix");
+        assert_eq!(settings["thousands_separator"], "Space");
+        assert_eq!(settings["decimal_places_override"], Value::Null);
+
+        let Value::Object(settings) = json!({ "language": "en" }) else {
+            unreachable!()
+        };
+        let settings = upgrade(settings, 5).unwrap();
+        assert_eq!(settings["currency_symbol_position"], "Prefix");
+        assert_eq!(settings["thousands_separator"], "Comma");
+    }
+
+    #[test]
+    fn test_upgrade_keeps_existing_fields() {
+        let settings = json!({ "timezone": "Europe/Bucharest" });
+        let Value::Object(settings) = settings else {
+            unreachable!()
+        };
+
+        let settings = upgrade(settings, 1).unwrap();
+        assert_eq!(settings["timezone"], "Europe/Bucharest");
+    }
+
+    #[test]
+    fn test_upgrade_current_version_is_unchanged() {
+        let mut settings = Map::new();
+        settings.insert("language".into(), "fr".into());
+
+        let upgraded = upgrade(settings.clone(), CURRENT_SCHEMA_VERSION).unwrap();
+        assert_eq!(upgraded, settings);
+    }
+
+    #[test]
+    fn test_upgrade_rejects_unknown_versions() {
+        assert_eq!(
+            upgrade(Map::new(), CURRENT_SCHEMA_VERSION + 1),
+            Err(TranslationKey::UnsupportedSettingsVersion)
+        );
+        assert_eq!(
+            upgrade(Map::new(), 0),
+            Err(TranslationKey::UnsupportedSettingsVersion)
+        );
+    }
+
+    #[test]
+    fn test_export_document() {
+        let settings = json!({
+            "user_id": "550e8400-e29b-41d4-a716-446655440000",
+            "schema_version": CURRENT_SCHEMA_VERSION,
+            "updated_at": "2025-11-10T12:00:00Z",
+            "language": "en"
+        });
+        let Value::Object(settings) = settings else {
+            unreachable!()
+        };
+
+        let document = SettingsDocument::export(settings);
+        assert_eq!(document.schema_version, CURRENT_SCHEMA_VERSION);
+        assert!(document.exported_at.is_some());
+        assert!(!document.settings.contains_key("user_id"));
+        assert!(!document.settings.contains_key("schema_version"));
+        assert!(!document.settings.contains_key("updated_at"));
+        assert_eq!(document.settings["language"], "en");
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Value of the `List-Unsubscribe` form field of a one-click unsubscribe (RFC 8058)
+const ONE_CLICK: &str = "One-Click";
+
+/// Query parameters of the unsubscribe endpoint
+///
+/// # Fields
+///
+/// * `token` - Token identifying the user, from the email's unsubscribe link
+#[derive(Deserialize)]
+pub struct UnsubscribeQuery {
+    pub token: String,
+}
+
+/// Form body mail providers send with a one-click unsubscribe
+///
+/// # Fields
+///
+/// * `list_unsubscribe` - The `List-Unsubscribe` field, `One-Click` for a one-click unsubscribe
+#[derive(Deserialize)]
+pub struct OneClickUnsubscribe {
+    #[serde(rename = "List-Unsubscribe")]
+    pub list_unsubscribe: Option<String>,
+}
+
+impl OneClickUnsubscribe {
+    /// Checks whether the body is a one-click unsubscribe
+    pub fn is_one_click(&self) -> bool {
+        self.list_unsubscribe.as_deref() == Some(ONE_CLICK)
+    }
+}
+
+/// Creates a new unsubscribe token
+///
+/// Tokens are random and hex encoded, so they are URL safe as the email
+/// service requires.
+pub fn new_token() -> String {
+    Uuid::new_v4().simple().to_string()
+}
+
+/// Checks whether a string can be an unsubscribe token
+///
+/// Lets malformed tokens be rejected without a database lookup.
+///
+/// # Arguments
+///
+/// * `token` - The token from the unsubscribe link
+///
+/// # Returns
+///
+/// `true` if the token is 32 lowercase hex characters
+pub fn is_valid_token(token: &str) -> bool {
+    token.len() == 32
+        && token
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_tokens_are_valid_and_unique() {
+        let token = new_token();
+
+        assert!(is_valid_token(&token));
+        assert_ne!(token, new_token());
+    }
+
+    #[test]
+    fn test_invalid_tokens() {
+        assert!(!is_valid_token(""));
+        assert!(!is_valid_token("not-a-token"));
+        assert!(!is_valid_token(&new_token().to_uppercase()));
+        assert!(!is_valid_token(&format!("{}0", new_token())));
+    }
+
+    #[test]
+    fn test_one_click_body() {
+        let body = |value: Option<&str>| OneClickUnsubscribe {
+            list_unsubscribe: value.map(String::from),
+        };
+
+        assert!(body(Some("One-Click")).is_one_click());
+        assert!(!body(Some("one-click")).is_one_click());
+        assert!(!body(None).is_one_click());
+    }
+}
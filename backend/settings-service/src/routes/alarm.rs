@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState, database,
+    models::{
+        alarm::{CreateAlarm, UpdateAlarm},
+        response::{Error, TranslationKey},
+    },
+    routes::middlewares::{auth_guard, write_rate_limit},
+};
+
+/// Creates a router for the alarm routes
+///
+/// This function sets up the alarm endpoints and returns a configured Axum router.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the alarm endpoints with auth middleware.
+/// Writes are rate limited per user, reads are not.
+///
+/// # Routes
+///
+/// - `GET /` - List the authenticated user's alarms (protected by auth middleware)
+/// - `POST /` - Create a new alarm (protected by auth middleware)
+/// - `PATCH /{id}` - Partially update an alarm by ID (protected by auth middleware)
+/// - `DELETE /{id}` - Delete an alarm by ID (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_alarms))
+        .route("/", post(create_alarm))
+        .route("/{id}", patch(update_alarm))
+        .route("/{id}", delete(delete_alarm))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            write_rate_limit::write_rate_limit,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Retrieves all alarms of the authenticated user
+///
+/// Alarms are returned oldest first; the first one backs the legacy
+/// `alarm_set`, `alarm_time` and `alarm_offset_minutes` settings fields.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<Alarm>>)` - The user's alarms as JSON
+/// * `Err(Error)` - Database operation error
+///
+/// # Example Response
+///
+/// ```json
+/// [
+///     {
+///         "id": "7a1f8e2c-3b4d-4e5f-8a9b-0c1d2e3f4a5b",
+///         "label": "Morning budget check",
+///         "time": "08:00:00",
+///         "offset_minutes": 0,
+///         "enabled": true,
+///         "days_of_week": 31,
+///         "created_at": "2025-11-10T12:00:00Z"
+///     }
+/// ]
+/// ```
+async fn get_all_alarms(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("GET /user/alarms - Fetching alarms for user {}", user_id);
+
+    let alarms = database::alarm::find_all_by_user(user_id, state.get_database_pool())
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch alarms for user {}", user_id);
+        })?;
+
+    Ok(Json(alarms))
+}
+
+/// Creates a new alarm
+///
+/// Users without settings get the default settings created first, the alarms
+/// belong to the settings row.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+/// * `create_alarm` - The alarm creation data
+///
+/// # Returns
+///
+/// * `Ok((StatusCode::CREATED, Json<Alarm>))` - The created alarm
+/// * `Err(Error)` - Invalid label or days, alarm limit reached, or database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// POST /user/alarms
+/// Content-Type: application/json
+///
+/// {
+///     "label": "Morning budget check",
+///     "time": "08:00:00",
+///     "days_of_week": 31
+/// }
+/// ```
+async fn create_alarm(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(create_alarm): Json<CreateAlarm>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("POST /user/alarms - Creating alarm for user {}", user_id);
+
+    create_alarm
+        .validate()
+        .map_err(|translation_key| -> Error {
+            tracing::warn!("Invalid alarm for user {}", user_id);
+            (StatusCode::BAD_REQUEST, translation_key).into()
+        })?;
+
+    let pool = state.get_database_pool();
+
+    database::settings::insert_default(user_id, &state.config.default_settings, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to create settings for user {}", user_id);
+        })?;
+
+    let alarm = database::alarm::create(
+        user_id,
+        create_alarm,
+        state.config.max_alarms_per_user,
+        pool,
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!("Failed to create alarm for user {}", user_id);
+    })?;
+    state.invalidate_user_settings(user_id).await;
+
+    tracing::info!(
+        "Successfully created alarm {} for user {}",
+        alarm.id,
+        user_id
+    );
+    Ok((StatusCode::CREATED, Json(alarm)))
+}
+
+/// Updates an alarm
+///
+/// Only the fields provided in the request body are updated.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `alarm_id` - The UUID of the alarm to update
+/// * `state` - Shared application state
+/// * `update_alarm` - The alarm update data
+///
+/// # Returns
+///
+/// * `Ok(Json<Alarm>)` - The updated alarm
+/// * `Err(Error)` - Invalid label or days, alarm not found, or database operation error
+async fn update_alarm(
+    Extension(user_id): Extension<Uuid>,
+    Path(alarm_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(update_alarm): Json<UpdateAlarm>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "PATCH /user/alarms/{} - Updating alarm for user {}",
+        alarm_id,
+        user_id
+    );
+
+    update_alarm
+        .validate()
+        .map_err(|translation_key| -> Error {
+            tracing::warn!("Invalid alarm update for user {}", user_id);
+            (StatusCode::BAD_REQUEST, translation_key).into()
+        })?;
+
+    let alarm = database::alarm::update(alarm_id, user_id, update_alarm, state.get_database_pool())
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to update alarm {} for user {}", alarm_id, user_id);
+        })?;
+    state.invalidate_user_settings(user_id).await;
+
+    tracing::info!(
+        "Successfully updated alarm {} for user {}",
+        alarm_id,
+        user_id
+    );
+    Ok(Json(alarm))
+}
+
+/// Deletes an alarm
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `alarm_id` - The UUID of the alarm to delete
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(StatusCode::NO_CONTENT)` - If deletion succeeds
+/// * `Err(Error)` - Alarm not found or database operation error
+async fn delete_alarm(
+    Extension(user_id): Extension<Uuid>,
+    Path(alarm_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "DELETE /user/alarms/{} - Deleting alarm for user {}",
+        alarm_id,
+        user_id
+    );
+
+    let deleted = database::alarm::delete(alarm_id, user_id, state.get_database_pool())
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to delete alarm {} for user {}", alarm_id, user_id);
+        })?;
+
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, TranslationKey::AlarmNotFound).into());
+    }
+    state.invalidate_user_settings(user_id).await;
+
+    tracing::info!(
+        "Successfully deleted alarm {} for user {}",
+        alarm_id,
+        user_id
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, put},
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState, database,
+    models::{
+        device_settings::DeviceOverrides,
+        response::{Error, TranslationKey},
+    },
+    routes::middlewares::{auth_guard, write_rate_limit},
+};
+
+/// Creates a router for the device settings routes
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the device settings endpoints with auth middleware.
+/// Writes are rate limited per user, reads are not.
+///
+/// # Routes
+///
+/// - `GET /{device_id}` - Get the settings overrides of a device (protected by auth middleware)
+/// - `PUT /{device_id}` - Replace the settings overrides of a device (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/{device_id}", get(get_device_settings))
+        .route("/{device_id}", put(put_device_settings))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            write_rate_limit::write_rate_limit,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Retrieves the settings overrides of a device and marks it as seen
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `device_id` - The client generated UUID of the device
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<DeviceSettings>)` - The device's overrides
+/// * `Err(Error)` - Device not found or database operation error
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///     "device_id": "0b6c9a3e-6a4f-4f51-9c1e-2f7d8e9a0b1c",
+///     "overrides": { "night_mode": true },
+///     "last_seen_at": "2025-11-10T12:00:00Z"
+/// }
+/// ```
+async fn get_device_settings(
+    Extension(user_id): Extension<Uuid>,
+    Path(device_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /user/device/{} - Fetching device settings for user {}",
+        device_id,
+        user_id
+    );
+
+    let device_settings =
+        database::device_settings::touch(user_id, device_id, state.get_database_pool())
+            .await
+            .inspect_err(|_| {
+                tracing::error!(
+                    "Failed to fetch settings of device {} for user {}",
+                    device_id,
+                    user_id
+                );
+            })?
+            .ok_or_else(|| -> Error {
+                (StatusCode::NOT_FOUND, TranslationKey::DeviceNotFound).into()
+            })?;
+
+    Ok(Json(device_settings))
+}
+
+/// Replaces the settings overrides of a device
+///
+/// Only `night_mode` and `language` can be overridden; fields left out use the
+/// user's settings. Users without settings get the default settings created first.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `device_id` - The client generated UUID of the device
+/// * `state` - Shared application state
+/// * `overrides` - The device's overrides
+///
+/// # Returns
+///
+/// * `Ok(Json<DeviceSettings>)` - The stored overrides
+/// * `Err(Error)` - 400 with the invalid `field` as param for an unsupported language,
+///   409 `DEVICE_LIMIT_REACHED` for a new device over the limit, or database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// PUT /user/device/0b6c9a3e-6a4f-4f51-9c1e-2f7d8e9a0b1c
+/// Content-Type: application/json
+///
+/// {
+///     "night_mode": true
+/// }
+/// ```
+async fn put_device_settings(
+    Extension(user_id): Extension<Uuid>,
+    Path(device_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(overrides): Json<DeviceOverrides>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "PUT /user/device/{} - Storing device settings for user {}",
+        device_id,
+        user_id
+    );
+
+    let overrides = overrides.normalized().map_err(|invalid| -> Error {
+        tracing::warn!(
+            "Invalid {} in device settings for user {}",
+            invalid.field,
+            user_id
+        );
+        Error::new(StatusCode::BAD_REQUEST, invalid.translation_key)
+            .with_param("field", invalid.field)
+    })?;
+
+    let pool = state.get_database_pool();
+
+    database::settings::insert_default(user_id, &state.config.default_settings, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to create settings for user {}", user_id);
+        })?;
+
+    let device_settings = database::device_settings::upsert(
+        user_id,
+        device_id,
+        overrides,
+        state.config.max_devices_per_user,
+        pool,
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!(
+            "Failed to store settings of device {} for user {}",
+            device_id,
+            user_id
+        );
+    })?;
+
+    tracing::info!(
+        "Successfully stored settings of device {} for user {}",
+        device_id,
+        user_id
+    );
+    Ok(Json(device_settings))
+}
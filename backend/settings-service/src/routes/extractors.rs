@@ -0,0 +1,203 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::models::response::{Error, TranslationKey};
+
+/// A JSON request body that reports every unsupported field
+///
+/// Works like axum's `Json` extractor, but a body rejected by a type with
+/// `#[serde(deny_unknown_fields)]` becomes a 400 `UNKNOWN_FIELDS` error with
+/// the offending field names in the `fields` param, instead of axum's plain
+/// text rejection. Other rejections are returned as axum would.
+///
+/// # Example Error Response
+///
+/// ```json
+/// {
+///     "translation_key": "UNKNOWN_FIELDS",
+///     "params": { "fields": ["colour", "font_size"] }
+/// }
+/// ```
+pub struct JsonBody<T>(pub T);
+
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<Value>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        match from_value(value) {
+            Ok(body) => Ok(Self(body)),
+            Err(BodyError::UnknownFields(fields)) => {
+                tracing::warn!("Rejected request body with unknown fields {:?}", fields);
+                Err(unknown_fields_error(fields).into_response())
+            }
+            Err(BodyError::Invalid(e)) => Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!(
+                    "Failed to deserialize the JSON body into the target type: {}",
+                    e
+                ),
+            )
+                .into_response()),
+        }
+    }
+}
+
+/// Reasons a JSON value could not be deserialized
+#[derive(Debug)]
+pub enum BodyError {
+    /// The value has fields the target type does not know, by name
+    UnknownFields(Vec<String>),
+    /// The value is not valid for the target type
+    Invalid(serde_json::Error),
+}
+
+/// Deserializes a JSON value, collecting every top-level field the target type rejects
+///
+/// Serde stops at the first unknown field, so each one reported is removed
+/// and the value deserialized again until no unknown field is left.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value to deserialize
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized value, if it has no unknown fields
+/// * `Err(BodyError)` - The unknown fields, or the error of an otherwise invalid value
+pub fn from_value<T: DeserializeOwned>(mut value: Value) -> Result<T, BodyError> {
+    let mut unknown = Vec::new();
+
+    loop {
+        let err = match T::deserialize(&value) {
+            Ok(body) if unknown.is_empty() => return Ok(body),
+            Ok(_) => return Err(BodyError::UnknownFields(unknown)),
+            Err(err) => err,
+        };
+
+        let removed = unknown_field(&err).filter(|field| {
+            value
+                .as_object_mut()
+                .is_some_and(|fields| fields.remove(field).is_some())
+        });
+        match removed {
+            Some(field) => unknown.push(field),
+            None if unknown.is_empty() => return Err(BodyError::Invalid(err)),
+            None => return Err(BodyError::UnknownFields(unknown)),
+        }
+    }
+}
+
+/// Returns the 400 error listing the unknown fields of a request body
+///
+/// # Arguments
+///
+/// * `fields` - Names of the unknown fields
+pub fn unknown_fields_error(fields: Vec<String>) -> Error {
+    Error::new(StatusCode::BAD_REQUEST, TranslationKey::UnknownFields).with_param("fields", fields)
+}
+
+/// Returns the field name of a serde "unknown field" error
+fn unknown_field(err: &serde_json::Error) -> Option<String> {
+    let message = err.to_string();
+    let field = message.strip_prefix("unknown field `")?;
+    field.split('`').next().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{Body, to_bytes};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Payload {
+        language: Option<String>,
+    }
+
+    fn request(body: &str) -> Request {
+        Request::builder()
+            .method("PUT")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_from_value_known_fields() {
+        let body: Payload = from_value(json!({ "language": "en" })).unwrap();
+        assert_eq!(body.language, Some("en".to_string()));
+    }
+
+    #[test]
+    fn test_from_value_collects_every_unknown_field() {
+        let result = from_value::<Payload>(json!({
+            "language": "en",
+            "colour": "blue",
+            "font_size": 12
+        }));
+
+        let Err(BodyError::UnknownFields(mut fields)) = result else {
+            panic!("expected unknown fields, got {:?}", result);
+        };
+        fields.sort();
+        assert_eq!(fields, vec!["colour", "font_size"]);
+    }
+
+    #[test]
+    fn test_from_value_invalid_type() {
+        let result = from_value::<Payload>(json!({ "language": 5 }));
+        assert!(matches!(result, Err(BodyError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_from_value_unknown_field_reported_before_invalid_type() {
+        let result = from_value::<Payload>(json!({ "colour": "blue", "language": 5 }));
+        assert!(matches!(result, Err(BodyError::UnknownFields(fields)) if fields == ["colour"]));
+    }
+
+    #[tokio::test]
+    async fn test_json_body_rejects_unknown_fields_with_bad_request() {
+        let response = JsonBody::<Payload>::from_request(request(r#"{ "colour": "blue" }"#), &())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["translation_key"], "UNKNOWN_FIELDS");
+        assert_eq!(body["params"]["fields"], json!(["colour"]));
+    }
+
+    #[tokio::test]
+    async fn test_json_body_keeps_other_rejections() {
+        let response = JsonBody::<Payload>::from_request(request("not json"), &())
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = JsonBody::<Payload>::from_request(request(r#"{ "language": 5 }"#), &())
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
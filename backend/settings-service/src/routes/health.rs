@@ -35,6 +35,7 @@ async fn health_checker_handler(State(state): State<Arc<AppState>>) -> impl Into
         Ok(_) => Json(Health {
             status: HealthStatus::Healthy,
             database: Some(DatabaseConnection::Connected),
+            auth_service: None,
             version: env!("CARGO_PKG_VERSION").into(),
         })
         .into_response(),
@@ -43,6 +44,7 @@ async fn health_checker_handler(State(state): State<Arc<AppState>>) -> impl Into
             Json(Health {
                 status: HealthStatus::Unhealthy,
                 database: Some(DatabaseConnection::Disconnected),
+                auth_service: None,
                 version: env!("CARGO_PKG_VERSION").into(),
             }),
         )
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    http::{HeaderMap, header},
+    response::IntoResponse,
+    routing::get,
+};
+use shared_types::enums::Language;
+
+use crate::{AppState, models::meta::EnumValues};
+
+/// Creates a router for the metadata routes
+///
+/// The metadata is the same for every user, so these routes are public.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the metadata endpoints.
+///
+/// # Routes
+///
+/// - `GET /enums` - List the values of every enum the backend accepts
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/enums", get(get_enum_values))
+        .with_state(state)
+}
+
+/// Lists the values of every enum the backend accepts
+///
+/// Labels are in the preferred supported language of the `Accept-Language`
+/// header, English if the header is missing or names no supported language.
+///
+/// # Arguments
+///
+/// * `headers` - The request headers
+///
+/// # Returns
+///
+/// JSON response with the supported values of each enum
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///     "currencies": ["USD", "EUR", "GBP", "CAD", "JPY", "RON"],
+///     "languages": ["en", "es", "fr", "de", "ro"],
+///     "wallet_types": ["Account", "Savings", "Deposit", "CreditCard", "Loan"],
+///     "date_formats": ["ISO", "DMY", "MDY"],
+///     "number_formats": ["dot-decimal", "comma-decimal"],
+///     "week_starts": ["Monday", "Sunday"],
+///     "budget_periods": ["Weekly", "Monthly"],
+///     "themes": ["light", "dark", "system"],
+///     "summary_email_frequencies": ["Off", "Weekly", "Monthly"],
+///     "currency_symbol_positions": ["Prefix", "Suffix"],
+///     "thousands_separators": ["Comma", "Dot", "Space", "None"],
+///     "language": "en",
+///     "wallet_type_labels": {
+///         "Account": "Account",
+///         "CreditCard": "Credit Card",
+///         "Deposit": "Deposit",
+///         "Loan": "Loan",
+///         "Savings": "Savings"
+///     }
+/// }
+/// ```
+async fn get_enum_values(headers: HeaderMap) -> impl IntoResponse {
+    let language = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Language::from_accept_language)
+        .unwrap_or(Language::En);
+
+    Json(EnumValues::supported(language))
+}
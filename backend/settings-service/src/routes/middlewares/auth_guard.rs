@@ -2,42 +2,38 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Request, State},
-    http::{StatusCode, header},
+    http::StatusCode,
     middleware::Next,
     response::IntoResponse,
 };
-use uuid::Uuid;
+use shared_types::identity::verified_user_id;
 
 use crate::{
     AppState,
-    grpc::auth_service::service::VerifyTokenRequest,
     models::response::{Error, TranslationKey},
 };
 
 /// Authentication middleware guard for protected routes
 ///
-/// Validates JWT tokens by calling the auth service gRPC endpoint
+/// The gateway verifies the access token of every request with the auth
+/// service and forwards the user it belongs to in the `X-User-Id` header,
+/// together with the internal shared secret. The header is only trusted when
+/// the secret matches, so requests that did not pass through the gateway
+/// cannot claim a user.
 ///
 /// # Flow
-/// 1. Extracts Bearer token from Authorization header
-/// 2. Calls auth service via gRPC to verify token (using persistent connection)
-/// 3. Auth service returns Option<Uuid> with user ID if valid
-/// 4. Adds user ID to request extensions if token is valid
+/// 1. Checks the internal secret the gateway sends
+/// 2. Reads the verified user ID from the `X-User-Id` header
+/// 3. Adds user ID to request extensions
 ///
 /// # Arguments
-/// * `state` - Application state containing auth service client
+/// * `state` - Application state containing the internal secret
 /// * `req` - The incoming HTTP request
 /// * `next` - Next middleware in chain
 ///
 /// # Returns
 /// * `Ok(Response)` - If authentication succeeds
-/// * `Err(Error)` - If any validation step fails
-///
-/// # Errors
-/// * Returns 401 Unauthorized if:
-///   - No token provided
-///   - Token is invalid/expired
-///   - Auth service returns None
+/// * `Err(Error)` - 401 if the request carries no verified user
 pub async fn auth_guard(
     State(state): State<Arc<AppState>>,
     mut req: Request,
@@ -45,65 +41,13 @@ pub async fn auth_guard(
 ) -> Result<impl IntoResponse, Error> {
     tracing::debug!("Auth guard: Processing request");
 
-    // Extract Bearer token from Authorization header
-    let received_token = req
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|header| header.to_str().ok())
-        .and_then(|header| header.strip_prefix("Bearer "))
+    let user_uuid = verified_user_id(req.headers(), &state.config.internal_grpc_secret)
         .ok_or_else(|| {
-            tracing::warn!("Auth guard: No Authorization token provided");
+            tracing::warn!("Auth guard: No user verified by the gateway");
             (StatusCode::UNAUTHORIZED, TranslationKey::NotLoggedIn)
         })?;
 
-    tracing::debug!("Auth guard: Token extracted from header");
-
-    // Get auth service client from state (persistent connection)
-    let mut client = state.get_auth_service().await;
-
-    tracing::debug!("Auth guard: Using persistent auth service connection, calling verify_token");
-
-    // Call verify_token on auth service
-    let request = tonic::Request::new(VerifyTokenRequest {
-        token: received_token.to_string(),
-    });
-
-    let response = client.verify_token(request).await.map_err(|e| {
-        tracing::error!("Auth guard: Failed to verify token: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
-    })?;
-
-    let response_inner = response.into_inner();
-
-    // Check if token is valid (auth service returns Some(user_id) if valid)
-    let user_id = response_inner.user_id.ok_or_else(|| {
-        // Check error reason to return appropriate error
-        let error_reason = response_inner
-            .error_reason
-            .as_deref()
-            .unwrap_or("TOKEN_INVALID");
-        tracing::warn!("Auth guard: Token validation failed - {}", error_reason);
-
-        if error_reason == "TOKEN_EXPIRED" {
-            (StatusCode::UNAUTHORIZED, TranslationKey::TokenExpired)
-        } else {
-            (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid)
-        }
-    })?;
-
-    // Parse user_id as UUID
-    let user_uuid = Uuid::parse_str(&user_id).map_err(|e| {
-        tracing::error!("Auth guard: Invalid user ID format: {}", e);
-        (StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid)
-    })?;
-
-    tracing::info!(
-        "Auth guard: Token verified successfully for user: {}",
-        user_uuid
-    );
+    tracing::debug!("Auth guard: Request verified for user: {}", user_uuid);
 
     // Add user UUID to request extensions and continue
     req.extensions_mut().insert(user_uuid);
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension,
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::response::{Error, TranslationKey},
+};
+
+/// Rate limiting middleware for settings writes
+///
+/// Must run after `auth_guard`, which provides the user ID. Safe methods
+/// (`GET`, `HEAD`, `OPTIONS`) are never limited.
+///
+/// # Arguments
+/// * `state` - Application state containing the write rate limiter
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `req` - The incoming HTTP request
+/// * `next` - Next middleware in chain
+///
+/// # Returns
+/// * `Response` - The response of the next middleware if the write is allowed
+///
+/// # Errors
+/// * Returns 429 Too Many Requests with a `Retry-After` header and the
+///   `retry_after` param in seconds if the user exceeded their write limit
+pub async fn write_rate_limit(
+    State(state): State<Arc<AppState>>,
+    Extension(user_id): Extension<Uuid>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if req.method().is_safe() {
+        return next.run(req).await;
+    }
+
+    if let Err(retry_after) = state
+        .get_write_rate_limiter()
+        .try_acquire(user_id, Utc::now())
+        .await
+    {
+        tracing::warn!(
+            "User {} exceeded the settings write limit, retry after {}s",
+            user_id,
+            retry_after
+        );
+
+        let mut response = Error::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            TranslationKey::SettingsWriteRateLimited,
+        )
+        .with_param("retry_after", retry_after)
+        .into_response();
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+        return response;
+    }
+
+    next.run(req).await
+}
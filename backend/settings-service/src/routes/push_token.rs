@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState, database,
+    models::{
+        push_token::RegisterPushToken,
+        response::{Error, TranslationKey},
+    },
+    routes::middlewares::{auth_guard, write_rate_limit},
+};
+
+/// Creates a router for the push token routes
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the push token endpoints with auth middleware.
+/// Writes are rate limited per user, reads are not.
+///
+/// # Routes
+///
+/// - `GET /` - List the authenticated user's push tokens (protected by auth middleware)
+/// - `POST /` - Register a push token (protected by auth middleware)
+/// - `DELETE /{id}` - Delete a push token by ID (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_push_tokens))
+        .route("/", post(register_push_token))
+        .route("/{id}", delete(delete_push_token))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            write_rate_limit::write_rate_limit,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Retrieves all push tokens of the authenticated user
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<PushToken>>)` - The user's push tokens as JSON
+/// * `Err(Error)` - Database operation error
+///
+/// # Example Response
+///
+/// ```json
+/// [
+///     {
+///         "id": "3f2a9c1e-5b7d-4e8f-9a0b-1c2d3e4f5a6b",
+///         "platform": "android",
+///         "token": "fcm-registration-token",
+///         "created_at": "2025-11-10T16:00:00Z",
+///         "last_seen_at": "2025-11-12T08:30:00Z"
+///     }
+/// ]
+/// ```
+async fn get_all_push_tokens(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /user/push-tokens - Fetching push tokens for user {}",
+        user_id
+    );
+
+    let push_tokens = database::push_token::find_all_by_user(user_id, state.get_database_pool())
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch push tokens for user {}", user_id);
+        })?;
+
+    Ok(Json(push_tokens))
+}
+
+/// Registers a push token
+///
+/// Clients register their token on every start. A token the user already
+/// registered is not duplicated, its `last_seen_at` is bumped instead.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+/// * `register_push_token` - The registration data
+///
+/// # Returns
+///
+/// * `Ok((StatusCode::CREATED, Json<PushToken>))` - The token was registered for the first time
+/// * `Ok((StatusCode::OK, Json<PushToken>))` - The token was already registered
+/// * `Err(Error)` - Invalid platform or token, or database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// POST /user/push-tokens
+/// Content-Type: application/json
+///
+/// {
+///     "platform": "android",
+///     "token": "fcm-registration-token"
+/// }
+/// ```
+async fn register_push_token(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(register_push_token): Json<RegisterPushToken>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "POST /user/push-tokens - Registering push token for user {}",
+        user_id
+    );
+
+    let register_push_token =
+        register_push_token
+            .normalized()
+            .map_err(|translation_key| -> Error {
+                tracing::warn!("Invalid push token for user {}", user_id);
+                (StatusCode::BAD_REQUEST, translation_key).into()
+            })?;
+
+    let pool = state.get_database_pool();
+
+    database::settings::insert_default(user_id, &state.config.default_settings, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to create settings for user {}", user_id);
+        })?;
+
+    let (push_token, inserted) = database::push_token::register(user_id, register_push_token, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to register push token for user {}", user_id);
+        })?;
+
+    let status = if inserted {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+
+    tracing::info!(
+        "Successfully registered push token {} for user {}",
+        push_token.id,
+        user_id
+    );
+    Ok((status, Json(push_token)))
+}
+
+/// Deletes a push token
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `push_token_id` - The UUID of the token registration to delete
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(StatusCode::NO_CONTENT)` - If deletion succeeds
+/// * `Err(Error)` - Push token not found or database operation error
+async fn delete_push_token(
+    Extension(user_id): Extension<Uuid>,
+    Path(push_token_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "DELETE /user/push-tokens/{} - Deleting push token for user {}",
+        push_token_id,
+        user_id
+    );
+
+    let deleted = database::push_token::delete(push_token_id, user_id, state.get_database_pool())
+        .await
+        .inspect_err(|_| {
+            tracing::error!(
+                "Failed to delete push token {} for user {}",
+                push_token_id,
+                user_id
+            );
+        })?;
+
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, TranslationKey::PushTokenNotFound).into());
+    }
+
+    tracing::info!(
+        "Successfully deleted push token {} for user {}",
+        push_token_id,
+        user_id
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use axum::{
+    Form, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    routing::post,
+};
+use shared_types::enums::SummaryEmailFrequency;
+
+use crate::{
+    AppState, database,
+    models::{
+        response::{Error, TranslationKey},
+        settings::UpdateSettings,
+        settings_history::SettingsChangeSource,
+        unsubscribe::{self, OneClickUnsubscribe, UnsubscribeQuery},
+    },
+};
+
+/// Creates a router for the unsubscribe route
+///
+/// The route is linked from the `List-Unsubscribe` header of non-transactional
+/// emails and called by mail providers, so it is authorized by the token of
+/// the link instead of the auth middleware.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the unsubscribe endpoint.
+///
+/// # Routes
+///
+/// - `POST /?token=...` - One-click unsubscribe from summary emails (RFC 8058)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(unsubscribe))
+        .with_state(state)
+}
+
+/// Turns off the summary emails of the user an unsubscribe token belongs to
+///
+/// Only one-click POSTs with a `List-Unsubscribe=One-Click` form body are
+/// accepted. Link scanners follow the URL with GET requests, so a GET never
+/// unsubscribes anyone.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+/// * `query` - Query parameters with the token of the unsubscribe link
+/// * `body` - The one-click unsubscribe form body
+///
+/// # Returns
+///
+/// * `Ok(StatusCode)` - 200 once the summary emails are turned off
+/// * `Err(Error)` - 400 `InvalidUnsubscribeRequest` if the body is not a one-click unsubscribe,
+///   404 `InvalidUnsubscribeToken` if the token is malformed or unknown, or database error
+///
+/// # Example Request
+///
+/// ```http
+/// POST /unsubscribe?token=0f8e5a3c9b2d4e6f8a1b3c5d7e9f0a2b
+/// Content-Type: application/x-www-form-urlencoded
+///
+/// List-Unsubscribe=One-Click
+/// ```
+async fn unsubscribe(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<UnsubscribeQuery>,
+    Form(body): Form<OneClickUnsubscribe>,
+) -> Result<StatusCode, Error> {
+    tracing::info!("POST /unsubscribe - One-click unsubscribe");
+
+    if !body.is_one_click() {
+        tracing::warn!("Rejected unsubscribe request without List-Unsubscribe=One-Click");
+        return Err((
+            StatusCode::BAD_REQUEST,
+            TranslationKey::InvalidUnsubscribeRequest,
+        )
+            .into());
+    }
+
+    let invalid_token = || -> Error {
+        (
+            StatusCode::NOT_FOUND,
+            TranslationKey::InvalidUnsubscribeToken,
+        )
+            .into()
+    };
+    if !unsubscribe::is_valid_token(&query.token) {
+        tracing::warn!("Rejected unsubscribe request with a malformed token");
+        return Err(invalid_token());
+    }
+
+    let pool = state.get_database_pool();
+
+    let user_id = database::unsubscribe_token::find_user(&query.token, pool)
+        .await
+        .inspect_err(|_| tracing::error!("Failed to look up unsubscribe token"))?
+        .ok_or_else(|| {
+            tracing::warn!("Rejected unsubscribe request with an unknown token");
+            invalid_token()
+        })?;
+
+    let update = UpdateSettings {
+        summary_email_frequency: Some(SummaryEmailFrequency::Off.as_str().to_string()),
+        ..Default::default()
+    };
+    database::settings::update(
+        user_id,
+        update,
+        SettingsChangeSource::Unsubscribe,
+        None,
+        pool,
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!("Failed to turn off summary emails for user {}", user_id);
+    })?;
+    state.invalidate_user_settings(user_id).await;
+
+    tracing::info!("User {} unsubscribed from summary emails", user_id);
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::routes::tests::unreachable_state;
+
+    async fn post_unsubscribe(token: &str, body: &'static str) -> StatusCode {
+        let state = unreachable_state();
+
+        get_router(state.clone())
+            .with_state(state)
+            .oneshot(
+                Request::post(format!("/?token={}", token))
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_requires_one_click_body() {
+        let token = unsubscribe::new_token();
+
+        assert_eq!(post_unsubscribe(&token, "").await, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            post_unsubscribe(&token, "List-Unsubscribe=Other").await,
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_rejects_malformed_token() {
+        assert_eq!(
+            post_unsubscribe("not-a-token", "List-Unsubscribe=One-Click").await,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -1,20 +1,59 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use axum::{
     Extension, Json, Router,
-    extract::State,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode, header},
     middleware,
-    response::IntoResponse,
-    routing::{get, post},
+    response::{IntoResponse, Response},
+    routing::{get, patch, post},
 };
+use chrono::Utc;
+use chrono_tz::Tz;
+use serde::Deserialize;
+use serde_json::Value;
 use uuid::Uuid;
 
 use crate::{
     AppState, database,
-    models::{response::Error, settings::UpdateSettings},
-    routes::middlewares::auth_guard,
+    models::{
+        alarm::{EVERY_DAY, NextAlarm, next_alarm, next_trigger},
+        response::{Error, TranslationKey},
+        settings::{DefaultSettings, ResetSettings, UpdateSettings},
+        settings_history::SettingsChangeSource,
+        settings_schema::SettingsDocument,
+    },
+    routes::{
+        extractors::{self, BodyError, JsonBody},
+        middlewares::{auth_guard, write_rate_limit},
+    },
 };
 
+/// Query parameters for settings history listing
+#[derive(Debug, Deserialize)]
+pub struct SettingsHistoryQuery {
+    /// Maximum number of entries to return (default: 20, max: 100)
+    #[serde(default = "default_history_limit")]
+    limit: i64,
+    /// Number of newer entries to skip (default: 0)
+    #[serde(default)]
+    offset: i64,
+}
+
+fn default_history_limit() -> i64 {
+    20
+}
+
+/// Header a client can send its device id in instead of the `device_id` query parameter
+pub const DEVICE_ID_HEADER: &str = "x-device-id";
+
+/// Query parameters for reading the settings
+#[derive(Debug, Deserialize)]
+pub struct SettingsQuery {
+    /// The device to apply the settings overrides of (optional)
+    device_id: Option<Uuid>,
+}
+
 /// Creates a router for the user settings routes
 ///
 /// This function sets up the user settings endpoints and returns a configured Axum router.
@@ -26,15 +65,32 @@ use crate::{
 /// # Returns
 ///
 /// Returns an Axum router configured with the user settings endpoints with auth middleware.
+/// Writes are rate limited per user, reads are not.
 ///
 /// # Routes
 ///
-/// - `GET /{id}` - Retrieve user settings by user ID (protected by auth middleware)
-/// - `POST /update/{id}` - Update user settings by user ID (protected by auth middleware)
+/// - `GET /` - Retrieve the authenticated user's settings (protected by auth middleware)
+/// - `PATCH /` - Partially update the authenticated user's settings (protected by auth middleware)
+/// - `POST /` - Alias of `PATCH /` kept for compatibility (protected by auth middleware)
+/// - `POST /reset` - Reset the authenticated user's settings to the defaults (protected by auth middleware)
+/// - `GET /history` - List the authenticated user's settings changes (protected by auth middleware)
+/// - `GET /alarm/next` - Get the next time one of the user's alarms rings (protected by auth middleware)
+/// - `GET /export` - Export the authenticated user's settings (protected by auth middleware)
+/// - `POST /import` - Import an exported settings document (protected by auth middleware)
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(get_user_settings))
+        .route("/", patch(update_user_settings))
         .route("/", post(update_user_settings))
+        .route("/reset", post(reset_user_settings))
+        .route("/history", get(get_user_settings_history))
+        .route("/alarm/next", get(get_next_alarm))
+        .route("/export", get(export_user_settings))
+        .route("/import", post(import_user_settings))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            write_rate_limit::write_rate_limit,
+        ))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             auth_guard::auth_guard,
@@ -45,22 +101,30 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 /// Retrieves user settings
 ///
 /// This endpoint fetches the settings for a specific user. If no settings exist
-/// for the user, default settings will be created and returned.
+/// for the user, default settings will be created and returned. The `ETag`
+/// header holds the version of the settings for conditional updates.
+///
+/// When a device id is given, in the `device_id` query parameter or the
+/// `X-Device-Id` header, the device's overrides are applied and the names of
+/// the overridden fields are listed in `overridden`.
 ///
 /// # Arguments
 ///
 /// * `id` - The UUID of the user whose settings to retrieve
 /// * `state` - Shared application state
+/// * `headers` - The request headers, with the optional `X-Device-Id` and `Accept-Language`
+/// * `query` - Query parameters with the optional device id
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Settings>)` - The user's settings as JSON
+/// * `Ok(Json<Settings>)` - The user's settings as JSON, with their `ETag`
+/// * `Ok(Json<MergedSettings>)` - The settings with the device's overrides, if a device is given
 /// * `Err(Error)` - Database operation error
 ///
 /// # Example Request
 ///
 /// ```http
-/// GET /user
+/// GET /user?device_id=0b6c9a3e-6a4f-4f51-9c1e-2f7d8e9a0b1c
 /// ```
 ///
 /// # Example Response
@@ -73,54 +137,114 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 ///     "alarm_set": false,
 ///     "alarm_time": "08:00:00",
 ///     "alarm_offset_minutes": 0,
-///     "night_mode": false
+///     "night_mode": true,
+///     "theme": "dark",
+///     "timezone": "UTC",
+///     "date_format": "ISO",
+///     "number_format": "dot-decimal",
+///     "week_starts_on": "Monday",
+///     "budget_period": "Monthly",
+///     "analytics_enabled": true,
+///     "crash_reports_enabled": false,
+///     "summary_email_frequency": "Off",
+///     "summary_email_day": 1,
+///     "currency_symbol_position": "Prefix",
+///     "thousands_separator": "Comma",
+///     "decimal_places_override": null,
+///     "overridden": ["night_mode", "theme"]
 /// }
 /// ```
 async fn get_user_settings(
     Extension(id): Extension<Uuid>,
     State(state): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, Error> {
+    headers: HeaderMap,
+    Query(query): Query<SettingsQuery>,
+) -> Result<Response, Error> {
     tracing::info!("GET /user/{} - Fetching user settings", id);
 
     let pool = state.get_database_pool();
 
     tracing::debug!("Database pool acquired for user {}", id);
 
-    let settings = database::settings::find_by_uuid(id, pool)
+    let defaults = request_defaults(&state, &headers);
+    let settings = state
+        .get_user_settings(id, &defaults)
         .await
         .inspect_err(|_| {
             tracing::error!("Failed to fetch settings for user {}", id);
         })?;
 
-    tracing::info!("Successfully fetched settings for user {}", id);
-    Ok(Json(settings))
+    let etag = [(header::ETAG, settings.etag())];
+    let device_id = query.device_id.or_else(|| {
+        headers
+            .get(DEVICE_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| Uuid::parse_str(value).ok())
+    });
+
+    let Some(device_id) = device_id else {
+        tracing::info!("Successfully fetched settings for user {}", id);
+        return Ok((etag, Json(settings)).into_response());
+    };
+
+    let overrides = database::device_settings::touch(id, device_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!(
+                "Failed to fetch settings of device {} for user {}",
+                device_id,
+                id
+            );
+        })?
+        .map(|device_settings| device_settings.overrides.0)
+        .unwrap_or_default();
+
+    tracing::info!(
+        "Successfully fetched settings of device {} for user {}",
+        device_id,
+        id
+    );
+    Ok((etag, Json(settings.with_device_overrides(&overrides))).into_response())
 }
 
 /// Updates user settings
 ///
 /// This endpoint allows partial updates to user settings. Only the fields
 /// provided in the request body will be updated, leaving other fields unchanged.
+/// Users without settings get the default settings created first, so the
+/// update is never lost. When an `If-Match` header is sent, the update is only
+/// applied if the settings still have that `ETag`; without it the last write wins.
+/// A `null` `decimal_places_override` shows amounts with the currency's decimal
+/// places again.
 ///
 /// # Arguments
 ///
 /// * `id` - The UUID of the user whose settings to update
 /// * `state` - Shared application state
+/// * `headers` - The request headers, with the optional `If-Match` and `Accept-Language`
 /// * `settings` - The settings update data (only non-None fields will be updated)
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Settings>)` - The updated user settings as JSON
-/// * `Err(Error)` - Database operation error
+/// * `Ok((StatusCode, Json<Settings>))` - The full updated settings and their new `ETag`, with
+///   201 if the settings were created by this request and 200 otherwise
+/// * `Err(Error)` - 400 with the invalid `field` as param for an unsupported language, currency,
+///   theme, timezone, format, week start, budget period, summary email frequency, currency symbol
+///   position, thousands separator or decimal places, a summary email day the frequency does not
+///   have or a thousands separator matching the decimal separator, 400 `UNKNOWN_FIELDS` with the
+///   `fields` the service does not support as param, 412 `SETTINGS_MODIFIED` if the settings
+///   changed since the `If-Match` tag was read, or database operation error
 ///
 /// # Example Request
 ///
 /// ```http
-/// POST /user
+/// PATCH /user
 /// Content-Type: application/json
+/// If-Match: "1731240000123456"
 ///
 /// {
 ///     "language": "es",
-///     "night_mode": true,
+///     "theme": "dark",
 ///     "alarm_time": "09:30:00"
 /// }
 /// ```
@@ -135,40 +259,572 @@ async fn get_user_settings(
 ///     "alarm_set": false,
 ///     "alarm_time": "09:30:00",
 ///     "alarm_offset_minutes": 0,
-///     "night_mode": true
+///     "night_mode": true,
+///     "theme": "dark",
+///     "timezone": "UTC",
+///     "date_format": "ISO",
+///     "number_format": "dot-decimal",
+///     "week_starts_on": "Monday",
+///     "budget_period": "Monthly",
+///     "analytics_enabled": true,
+///     "crash_reports_enabled": false,
+///     "summary_email_frequency": "Off",
+///     "summary_email_day": 1,
+///     "currency_symbol_position": "Prefix",
+///     "thousands_separator": "Comma",
+///     "decimal_places_override": null
+/// }
+/// ```
+///
+/// # Example Error Response
+///
+/// ```json
+/// {
+///     "translation_key": "INVALID_CURRENCY",
+///     "params": { "field": "currency" }
 /// }
 /// ```
 async fn update_user_settings(
     Extension(id): Extension<Uuid>,
     State(state): State<Arc<AppState>>,
-    Json(settings): Json<UpdateSettings>,
+    headers: HeaderMap,
+    JsonBody(settings): JsonBody<UpdateSettings>,
 ) -> Result<impl IntoResponse, Error> {
-    tracing::info!("POST /user/update/{} - Updating user settings", id);
+    tracing::info!("PATCH /user - Updating settings for user {}", id);
     tracing::debug!(
-        "Update payload: language={:?}, currency={:?}, alarm_set={:?}, night_mode={:?}",
+        "Update payload: language={:?}, currency={:?}, alarm_set={:?}, night_mode={:?}, theme={:?}, timezone={:?}",
         settings.language,
         settings.currency,
         settings.alarm_set,
-        settings.night_mode
+        settings.night_mode,
+        settings.theme,
+        settings.timezone
     );
 
+    let settings = settings.normalized().map_err(|invalid| -> Error {
+        tracing::warn!(
+            "Invalid {} in settings update for user {}",
+            invalid.field,
+            id
+        );
+        Error::new(StatusCode::BAD_REQUEST, invalid.translation_key)
+            .with_param("field", invalid.field)
+    })?;
+
     let pool = state.get_database_pool();
+    let defaults = &request_defaults(&state, &headers);
 
     tracing::debug!("Database pool acquired for user {}", id);
 
-    database::settings::update(id, settings, pool)
+    let created = database::settings::insert_default(id, defaults, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to create settings for user {}", id);
+        })?
+        > 0;
+
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .and_then(|value| value.to_str().ok());
+
+    database::settings::update(id, settings, SettingsChangeSource::Update, if_match, pool)
         .await
         .inspect_err(|_| {
             tracing::error!("Failed to update settings for user {}", id);
         })?;
+    state.invalidate_user_settings(id).await;
 
     tracing::debug!("Settings updated, fetching updated record for user {}", id);
-    let settings = database::settings::find_by_uuid(id, pool)
+    let settings = state
+        .get_user_settings(id, defaults)
         .await
         .inspect_err(|_| {
             tracing::error!("Failed to fetch updated settings for user {}", id);
         })?;
 
+    let status = if created {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+
     tracing::info!("Successfully updated settings for user {}", id);
+    Ok((status, [(header::ETAG, settings.etag())], Json(settings)))
+}
+
+/// Resets user settings to the defaults
+///
+/// Every field is set back to the configured defaults in a single update. The
+/// settings row is kept, so resetting twice gives the same result.
+///
+/// # Arguments
+///
+/// * `id` - The UUID of the user whose settings to reset
+/// * `state` - Shared application state
+/// * `reset` - The reset request, must confirm the reset
+///
+/// # Returns
+///
+/// * `Ok(Json<Settings>)` - The reset user settings as JSON
+/// * `Err(Error)` - Reset not confirmed, or database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// POST /user/reset
+/// Content-Type: application/json
+///
+/// {
+///     "confirm": true
+/// }
+/// ```
+async fn reset_user_settings(
+    Extension(id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(reset): Json<ResetSettings>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("POST /user/reset - Resetting settings for user {}", id);
+
+    if !reset.confirm {
+        tracing::warn!("Settings reset for user {} was not confirmed", id);
+        return Err((StatusCode::BAD_REQUEST, TranslationKey::ResetNotConfirmed).into());
+    }
+
+    let pool = state.get_database_pool();
+    let defaults = &state.config.default_settings;
+
+    database::settings::update(
+        id,
+        defaults.as_update(),
+        SettingsChangeSource::Reset,
+        None,
+        pool,
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!("Failed to reset settings for user {}", id);
+    })?;
+    state.invalidate_user_settings(id).await;
+
+    let settings = state
+        .get_user_settings(id, defaults)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch reset settings for user {}", id);
+        })?;
+
+    tracing::info!("Successfully reset settings for user {}", id);
+    Ok(Json(settings))
+}
+
+/// Lists the changes made to the user's settings
+///
+/// Every update or reset that changed at least one field is recorded with the
+/// old and new values of the changed fields. Entries are returned newest first.
+///
+/// # Arguments
+///
+/// * `id` - The UUID of the authenticated user
+/// * `state` - Shared application state
+/// * `query` - Query parameters with optional limit (default: 20, max: 100) and offset
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<SettingsHistoryEntry>>)` - The history entries
+/// * `Err(Error)` - Database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// GET /user/history?limit=20&offset=0
+/// ```
+///
+/// # Example Response
+///
+/// ```json
+/// [
+///     {
+///         "id": "7a1f8e2c-3b4d-4e5f-8a9b-0c1d2e3f4a5b",
+///         "changes": {
+///             "currency": { "old": "usd", "new": "eur" }
+///         },
+///         "source": "update",
+///         "changed_at": "2025-11-10T12:00:00Z"
+///     }
+/// ]
+/// ```
+async fn get_user_settings_history(
+    Extension(id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SettingsHistoryQuery>,
+) -> Result<impl IntoResponse, Error> {
+    // Cap the limit to a maximum of 100 to prevent abuse
+    let limit = query.limit.clamp(1, 100);
+    let offset = query.offset.max(0);
+
+    tracing::info!(
+        "GET /user/history - Fetching {} history entries for user {}",
+        limit,
+        id
+    );
+
+    let history =
+        database::settings_history::find_by_user_id(id, limit, offset, state.get_database_pool())
+            .await
+            .inspect_err(|_| {
+                tracing::error!("Failed to fetch settings history for user {}", id);
+            })?;
+
+    Ok(Json(history))
+}
+
+/// Computes when the user's next alarm rings
+///
+/// The alarm times are wall-clock times in the user's timezone setting, so
+/// the trigger follows DST changes. Alarms that already rang today roll over
+/// to their next day. Users without alarms in the alarm list get the legacy
+/// alarm of their settings, which rings every day.
+///
+/// # Arguments
+///
+/// * `id` - The UUID of the authenticated user
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<Option<NextAlarm>>)` - The next alarm, or `null` if every alarm is disabled
+/// * `Err(Error)` - Database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// GET /user/alarm/next
+/// ```
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///     "alarm_id": "7a1f8e2c-3b4d-4e5f-8a9b-0c1d2e3f4a5b",
+///     "trigger_at": "2025-11-11T07:00:00Z",
+///     "local_time": "2025-11-11T08:00:00+01:00"
+/// }
+/// ```
+async fn get_next_alarm(
+    Extension(id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /user/alarm/next - Computing next alarm for user {}",
+        id
+    );
+
+    let pool = state.get_database_pool();
+
+    let settings = state
+        .get_user_settings(id, &state.config.default_settings)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch settings for user {}", id);
+        })?;
+    let alarms = database::alarm::find_all_by_user(id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch alarms for user {}", id);
+        })?;
+
+    // Stored timezones are validated on write, UTC only guards against manual edits
+    let timezone = Tz::from_str(settings.timezone()).unwrap_or(Tz::UTC);
+    let now = Utc::now();
+
+    let next = if alarms.is_empty() {
+        settings
+            .alarm_set()
+            .then(|| {
+                next_trigger(
+                    settings.alarm_time(),
+                    settings.alarm_offset_minutes(),
+                    EVERY_DAY,
+                    timezone,
+                    now,
+                )
+            })
+            .flatten()
+            .map(|trigger_at| NextAlarm::new(None, trigger_at, timezone))
+    } else {
+        next_alarm(&alarms, timezone, now)
+    };
+
+    Ok(Json(next))
+}
+
+/// Exports the user's settings as a versioned document
+///
+/// # Arguments
+///
+/// * `id` - The UUID of the authenticated user
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<SettingsDocument>)` - The export document as a JSON attachment
+/// * `Err(Error)` - Database operation error
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///     "schema_version": 6,
+///     "exported_at": "2025-11-10T12:00:00Z",
+///     "settings": {
+///         "language": "en",
+///         "currency": "usd",
+///         "timezone": "UTC",
+///         "budget_period": "Monthly"
+///     }
+/// }
+/// ```
+async fn export_user_settings(
+    Extension(id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("GET /user/export - Exporting settings for user {}", id);
+
+    let settings = state
+        .get_user_settings(id, &state.config.default_settings)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch settings for user {}", id);
+        })?;
+
+    let Ok(Value::Object(fields)) = serde_json::to_value(&settings) else {
+        tracing::error!("Failed to serialize settings for user {}", id);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            TranslationKey::InternalServerError,
+        )
+            .into());
+    };
+
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"brewget-settings.json\"",
+        )],
+        Json(SettingsDocument::export(fields)),
+    ))
+}
+
+/// Imports an exported settings document
+///
+/// Documents of older schema versions are upgraded to the current schema
+/// first. The imported fields are validated and applied like a partial
+/// update, fields missing from the document are left unchanged.
+///
+/// # Arguments
+///
+/// * `id` - The UUID of the authenticated user
+/// * `state` - Shared application state
+/// * `document` - The settings document to import
+///
+/// # Returns
+///
+/// * `Ok(Json<Settings>)` - The settings after the import
+/// * `Err(Error)` - 400 for a newer or unknown schema version, an unreadable document or an
+///   unsupported value (with the invalid `field` as param), 400 `UNKNOWN_FIELDS` for settings
+///   the service does not support (with the `fields` as param), or database operation error
+///
+/// # Example Request
+///
+/// ```http
+/// POST /user/import
+/// Content-Type: application/json
+///
+/// {
+///     "schema_version": 1,
+///     "settings": {
+///         "language": "es",
+///         "currency": "eur",
+///         "night_mode": true
+///     }
+/// }
+/// ```
+async fn import_user_settings(
+    Extension(id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    JsonBody(document): JsonBody<SettingsDocument>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "POST /user/import - Importing settings version {} for user {}",
+        document.schema_version,
+        id
+    );
+
+    let fields = document.upgraded().map_err(|translation_key| -> Error {
+        tracing::warn!("Unsupported settings document for user {}", id);
+        (StatusCode::BAD_REQUEST, translation_key).into()
+    })?;
+
+    let settings = extractors::from_value::<UpdateSettings>(Value::Object(fields))
+        .map_err(|e| -> Error {
+            match e {
+                BodyError::UnknownFields(fields) => {
+                    tracing::warn!(
+                        "Unknown fields {:?} in settings import for user {}",
+                        fields,
+                        id
+                    );
+                    extractors::unknown_fields_error(fields)
+                }
+                BodyError::Invalid(_) => {
+                    tracing::warn!("Unreadable settings document for user {}", id);
+                    (
+                        StatusCode::BAD_REQUEST,
+                        TranslationKey::InvalidSettingsDocument,
+                    )
+                        .into()
+                }
+            }
+        })?
+        .normalized()
+        .map_err(|invalid| -> Error {
+            tracing::warn!(
+                "Invalid {} in settings import for user {}",
+                invalid.field,
+                id
+            );
+            Error::new(StatusCode::BAD_REQUEST, invalid.translation_key)
+                .with_param("field", invalid.field)
+        })?;
+
+    let pool = state.get_database_pool();
+    let defaults = &state.config.default_settings;
+
+    database::settings::insert_default(id, defaults, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to create settings for user {}", id);
+        })?;
+
+    database::settings::update(id, settings, SettingsChangeSource::Update, None, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to import settings for user {}", id);
+        })?;
+    state.invalidate_user_settings(id).await;
+
+    let settings = state
+        .get_user_settings(id, defaults)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch imported settings for user {}", id);
+        })?;
+
+    tracing::info!("Successfully imported settings for user {}", id);
     Ok(Json(settings))
 }
+
+/// Returns the defaults for a settings row created by a request
+///
+/// The language falls back to the request's `Accept-Language` header, an
+/// existing row is never changed by it.
+fn request_defaults(state: &AppState, headers: &HeaderMap) -> DefaultSettings {
+    let accept_language = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok());
+
+    state
+        .config
+        .default_settings
+        .with_accept_language(accept_language)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use shared_types::identity::{INTERNAL_SECRET_HEADER, USER_ID_HEADER};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{models::settings::Settings, routes::tests::unreachable_state_with};
+
+    fn settings(user_id: Uuid) -> Settings {
+        serde_json::from_value(serde_json::json!({
+            "user_id": user_id,
+            "language": "en",
+            "currency": "usd",
+            "alarm_set": false,
+            "alarm_time": "07:00:00",
+            "alarm_offset_minutes": 0,
+            "night_mode": false,
+            "theme": "light",
+            "timezone": "UTC",
+            "date_format": "ISO",
+            "number_format": "dot-decimal",
+            "week_starts_on": "Monday",
+            "budget_period": "Monthly",
+            "analytics_enabled": true,
+            "crash_reports_enabled": false,
+            "summary_email_frequency": "Off",
+            "summary_email_day": 1,
+            "currency_symbol_position": "Prefix",
+            "thousands_separator": "Comma",
+            "schema_version": 3,
+            "updated_at": "2025-11-10T12:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    fn request(method: &str, user_id: Uuid, body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri("/")
+            .header(USER_ID_HEADER, user_id.to_string())
+            .header(INTERNAL_SECRET_HEADER, "secret")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_writes_over_the_limit_are_rate_limited_but_reads_are_not() {
+        let state = unreachable_state_with(|config| {
+            config.settings_writes_per_minute = 1;
+            config.settings_cache_ttl_seconds = 60;
+        });
+        let user_id = Uuid::new_v4();
+        // Reads are answered from the cache, as the database cannot be reached
+        state
+            .get_settings_cache()
+            .get_or_load(user_id, || async { Ok(settings(user_id)) })
+            .await
+            .unwrap();
+        let app = get_router(state.clone()).with_state(state);
+
+        let first = app
+            .clone()
+            .oneshot(request("POST", user_id, r#"{"night_mode": true}"#))
+            .await
+            .unwrap();
+        assert_ne!(first.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let second = app
+            .clone()
+            .oneshot(request("POST", user_id, r#"{"night_mode": true}"#))
+            .await
+            .unwrap();
+        assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(second.headers().contains_key(header::RETRY_AFTER));
+        let body = axum::body::to_bytes(second.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["translation_key"], "SETTINGS_WRITE_RATE_LIMITED");
+
+        let read = app.oneshot(request("GET", user_id, "")).await.unwrap();
+        assert_eq!(read.status(), StatusCode::OK);
+    }
+}
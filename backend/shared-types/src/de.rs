@@ -0,0 +1,35 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a nullable field, so a `null` value is told apart from a missing field
+///
+/// Used with `#[serde(default)]`: a missing field is `None`, `null` is
+/// `Some(None)` and clears the stored value.
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Update {
+        #[serde(default, deserialize_with = "nullable")]
+        limit: Option<Option<i32>>,
+    }
+
+    #[test]
+    fn test_nullable_tells_null_and_missing_apart() {
+        let missing: Update = serde_json::from_str("{}").unwrap();
+        let null: Update = serde_json::from_str(r#"{ "limit": null }"#).unwrap();
+        let value: Update = serde_json::from_str(r#"{ "limit": 5 }"#).unwrap();
+
+        assert_eq!(missing.limit, None);
+        assert_eq!(null.limit, Some(None));
+        assert_eq!(value.limit, Some(Some(5)));
+    }
+}
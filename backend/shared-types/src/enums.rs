@@ -1,7 +1,10 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 /// Supported currencies in the application
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Currency {
     /// United States Dollar
@@ -48,6 +51,52 @@ impl Currency {
             Currency::Ron,
         ]
     }
+
+    /// Returns the number of minor unit decimal places used by the currency
+    ///
+    /// Most currencies use cents (2 decimals), while the Japanese Yen has no
+    /// minor unit in everyday use.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Currency::Jpy => 0,
+            Currency::Usd | Currency::Eur | Currency::Gbp | Currency::Cad | Currency::Ron => 2,
+        }
+    }
+
+    /// Returns the symbol amounts in the currency are displayed with
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Usd => "$",
+            Currency::Eur => "€",
+            Currency::Gbp => "£",
+            Currency::Cad => "CA$",
+            Currency::Jpy => "¥",
+            Currency::Ron => "lei",
+        }
+    }
+
+    /// Returns where the symbol goes by default for amounts in the currency
+    pub fn default_symbol_position(&self) -> CurrencySymbolPosition {
+        match self {
+            Currency::Eur | Currency::Ron => CurrencySymbolPosition::Suffix,
+            Currency::Usd | Currency::Gbp | Currency::Cad | Currency::Jpy => {
+                CurrencySymbolPosition::Prefix
+            }
+        }
+    }
+
+    /// Returns how thousands are separated by default for amounts in the currency
+    ///
+    /// Currencies written with a dot between thousands locally default to a
+    /// space, so the default never clashes with either decimal separator.
+    pub fn default_thousands_separator(&self) -> ThousandsSeparator {
+        match self {
+            Currency::Eur | Currency::Ron => ThousandsSeparator::Space,
+            Currency::Usd | Currency::Gbp | Currency::Cad | Currency::Jpy => {
+                ThousandsSeparator::Comma
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for Currency {
@@ -56,6 +105,19 @@ impl std::fmt::Display for Currency {
     }
 }
 
+impl FromStr for Currency {
+    type Err = String;
+
+    /// Parses a currency code case-insensitively (e.g. "EUR" or "eur")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::all()
+            .iter()
+            .find(|currency| currency.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported currency: {}", s))
+    }
+}
+
 /// Supported languages in the application
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -99,6 +161,40 @@ impl Language {
             Language::Ro,
         ]
     }
+
+    /// Picks the preferred supported language from an `Accept-Language` header
+    ///
+    /// Languages are ranked by their quality value, regional variants match
+    /// their base language (e.g. "ro-RO" matches "ro").
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The header value (e.g. "ro-RO,ro;q=0.9,en;q=0.8")
+    ///
+    /// # Returns
+    ///
+    /// The supported language with the highest quality, or `None` if none is supported
+    pub fn from_accept_language(header: &str) -> Option<Language> {
+        header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                let language = Language::from_str(tag.split('-').next()?).ok()?;
+                (quality > 0.0).then_some((language, quality))
+            })
+            .fold(
+                None,
+                |best: Option<(Language, f32)>, (language, quality)| match best {
+                    Some((_, best_quality)) if best_quality >= quality => best,
+                    _ => Some((language, quality)),
+                },
+            )
+            .map(|(language, _)| language)
+    }
 }
 
 impl std::fmt::Display for Language {
@@ -107,6 +203,457 @@ impl std::fmt::Display for Language {
     }
 }
 
+impl FromStr for Language {
+    type Err = String;
+
+    /// Parses a language code case-insensitively (e.g. "en" or "EN")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::all()
+            .iter()
+            .find(|language| language.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported language: {}", s))
+    }
+}
+
+/// Supported date format preferences
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DateFormat {
+    /// Year-month-day, e.g. 2025-12-31
+    #[default]
+    #[serde(rename = "ISO")]
+    Iso,
+    /// Day/month/year, e.g. 31/12/2025
+    #[serde(rename = "DMY")]
+    Dmy,
+    /// Month/day/year, e.g. 12/31/2025
+    #[serde(rename = "MDY")]
+    Mdy,
+}
+
+impl DateFormat {
+    /// Returns the date format as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateFormat::Iso => "ISO",
+            DateFormat::Dmy => "DMY",
+            DateFormat::Mdy => "MDY",
+        }
+    }
+
+    /// Returns all supported date formats
+    pub fn all() -> &'static [DateFormat] {
+        &[DateFormat::Iso, DateFormat::Dmy, DateFormat::Mdy]
+    }
+}
+
+impl std::fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DateFormat {
+    type Err = String;
+
+    /// Parses a date format case-insensitively (e.g. "DMY" or "dmy")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DateFormat::all()
+            .iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported date format: {}", s))
+    }
+}
+
+/// Supported number format preferences
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum NumberFormat {
+    /// Dot as decimal separator, e.g. 1,234.56
+    #[default]
+    DotDecimal,
+    /// Comma as decimal separator, e.g. 1.234,56
+    CommaDecimal,
+}
+
+impl NumberFormat {
+    /// Returns the number format as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberFormat::DotDecimal => "dot-decimal",
+            NumberFormat::CommaDecimal => "comma-decimal",
+        }
+    }
+
+    /// Returns all supported number formats
+    pub fn all() -> &'static [NumberFormat] {
+        &[NumberFormat::DotDecimal, NumberFormat::CommaDecimal]
+    }
+
+    /// Returns the character between the whole and the fractional part
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            NumberFormat::DotDecimal => '.',
+            NumberFormat::CommaDecimal => ',',
+        }
+    }
+}
+
+impl std::fmt::Display for NumberFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for NumberFormat {
+    type Err = String;
+
+    /// Parses a number format case-insensitively (e.g. "comma-decimal")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NumberFormat::all()
+            .iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported number format: {}", s))
+    }
+}
+
+/// Supported positions of the currency symbol around an amount
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CurrencySymbolPosition {
+    /// Before the amount, e.g. $10.00
+    #[default]
+    Prefix,
+    /// After the amount, e.g. 10.00 €
+    Suffix,
+}
+
+impl CurrencySymbolPosition {
+    /// Returns the currency symbol position as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CurrencySymbolPosition::Prefix => "Prefix",
+            CurrencySymbolPosition::Suffix => "Suffix",
+        }
+    }
+
+    /// Returns all supported currency symbol positions
+    pub fn all() -> &'static [CurrencySymbolPosition] {
+        &[
+            CurrencySymbolPosition::Prefix,
+            CurrencySymbolPosition::Suffix,
+        ]
+    }
+}
+
+impl std::fmt::Display for CurrencySymbolPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CurrencySymbolPosition {
+    type Err = String;
+
+    /// Parses a currency symbol position case-insensitively (e.g. "Prefix" or "suffix")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CurrencySymbolPosition::all()
+            .iter()
+            .find(|position| position.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported currency symbol position: {}", s))
+    }
+}
+
+/// Supported separators between groups of thousands
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ThousandsSeparator {
+    /// A comma, e.g. 1,234
+    #[default]
+    Comma,
+    /// A dot, e.g. 1.234
+    Dot,
+    /// A space, e.g. 1 234
+    Space,
+    /// No separator, e.g. 1234
+    None,
+}
+
+impl ThousandsSeparator {
+    /// Returns the thousands separator as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThousandsSeparator::Comma => "Comma",
+            ThousandsSeparator::Dot => "Dot",
+            ThousandsSeparator::Space => "Space",
+            ThousandsSeparator::None => "None",
+        }
+    }
+
+    /// Returns all supported thousands separators
+    pub fn all() -> &'static [ThousandsSeparator] {
+        &[
+            ThousandsSeparator::Comma,
+            ThousandsSeparator::Dot,
+            ThousandsSeparator::Space,
+            ThousandsSeparator::None,
+        ]
+    }
+
+    /// Returns the character between groups of thousands, `None` for no separator
+    pub fn as_char(&self) -> Option<char> {
+        match self {
+            ThousandsSeparator::Comma => Some(','),
+            ThousandsSeparator::Dot => Some('.'),
+            ThousandsSeparator::Space => Some(' '),
+            ThousandsSeparator::None => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ThousandsSeparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ThousandsSeparator {
+    type Err = String;
+
+    /// Parses a thousands separator case-insensitively (e.g. "Space" or "dot")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ThousandsSeparator::all()
+            .iter()
+            .find(|separator| separator.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported thousands separator: {}", s))
+    }
+}
+
+/// Supported first days of the week
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum WeekStart {
+    /// Weeks run Monday to Sunday
+    #[default]
+    Monday,
+    /// Weeks run Sunday to Saturday
+    Sunday,
+}
+
+impl WeekStart {
+    /// Returns the first day of the week as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeekStart::Monday => "Monday",
+            WeekStart::Sunday => "Sunday",
+        }
+    }
+
+    /// Returns all supported first days of the week
+    pub fn all() -> &'static [WeekStart] {
+        &[WeekStart::Monday, WeekStart::Sunday]
+    }
+
+    /// Returns the first day of the week containing a date
+    ///
+    /// Used to bucket dates into weeks for weekly aggregations.
+    ///
+    /// # Arguments
+    /// * `date` - Any date of the week
+    ///
+    /// # Returns
+    /// The date the week starts on
+    pub fn week_of(&self, date: NaiveDate) -> NaiveDate {
+        let days_since_start = match self {
+            WeekStart::Monday => date.weekday().num_days_from_monday(),
+            WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+        };
+
+        date - Days::new(days_since_start.into())
+    }
+}
+
+impl std::fmt::Display for WeekStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WeekStart {
+    type Err = String;
+
+    /// Parses a day name case-insensitively (e.g. "Monday" or "sunday")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WeekStart::all()
+            .iter()
+            .find(|week_start| week_start.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported first day of the week: {}", s))
+    }
+}
+
+/// Supported budget periods
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BudgetPeriod {
+    /// Budgets reset every week
+    Weekly,
+    /// Budgets reset every month
+    #[default]
+    Monthly,
+}
+
+impl BudgetPeriod {
+    /// Returns the budget period as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetPeriod::Weekly => "Weekly",
+            BudgetPeriod::Monthly => "Monthly",
+        }
+    }
+
+    /// Returns all supported budget periods
+    pub fn all() -> &'static [BudgetPeriod] {
+        &[BudgetPeriod::Weekly, BudgetPeriod::Monthly]
+    }
+}
+
+impl std::fmt::Display for BudgetPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for BudgetPeriod {
+    type Err = String;
+
+    /// Parses a budget period case-insensitively (e.g. "Monthly" or "weekly")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BudgetPeriod::all()
+            .iter()
+            .find(|period| period.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported budget period: {}", s))
+    }
+}
+
+/// Supported frequencies of the summary emails
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SummaryEmailFrequency {
+    /// No summary emails are sent
+    #[default]
+    Off,
+    /// A summary is sent every week
+    Weekly,
+    /// A summary is sent every month
+    Monthly,
+}
+
+impl SummaryEmailFrequency {
+    /// Returns the summary email frequency as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SummaryEmailFrequency::Off => "Off",
+            SummaryEmailFrequency::Weekly => "Weekly",
+            SummaryEmailFrequency::Monthly => "Monthly",
+        }
+    }
+
+    /// Returns all supported summary email frequencies
+    pub fn all() -> &'static [SummaryEmailFrequency] {
+        &[
+            SummaryEmailFrequency::Off,
+            SummaryEmailFrequency::Weekly,
+            SummaryEmailFrequency::Monthly,
+        ]
+    }
+}
+
+impl std::fmt::Display for SummaryEmailFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for SummaryEmailFrequency {
+    type Err = String;
+
+    /// Parses a summary email frequency case-insensitively (e.g. "Weekly" or "off")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SummaryEmailFrequency::all()
+            .iter()
+            .find(|frequency| frequency.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported summary email frequency: {}", s))
+    }
+}
+
+/// Supported color themes of the application
+///
+/// Replaces the `night_mode` flag of the settings: `night_mode` is true
+/// exactly when the theme is `Dark`. `System` follows the device and is
+/// reported as `night_mode = false`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Light colors
+    #[default]
+    Light,
+    /// Dark colors
+    Dark,
+    /// Follows the color scheme of the device
+    System,
+}
+
+impl Theme {
+    /// Returns the theme as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    /// Returns all supported themes
+    pub fn all() -> &'static [Theme] {
+        &[Theme::Light, Theme::Dark, Theme::System]
+    }
+
+    /// Returns the theme written by clients that only know `night_mode`
+    pub fn from_night_mode(night_mode: bool) -> Self {
+        if night_mode {
+            Theme::Dark
+        } else {
+            Theme::Light
+        }
+    }
+
+    /// Returns the `night_mode` value reported for the theme
+    pub fn night_mode(&self) -> bool {
+        *self == Theme::Dark
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    /// Parses a theme case-insensitively (e.g. "dark" or "Dark")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::all()
+            .iter()
+            .find(|theme| theme.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unsupported theme: {}", s))
+    }
+}
+
 /// Supported wallet types in the application
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum WalletType {
@@ -145,6 +692,45 @@ impl WalletType {
             WalletType::Loan,
         ]
     }
+
+    /// Returns the name of the wallet type shown to users in a language
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the name
+    ///
+    /// # Returns
+    ///
+    /// The localized display name (e.g. "Credit Card" or "Tarjeta de crédito")
+    pub fn display_name(&self, language: Language) -> &'static str {
+        match (self, language) {
+            (WalletType::Account, Language::En) => "Account",
+            (WalletType::Account, Language::Es) => "Cuenta",
+            (WalletType::Account, Language::Fr) => "Compte",
+            (WalletType::Account, Language::De) => "Konto",
+            (WalletType::Account, Language::Ro) => "Cont",
+            (WalletType::Savings, Language::En) => "Savings",
+            (WalletType::Savings, Language::Es) => "Ahorros",
+            (WalletType::Savings, Language::Fr) => "Épargne",
+            (WalletType::Savings, Language::De) => "Sparkonto",
+            (WalletType::Savings, Language::Ro) => "Economii",
+            (WalletType::Deposit, Language::En) => "Deposit",
+            (WalletType::Deposit, Language::Es) => "Depósito",
+            (WalletType::Deposit, Language::Fr) => "Dépôt",
+            (WalletType::Deposit, Language::De) => "Festgeld",
+            (WalletType::Deposit, Language::Ro) => "Depozit",
+            (WalletType::CreditCard, Language::En) => "Credit Card",
+            (WalletType::CreditCard, Language::Es) => "Tarjeta de crédito",
+            (WalletType::CreditCard, Language::Fr) => "Carte de crédit",
+            (WalletType::CreditCard, Language::De) => "Kreditkarte",
+            (WalletType::CreditCard, Language::Ro) => "Card de credit",
+            (WalletType::Loan, Language::En) => "Loan",
+            (WalletType::Loan, Language::Es) => "Préstamo",
+            (WalletType::Loan, Language::Fr) => "Prêt",
+            (WalletType::Loan, Language::De) => "Kredit",
+            (WalletType::Loan, Language::Ro) => "Împrumut",
+        }
+    }
 }
 
 impl std::fmt::Display for WalletType {
@@ -194,6 +780,23 @@ mod tests {
         assert!(all.contains(&Currency::Eur));
     }
 
+    #[test]
+    fn test_currency_from_str() {
+        assert_eq!(Currency::from_str("USD"), Ok(Currency::Usd));
+        assert_eq!(Currency::from_str("eur"), Ok(Currency::Eur));
+        assert_eq!(Currency::from_str("Ron"), Ok(Currency::Ron));
+        assert!(Currency::from_str("XYZ").is_err());
+        assert!(Currency::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_currency_decimal_places() {
+        assert_eq!(Currency::Usd.decimal_places(), 2);
+        assert_eq!(Currency::Eur.decimal_places(), 2);
+        assert_eq!(Currency::Ron.decimal_places(), 2);
+        assert_eq!(Currency::Jpy.decimal_places(), 0);
+    }
+
     #[test]
     fn test_language_serialization() {
         let en = Language::En;
@@ -216,6 +819,14 @@ mod tests {
         assert_eq!(language, Language::Es);
     }
 
+    #[test]
+    fn test_language_from_str() {
+        assert_eq!(Language::from_str("en"), Ok(Language::En));
+        assert_eq!(Language::from_str("RO"), Ok(Language::Ro));
+        assert!(Language::from_str("klingon").is_err());
+        assert!(Language::from_str("").is_err());
+    }
+
     #[test]
     fn test_language_display() {
         assert_eq!(Language::En.to_string(), "en");
@@ -273,4 +884,233 @@ mod tests {
         let default = WalletType::default();
         assert_eq!(default, WalletType::Account);
     }
+
+    #[test]
+    fn test_date_format_serialization() {
+        for (format, expected) in [
+            (DateFormat::Iso, r#""ISO""#),
+            (DateFormat::Dmy, r#""DMY""#),
+            (DateFormat::Mdy, r#""MDY""#),
+        ] {
+            assert_eq!(serde_json::to_string(&format).unwrap(), expected);
+            assert_eq!(
+                serde_json::from_str::<DateFormat>(expected).unwrap(),
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_format_from_str() {
+        assert_eq!(DateFormat::from_str("dmy"), Ok(DateFormat::Dmy));
+        assert!(DateFormat::from_str("YMD").is_err());
+        assert_eq!(DateFormat::default(), DateFormat::Iso);
+    }
+
+    #[test]
+    fn test_number_format_serialization() {
+        for (format, expected) in [
+            (NumberFormat::DotDecimal, r#""dot-decimal""#),
+            (NumberFormat::CommaDecimal, r#""comma-decimal""#),
+        ] {
+            assert_eq!(serde_json::to_string(&format).unwrap(), expected);
+            assert_eq!(
+                serde_json::from_str::<NumberFormat>(expected).unwrap(),
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_format_from_str() {
+        assert_eq!(
+            NumberFormat::from_str("comma-decimal"),
+            Ok(NumberFormat::CommaDecimal)
+        );
+        assert!(NumberFormat::from_str("space-decimal").is_err());
+        assert_eq!(NumberFormat::default(), NumberFormat::DotDecimal);
+    }
+
+    #[test]
+    fn test_week_start_serialization() {
+        assert_eq!(
+            serde_json::to_string(&WeekStart::Monday).unwrap(),
+            r#""Monday""#
+        );
+        assert_eq!(
+            serde_json::from_str::<WeekStart>(r#""Sunday""#).unwrap(),
+            WeekStart::Sunday
+        );
+        assert_eq!(WeekStart::from_str("sunday"), Ok(WeekStart::Sunday));
+        assert!(WeekStart::from_str("Friday").is_err());
+        assert_eq!(WeekStart::default(), WeekStart::Monday);
+    }
+
+    #[test]
+    fn test_budget_period_serialization() {
+        assert_eq!(
+            serde_json::to_string(&BudgetPeriod::Monthly).unwrap(),
+            r#""Monthly""#
+        );
+        assert_eq!(
+            serde_json::from_str::<BudgetPeriod>(r#""Weekly""#).unwrap(),
+            BudgetPeriod::Weekly
+        );
+        assert_eq!(BudgetPeriod::from_str("weekly"), Ok(BudgetPeriod::Weekly));
+        assert!(BudgetPeriod::from_str("Yearly").is_err());
+        assert_eq!(BudgetPeriod::default(), BudgetPeriod::Monthly);
+    }
+
+    #[test]
+    fn test_currency_display_defaults() {
+        assert_eq!(Currency::Eur.symbol(), "€");
+        assert_eq!(
+            Currency::Usd.default_symbol_position(),
+            CurrencySymbolPosition::Prefix
+        );
+        assert_eq!(
+            Currency::Eur.default_symbol_position(),
+            CurrencySymbolPosition::Suffix
+        );
+        assert_eq!(
+            Currency::Jpy.default_thousands_separator(),
+            ThousandsSeparator::Comma
+        );
+        assert_eq!(
+            Currency::Ron.default_thousands_separator(),
+            ThousandsSeparator::Space
+        );
+    }
+
+    #[test]
+    fn test_currency_symbol_position_serialization() {
+        assert_eq!(
+            serde_json::to_string(&CurrencySymbolPosition::Suffix).unwrap(),
+            r#""Suffix""#
+        );
+        assert_eq!(
+            CurrencySymbolPosition::from_str("prefix"),
+            Ok(CurrencySymbolPosition::Prefix)
+        );
+        assert!(CurrencySymbolPosition::from_str("Infix").is_err());
+    }
+
+    #[test]
+    fn test_thousands_separator_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ThousandsSeparator::None).unwrap(),
+            r#""None""#
+        );
+        assert_eq!(
+            serde_json::from_str::<ThousandsSeparator>(r#""Space""#).unwrap(),
+            ThousandsSeparator::Space
+        );
+        assert_eq!(
+            ThousandsSeparator::from_str("dot"),
+            Ok(ThousandsSeparator::Dot)
+        );
+        assert!(ThousandsSeparator::from_str("Apostrophe").is_err());
+        assert_eq!(ThousandsSeparator::None.as_char(), None);
+    }
+
+    #[test]
+    fn test_summary_email_frequency_serialization() {
+        assert_eq!(
+            serde_json::to_string(&SummaryEmailFrequency::Weekly).unwrap(),
+            r#""Weekly""#
+        );
+        assert_eq!(
+            serde_json::from_str::<SummaryEmailFrequency>(r#""Monthly""#).unwrap(),
+            SummaryEmailFrequency::Monthly
+        );
+        assert_eq!(
+            SummaryEmailFrequency::from_str("off"),
+            Ok(SummaryEmailFrequency::Off)
+        );
+        assert!(SummaryEmailFrequency::from_str("Daily").is_err());
+        assert_eq!(SummaryEmailFrequency::default(), SummaryEmailFrequency::Off);
+    }
+
+    #[test]
+    fn test_theme_serialization() {
+        assert_eq!(serde_json::to_string(&Theme::Dark).unwrap(), r#""dark""#);
+        assert_eq!(
+            serde_json::from_str::<Theme>(r#""system""#).unwrap(),
+            Theme::System
+        );
+        assert!(serde_json::from_str::<Theme>(r#""sepia""#).is_err());
+        assert_eq!(Theme::from_str("Light"), Ok(Theme::Light));
+        assert!(Theme::from_str("sepia").is_err());
+        assert_eq!(Theme::default(), Theme::Light);
+    }
+
+    #[test]
+    fn test_theme_night_mode_mapping() {
+        assert_eq!(Theme::from_night_mode(true), Theme::Dark);
+        assert_eq!(Theme::from_night_mode(false), Theme::Light);
+        assert!(Theme::Dark.night_mode());
+        assert!(!Theme::Light.night_mode());
+        assert!(!Theme::System.night_mode());
+    }
+
+    #[test]
+    fn test_week_start_buckets_across_sunday() {
+        // Saturday 2025-11-08, Sunday 2025-11-09 and Monday 2025-11-10
+        let dates = [
+            NaiveDate::from_ymd_opt(2025, 11, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 9).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 10).unwrap(),
+        ];
+
+        let monday_weeks: Vec<_> = dates
+            .iter()
+            .map(|d| WeekStart::Monday.week_of(*d))
+            .collect();
+        let sunday_weeks: Vec<_> = dates
+            .iter()
+            .map(|d| WeekStart::Sunday.week_of(*d))
+            .collect();
+
+        // Monday weeks put Saturday and Sunday together, Monday starts a new week
+        let nov_3 = NaiveDate::from_ymd_opt(2025, 11, 3).unwrap();
+        assert_eq!(monday_weeks, vec![nov_3, nov_3, dates[2]]);
+
+        // Sunday weeks put Sunday and Monday together, Saturday ends the previous week
+        let nov_2 = NaiveDate::from_ymd_opt(2025, 11, 2).unwrap();
+        assert_eq!(sunday_weeks, vec![nov_2, dates[1], dates[1]]);
+    }
+
+    #[test]
+    fn test_language_from_accept_language() {
+        assert_eq!(
+            Language::from_accept_language("ro-RO,ro;q=0.9,en;q=0.8"),
+            Some(Language::Ro)
+        );
+        assert_eq!(
+            Language::from_accept_language("ja;q=0.9, de;q=0.5, fr;q=0.7"),
+            Some(Language::Fr)
+        );
+        assert_eq!(
+            Language::from_accept_language("en;q=0, es;q=0.1"),
+            Some(Language::Es)
+        );
+        assert_eq!(Language::from_accept_language("ja, zh-CN"), None);
+        assert_eq!(Language::from_accept_language(""), None);
+    }
+
+    #[test]
+    fn test_wallet_type_display_name() {
+        assert_eq!(
+            WalletType::CreditCard.display_name(Language::En),
+            "Credit Card"
+        );
+        assert_eq!(WalletType::Savings.display_name(Language::Ro), "Economii");
+
+        // Every wallet type has a name in every language
+        for wallet_type in WalletType::all() {
+            for language in Language::all() {
+                assert!(!wallet_type.display_name(*language).is_empty());
+            }
+        }
+    }
 }
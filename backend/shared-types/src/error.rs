@@ -13,10 +13,12 @@ use crate::response::{TranslationKey, TranslationKeyMessage};
 /// # Fields
 /// * `code` - HTTP status code for the error response
 /// * `body` - JSON message containing error translation key
+/// * `params` - Optional values the frontend can interpolate into the message
 #[derive(Debug)]
 pub struct Error {
     code: StatusCode,
     body: Json<TranslationKeyMessage>,
+    params: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl Error {
@@ -32,8 +34,27 @@ impl Error {
         Self {
             code,
             body: Json(TranslationKeyMessage { translation_key }),
+            params: None,
         }
     }
+
+    /// Adds a parameter to the error response
+    ///
+    /// Parameters are serialized in a `params` object next to the translation
+    /// key so the frontend can interpolate them into the message.
+    ///
+    /// # Arguments
+    /// * `key` - Name of the parameter
+    /// * `value` - Value of the parameter
+    ///
+    /// # Returns
+    /// Returns the `Error` with the parameter added
+    pub fn with_param(mut self, key: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.params
+            .get_or_insert_with(serde_json::Map::new)
+            .insert(key.to_string(), value.into());
+        self
+    }
 }
 
 /// Implements conversion into an Axum Response
@@ -41,7 +62,17 @@ impl Error {
 /// Allows the Error type to be returned directly from route handlers
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
-        (self.code, self.body).into_response()
+        match self.params {
+            Some(params) => (
+                self.code,
+                Json(serde_json::json!({
+                    "translation_key": self.body.translation_key,
+                    "params": params,
+                })),
+            )
+                .into_response(),
+            None => (self.code, self.body).into_response(),
+        }
     }
 }
 
@@ -65,9 +96,47 @@ impl From<jsonwebtoken::errors::Error> for Error {
     }
 }
 
+/// Postgres error code for a unique constraint violation
+const UNIQUE_VIOLATION: &str = "23505";
+
+/// Postgres error code for a foreign key constraint violation
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+
+/// Maps a Postgres error code to the status and translation key to return
+///
+/// # Arguments
+/// * `code` - The SQLSTATE code reported by the database, if any
+///
+/// # Returns
+/// Returns the status code and translation key, or `None` when the error is
+/// not caused by the request and should be treated as an internal error
+fn database_error_status(code: Option<&str>) -> Option<(StatusCode, TranslationKey)> {
+    match code? {
+        UNIQUE_VIOLATION => Some((StatusCode::CONFLICT, TranslationKey::ResourceAlreadyExists)),
+        FOREIGN_KEY_VIOLATION => Some((StatusCode::BAD_REQUEST, TranslationKey::InvalidReference)),
+        _ => None,
+    }
+}
+
 /// Converts SQLX database errors into the application Error type
+///
+/// Missing rows become 404, unique violations 409 and foreign key violations
+/// 400. Every other database error is logged and returned as a 500.
 impl From<sqlx::Error> for Error {
     fn from(value: sqlx::Error) -> Self {
+        if let sqlx::Error::RowNotFound = value {
+            tracing::warn!("Database error: {}", value);
+            return Self::new(StatusCode::NOT_FOUND, TranslationKey::ResourceNotFound);
+        }
+
+        let mapped = value
+            .as_database_error()
+            .and_then(|db_error| database_error_status(db_error.code().as_deref()));
+        if let Some(mapped) = mapped {
+            tracing::warn!("Database error: {}", value);
+            return mapped.into();
+        }
+
         tracing::error!("Database error: {}", value);
         Self::new(
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -77,13 +146,13 @@ impl From<sqlx::Error> for Error {
 }
 
 /// Converts Uuid errors into the application Error type
+///
+/// UUIDs parsed with `?` come from the authenticated user id placed in the
+/// request by the auth guard, so a malformed one means the token is invalid.
 impl From<uuid::Error> for Error {
     fn from(value: uuid::Error) -> Self {
-        tracing::error!("UUID error: {}", value);
-        Self::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            TranslationKey::InternalServerError,
-        )
+        tracing::warn!("UUID error: {}", value);
+        Self::new(StatusCode::UNAUTHORIZED, TranslationKey::TokenInvalid)
     }
 }
 
@@ -108,3 +177,64 @@ impl From<Box<dyn std::error::Error>> for Error {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn test_uuid_error_is_unauthorized() {
+        let error: Error = Uuid::from_str("not-a-uuid").unwrap_err().into();
+
+        assert_eq!(error.code, StatusCode::UNAUTHORIZED);
+        assert!(matches!(
+            error.body.translation_key,
+            TranslationKey::TokenInvalid
+        ));
+    }
+
+    #[test]
+    fn test_row_not_found_is_not_found() {
+        let error: Error = sqlx::Error::RowNotFound.into();
+
+        assert_eq!(error.code, StatusCode::NOT_FOUND);
+        assert!(matches!(
+            error.body.translation_key,
+            TranslationKey::ResourceNotFound
+        ));
+    }
+
+    #[test]
+    fn test_other_sqlx_errors_are_internal() {
+        let error: Error = sqlx::Error::PoolTimedOut.into();
+
+        assert_eq!(error.code, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_database_error_status_by_code() {
+        assert_eq!(
+            database_error_status(Some("23505")),
+            Some((StatusCode::CONFLICT, TranslationKey::ResourceAlreadyExists))
+        );
+        assert_eq!(
+            database_error_status(Some("23503")),
+            Some((StatusCode::BAD_REQUEST, TranslationKey::InvalidReference))
+        );
+        assert_eq!(database_error_status(Some("42P01")), None);
+        assert_eq!(database_error_status(None), None);
+    }
+
+    #[test]
+    fn test_with_param_collects_params() {
+        let error = Error::new(StatusCode::CONFLICT, TranslationKey::WalletLimitReached)
+            .with_param("limit", 50);
+
+        let params = error.params.unwrap();
+        assert_eq!(params["limit"], 50);
+    }
+}
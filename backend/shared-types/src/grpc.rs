@@ -0,0 +1,141 @@
+use subtle::ConstantTimeEq;
+use tonic::{Code, Status, metadata::MetadataMap};
+
+/// Metadata key carrying the shared secret of internal callers
+pub const INTERNAL_SECRET_METADATA_KEY: &str = "x-internal-secret";
+
+/// Checks that a request carries the internal shared secret
+///
+/// The secrets are compared in constant time, so response times do not
+/// reveal how much of a guess was right.
+///
+/// # Arguments
+/// * `metadata` - Metadata of the incoming gRPC request
+/// * `secret` - The configured internal shared secret
+///
+/// # Returns
+/// * `Ok(())` - If the secret matches
+/// * `Err(Status)` - `UNAUTHENTICATED` if the secret is missing or wrong
+pub fn authorize(metadata: &MetadataMap, secret: &str) -> Result<(), Status> {
+    match metadata
+        .get(INTERNAL_SECRET_METADATA_KEY)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) if is_internal_secret(value, secret) => Ok(()),
+        _ => Err(Status::unauthenticated("Invalid internal secret")),
+    }
+}
+
+/// Compares a received secret with the internal shared secret in constant time
+///
+/// # Arguments
+/// * `value` - The secret sent by the caller
+/// * `secret` - The configured internal shared secret
+///
+/// # Returns
+/// `true` if both secrets are equal
+pub fn is_internal_secret(value: &str, secret: &str) -> bool {
+    bool::from(value.as_bytes().ct_eq(secret.as_bytes()))
+}
+
+/// PostgreSQL error codes that go away when the statement is run again
+///
+/// Serialization failures, deadlocks, shutdowns and too many connections.
+/// Connection exceptions (class `08`) are checked separately.
+const TRANSIENT_SQLSTATES: [&str; 5] = ["40001", "40P01", "57P01", "57P03", "53300"];
+
+/// Maps a database error to the status a gRPC handler returns
+///
+/// Errors that go away on their own, like pool timeouts or lost connections,
+/// become `UNAVAILABLE` so callers retry them. Every other error becomes
+/// `INTERNAL`, since retrying it would fail the same way.
+///
+/// # Arguments
+/// * `error` - The database error
+/// * `message` - Message of the returned status
+///
+/// # Returns
+/// `UNAVAILABLE` for transient errors, `INTERNAL` otherwise
+pub fn database_status(error: &sqlx::Error, message: &str) -> Status {
+    let code = if is_transient(error) {
+        Code::Unavailable
+    } else {
+        Code::Internal
+    };
+
+    Status::new(code, message)
+}
+
+/// Checks whether a database error may succeed when retried
+fn is_transient(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed
+        | sqlx::Error::Io(_)
+        | sqlx::Error::WorkerCrashed => true,
+        sqlx::Error::Database(error) => error.code().is_some_and(|code| {
+            code.starts_with("08") || TRANSIENT_SQLSTATES.contains(&code.as_ref())
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_database_status_for_transient_errors() {
+        for error in [
+            sqlx::Error::PoolTimedOut,
+            sqlx::Error::PoolClosed,
+            sqlx::Error::Io(std::io::ErrorKind::ConnectionReset.into()),
+        ] {
+            assert_eq!(database_status(&error, "failed").code(), Code::Unavailable);
+        }
+    }
+
+    #[test]
+    fn test_database_status_for_permanent_errors() {
+        for error in [
+            sqlx::Error::RowNotFound,
+            sqlx::Error::ColumnNotFound("user_id".into()),
+            sqlx::Error::Protocol("unexpected message".into()),
+        ] {
+            let status = database_status(&error, "failed");
+            assert_eq!(status.code(), Code::Internal);
+            assert_eq!(status.message(), "failed");
+        }
+    }
+
+    #[test]
+    fn test_authorize_with_valid_secret() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(INTERNAL_SECRET_METADATA_KEY, "secret".parse().unwrap());
+
+        assert!(authorize(&metadata, "secret").is_ok());
+    }
+
+    #[test]
+    fn test_authorize_with_wrong_secret() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(INTERNAL_SECRET_METADATA_KEY, "other".parse().unwrap());
+
+        let status = authorize(&metadata, "secret").unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn test_authorize_with_secret_prefix() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(INTERNAL_SECRET_METADATA_KEY, "sec".parse().unwrap());
+
+        assert!(authorize(&metadata, "secret").is_err());
+    }
+
+    #[test]
+    fn test_authorize_without_secret() {
+        let status = authorize(&MetadataMap::new(), "secret").unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+}
@@ -0,0 +1,81 @@
+use axum::http::HeaderMap;
+use uuid::Uuid;
+
+use crate::grpc::{INTERNAL_SECRET_METADATA_KEY, is_internal_secret};
+
+/// Header carrying the ID of the user the gateway verified the access token of
+pub const USER_ID_HEADER: &str = "x-user-id";
+
+/// Header carrying the internal shared secret on requests forwarded by the gateway
+pub const INTERNAL_SECRET_HEADER: &str = INTERNAL_SECRET_METADATA_KEY;
+
+/// Reads the user the gateway verified a request for
+///
+/// The user ID header is only trusted when the request also carries the
+/// internal shared secret, which only the gateway knows. The gateway drops
+/// both headers from client requests, so they cannot be spoofed through it.
+///
+/// # Arguments
+/// * `headers` - Headers of the incoming HTTP request
+/// * `secret` - The configured internal shared secret
+///
+/// # Returns
+/// * `Some(Uuid)` - The verified user, if the secret matches and the ID is a UUID
+/// * `None` - If the secret is missing or wrong, or no valid user ID was sent
+pub fn verified_user_id(headers: &HeaderMap, secret: &str) -> Option<Uuid> {
+    let has_secret = headers
+        .get(INTERNAL_SECRET_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| is_internal_secret(value, secret));
+    if !has_secret {
+        return None;
+    }
+
+    headers
+        .get(USER_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::parse_str(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(user_id: &str, secret: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_ID_HEADER, user_id.parse().unwrap());
+        if let Some(secret) = secret {
+            headers.insert(INTERNAL_SECRET_HEADER, secret.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_verified_user_id_with_secret() {
+        let user_id = Uuid::new_v4();
+
+        assert_eq!(
+            verified_user_id(&headers(&user_id.to_string(), Some("secret")), "secret"),
+            Some(user_id)
+        );
+    }
+
+    #[test]
+    fn test_user_id_without_secret_is_not_trusted() {
+        let user_id = Uuid::new_v4().to_string();
+
+        assert_eq!(verified_user_id(&headers(&user_id, None), "secret"), None);
+        assert_eq!(
+            verified_user_id(&headers(&user_id, Some("other")), "secret"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_user_id_is_rejected() {
+        assert_eq!(
+            verified_user_id(&headers("not-a-uuid", Some("secret")), "secret"),
+            None
+        );
+    }
+}
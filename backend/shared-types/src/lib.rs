@@ -1,5 +1,9 @@
+pub mod de;
 pub mod enums;
 pub mod error;
+pub mod grpc;
+pub mod identity;
+pub mod money;
 pub mod response;
 
 pub use enums::*;
@@ -0,0 +1,356 @@
+use std::ops::{Add, Sub};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::{Currency, CurrencySymbolPosition, NumberFormat, ThousandsSeparator};
+
+/// Sums decimal amounts
+///
+/// # Arguments
+/// * `amounts` - The amounts to add up
+///
+/// # Returns
+/// The sum of all amounts, zero for no amounts
+pub fn sum(amounts: impl IntoIterator<Item = Decimal>) -> Decimal {
+    amounts
+        .into_iter()
+        .fold(Decimal::ZERO, |total, amount| total + amount)
+}
+
+/// Rounds an amount to the minor unit of a currency
+///
+/// # Arguments
+/// * `amount` - The amount to round
+/// * `currency` - The currency the amount is expressed in
+///
+/// # Returns
+/// The amount rounded to the currency's decimal places
+pub fn round(amount: Decimal, currency: Currency) -> Decimal {
+    amount.round_dp(currency.decimal_places())
+}
+
+/// Most decimal places an amount can be displayed with
+pub const MAX_DISPLAY_DECIMAL_PLACES: u32 = 4;
+
+/// How a user wants amounts displayed
+///
+/// # Fields
+/// * `symbol_position` - Where the currency symbol goes
+/// * `thousands_separator` - What separates groups of thousands
+/// * `number_format` - Which decimal separator is used
+/// * `decimal_places` - Decimal places shown, the currency's minor unit if `None`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayPrefs {
+    pub symbol_position: CurrencySymbolPosition,
+    pub thousands_separator: ThousandsSeparator,
+    pub number_format: NumberFormat,
+    pub decimal_places: Option<u32>,
+}
+
+impl DisplayPrefs {
+    /// Returns the default display preferences for amounts in a currency
+    ///
+    /// # Arguments
+    /// * `currency` - The currency the preferences are derived from
+    pub fn for_currency(currency: Currency) -> Self {
+        Self {
+            symbol_position: currency.default_symbol_position(),
+            thousands_separator: currency.default_thousands_separator(),
+            number_format: NumberFormat::default(),
+            decimal_places: None,
+        }
+    }
+}
+
+/// Formats an amount for display with the user's preferences
+///
+/// The amount is rounded like `round`, to the preferred decimal places or the
+/// currency's minor unit. Prefixed symbols are written next to the amount
+/// (`$1,234.56`), suffixed ones after a space (`1 234,56 €`).
+///
+/// # Arguments
+/// * `amount` - The amount to format
+/// * `currency` - The currency the amount is expressed in
+/// * `prefs` - The user's display preferences
+///
+/// # Returns
+/// The formatted amount with its currency symbol
+pub fn format_amount(amount: Decimal, currency: Currency, prefs: &DisplayPrefs) -> String {
+    let decimal_places = prefs
+        .decimal_places
+        .unwrap_or_else(|| currency.decimal_places())
+        .min(MAX_DISPLAY_DECIMAL_PLACES);
+    let rounded = amount.round_dp(decimal_places);
+
+    let digits = format!("{:.*}", decimal_places as usize, rounded.abs());
+    let (whole, fraction) = match digits.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (digits.as_str(), None),
+    };
+
+    let mut number = group_thousands(whole, prefs.thousands_separator);
+    if let Some(fraction) = fraction {
+        number.push(prefs.number_format.decimal_separator());
+        number.push_str(fraction);
+    }
+
+    let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    match prefs.symbol_position {
+        CurrencySymbolPosition::Prefix => format!("{}{}{}", sign, currency.symbol(), number),
+        CurrencySymbolPosition::Suffix => format!("{}{} {}", sign, number, currency.symbol()),
+    }
+}
+
+/// Inserts the thousands separator between every group of three digits
+fn group_thousands(digits: &str, separator: ThousandsSeparator) -> String {
+    let Some(separator) = separator.as_char() else {
+        return digits.to_string();
+    };
+
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// An amount of money in a specific currency
+///
+/// Adding or subtracting amounts of different currencies is a bug, so the
+/// `Add` and `Sub` implementations panic on a currency mismatch. Use
+/// `checked_add` and `checked_sub` when the currencies come from user input.
+///
+/// # Fields
+/// * `amount` - The amount of money
+/// * `currency` - The currency of the amount
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: Decimal,
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Creates a new amount of money
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of money
+    /// * `currency` - The currency of the amount
+    ///
+    /// # Returns
+    /// Returns a new `Money` instance
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Creates a zero amount of a currency
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// Returns the amount rounded to the currency's minor unit
+    pub fn rounded(self) -> Self {
+        Self::new(round(self.amount, self.currency), self.currency)
+    }
+
+    /// Adds two amounts of the same currency
+    ///
+    /// # Arguments
+    /// * `other` - The amount to add
+    ///
+    /// # Returns
+    /// * `Ok(Money)` - The sum in the shared currency
+    /// * `Err(String)` - If the currencies differ
+    pub fn checked_add(self, other: Money) -> Result<Money, String> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(self.amount + other.amount, self.currency))
+    }
+
+    /// Subtracts an amount of the same currency
+    ///
+    /// # Arguments
+    /// * `other` - The amount to subtract
+    ///
+    /// # Returns
+    /// * `Ok(Money)` - The difference in the shared currency
+    /// * `Err(String)` - If the currencies differ
+    pub fn checked_sub(self, other: Money) -> Result<Money, String> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(self.amount - other.amount, self.currency))
+    }
+
+    /// Checks that another amount uses the same currency
+    fn ensure_same_currency(&self, other: Money) -> Result<(), String> {
+        if self.currency != other.currency {
+            return Err(format!(
+                "Currency mismatch: {} and {}",
+                self.currency, other.currency
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// Adds two amounts of money
+///
+/// # Panics
+/// Panics if the currencies differ
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        self.checked_add(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Subtracts two amounts of money
+///
+/// # Panics
+/// Panics if the currencies differ
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        self.checked_sub(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(sum([dec!(0.1), dec!(0.2), dec!(-0.05)]), dec!(0.25));
+        assert_eq!(sum(Vec::new()), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_round_uses_currency_decimal_places() {
+        assert_eq!(round(dec!(10.456), Currency::Eur), dec!(10.46));
+        assert_eq!(round(dec!(10.456), Currency::Jpy), dec!(10));
+    }
+
+    #[test]
+    fn test_money_add_and_sub_same_currency() {
+        let a = Money::new(dec!(10.50), Currency::Usd);
+        let b = Money::new(dec!(2.25), Currency::Usd);
+
+        assert_eq!(a + b, Money::new(dec!(12.75), Currency::Usd));
+        assert_eq!(a - b, Money::new(dec!(8.25), Currency::Usd));
+    }
+
+    #[test]
+    fn test_money_checked_add_rejects_currency_mismatch() {
+        let usd = Money::new(dec!(1), Currency::Usd);
+        let eur = Money::new(dec!(1), Currency::Eur);
+
+        assert!(usd.checked_add(eur).is_err());
+        assert!(usd.checked_sub(eur).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Currency mismatch")]
+    fn test_money_add_panics_on_currency_mismatch() {
+        let _ = Money::new(dec!(1), Currency::Usd) + Money::new(dec!(1), Currency::Eur);
+    }
+
+    #[test]
+    fn test_format_amount_usd() {
+        let prefs = DisplayPrefs::for_currency(Currency::Usd);
+
+        assert_eq!(
+            format_amount(dec!(1234567.891), Currency::Usd, &prefs),
+            "$1,234,567.89"
+        );
+        assert_eq!(format_amount(dec!(-42.5), Currency::Usd, &prefs), "-$42.50");
+        assert_eq!(format_amount(dec!(999), Currency::Usd, &prefs), "$999.00");
+        assert_eq!(format_amount(Decimal::ZERO, Currency::Usd, &prefs), "$0.00");
+    }
+
+    #[test]
+    fn test_format_amount_eur() {
+        let prefs = DisplayPrefs {
+            number_format: NumberFormat::CommaDecimal,
+            ..DisplayPrefs::for_currency(Currency::Eur)
+        };
+        assert_eq!(
+            format_amount(dec!(1234.5), Currency::Eur, &prefs),
+            "1 234,50 €"
+        );
+
+        let prefs = DisplayPrefs {
+            symbol_position: CurrencySymbolPosition::Prefix,
+            thousands_separator: ThousandsSeparator::Dot,
+            number_format: NumberFormat::CommaDecimal,
+            decimal_places: None,
+        };
+        assert_eq!(
+            format_amount(dec!(-1234567.5), Currency::Eur, &prefs),
+            "-€1.234.567,50"
+        );
+    }
+
+    #[test]
+    fn test_format_amount_jpy_has_no_decimals() {
+        let prefs = DisplayPrefs::for_currency(Currency::Jpy);
+
+        assert_eq!(format_amount(dec!(1234.5), Currency::Jpy, &prefs), "¥1,234");
+        assert_eq!(format_amount(dec!(1235.5), Currency::Jpy, &prefs), "¥1,236");
+        assert_eq!(format_amount(dec!(-0.4), Currency::Jpy, &prefs), "¥0");
+    }
+
+    #[test]
+    fn test_format_amount_decimal_places_override() {
+        let prefs = DisplayPrefs {
+            thousands_separator: ThousandsSeparator::None,
+            decimal_places: Some(4),
+            ..DisplayPrefs::for_currency(Currency::Usd)
+        };
+        assert_eq!(
+            format_amount(dec!(1234.56789), Currency::Usd, &prefs),
+            "$1234.5679"
+        );
+        assert_eq!(format_amount(dec!(12), Currency::Jpy, &prefs), "¥12.0000");
+
+        let prefs = DisplayPrefs {
+            decimal_places: Some(0),
+            ..DisplayPrefs::for_currency(Currency::Eur)
+        };
+        assert_eq!(
+            format_amount(dec!(1999.99), Currency::Eur, &prefs),
+            "2 000 €"
+        );
+
+        let prefs = DisplayPrefs {
+            decimal_places: Some(9),
+            ..DisplayPrefs::for_currency(Currency::Usd)
+        };
+        assert_eq!(
+            format_amount(dec!(1.123456), Currency::Usd, &prefs),
+            "$1.1235"
+        );
+    }
+
+    #[test]
+    fn test_money_rounded() {
+        let money = Money::new(dec!(99.999), Currency::Ron).rounded();
+        assert_eq!(money, Money::new(dec!(100.00), Currency::Ron));
+        assert_eq!(Money::zero(Currency::Gbp).amount, Decimal::ZERO);
+    }
+}
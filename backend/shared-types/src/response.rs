@@ -22,6 +22,8 @@ pub enum TranslationKey {
     LinkIsExpired,
     /// Password cannot be reused error
     PasswordCannotBeReused,
+    /// Current password does not match error
+    CurrentPasswordIncorrect,
     /// Something went wrong generic error
     SomethingWentWrong,
     /// Captcha verification failed error
@@ -34,6 +36,8 @@ pub enum TranslationKey {
     AccountDeletedTemporarily,
     /// Username length too short error
     UsernameTooShort,
+    /// Username contains characters other than letters, digits, `_` and `-` error
+    UsernameInvalidCharacters,
     /// Email address is not valid error
     EmailAddressInvalid,
     /// Username or email already used error
@@ -86,6 +90,90 @@ pub enum TranslationKey {
     PasskeyAddedSuccessfully,
     /// Passkey removed successfully message
     PasskeyRemovedSuccessfully,
+    /// Maximum number of passkeys reached error
+    TooManyPasskeys,
+    /// Unsupported or invalid currency error
+    InvalidCurrency,
+    /// Unsupported or invalid language error
+    InvalidLanguage,
+    /// Unknown IANA timezone error
+    InvalidTimezone,
+    /// Unsupported date format error
+    InvalidDateFormat,
+    /// Unsupported number format error
+    InvalidNumberFormat,
+    /// Unsupported first day of the week error
+    InvalidWeekStart,
+    /// Unsupported budget period error
+    InvalidBudgetPeriod,
+    /// Unsupported color theme error
+    InvalidTheme,
+    /// Unsupported summary email frequency error
+    InvalidSummaryEmailFrequency,
+    /// Summary email day not valid for the frequency error
+    InvalidSummaryEmailDay,
+    /// Unsupported currency symbol position error
+    InvalidCurrencySymbolPosition,
+    /// Unsupported thousands separator, or one matching the decimal separator, error
+    InvalidThousandsSeparator,
+    /// Decimal places override out of range error
+    InvalidDecimalPlaces,
+    /// Settings reset requested without confirmation error
+    ResetNotConfirmed,
+    /// Alarm not found error
+    AlarmNotFound,
+    /// Maximum number of alarms reached error
+    AlarmLimitReached,
+    /// Alarm label empty or too long error
+    AlarmLabelInvalid,
+    /// Alarm days of week empty or invalid error
+    AlarmDaysInvalid,
+    /// Settings document written by an unknown schema version error
+    UnsupportedSettingsVersion,
+    /// Settings document could not be read error
+    InvalidSettingsDocument,
+    /// Settings changed since the client read them error
+    SettingsModified,
+    /// Device has no settings overrides error
+    DeviceNotFound,
+    /// Maximum number of devices with settings overrides reached error
+    DeviceLimitReached,
+    /// Push token not found error
+    PushTokenNotFound,
+    /// Push token empty or too long error
+    InvalidPushToken,
+    /// Unsupported push notification platform error
+    InvalidPushPlatform,
+    /// Settings written too often error
+    SettingsWriteRateLimited,
+    /// Unsubscribe link token unknown or malformed error
+    InvalidUnsubscribeToken,
+    /// Unsubscribe request is not a one-click unsubscribe error
+    InvalidUnsubscribeRequest,
+    /// Exchange rates could not be retrieved error
+    ExchangeRatesUnavailable,
+    /// Wallet not found error
+    WalletNotFound,
+    /// Wallet share not found error
+    WalletShareNotFound,
+    /// Wallet cannot be shared with its owner error
+    CannotShareWalletWithSelf,
+    /// Maximum number of wallets reached error
+    WalletLimitReached,
+    /// Data export requested too often error
+    ExportRateLimited,
+    /// Wallet name empty or too long error
+    WalletNameInvalid,
+    /// Wallet name already used by another wallet of the user error
+    WalletNameAlreadyUsed,
+    /// Requested resource does not exist error
+    ResourceNotFound,
+    /// Resource conflicts with an existing one error
+    ResourceAlreadyExists,
+    /// Request references a resource that does not exist error
+    InvalidReference,
+    /// Request body contains fields the endpoint does not support error
+    UnknownFields,
 }
 
 /// A message response structure containing a translation key
@@ -111,6 +199,7 @@ pub struct TranslationKeyMessage {
 /// # Fields
 /// * `status` - The current status of the service
 /// * `database` - The current status of the connection to the db (optional)
+/// * `auth_service` - Whether the auth service gRPC server is reachable (optional)
 /// * `version` - The current version of the service
 ///
 /// # Example
@@ -126,6 +215,8 @@ pub struct Health {
     pub status: HealthStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database: Option<DatabaseConnection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_service: Option<ServiceConnection>,
     pub version: String,
 }
 
@@ -143,6 +234,13 @@ pub enum DatabaseConnection {
     Disconnected,
 }
 
+/// The enum for the reachability of a dependent service
+#[derive(Serialize)]
+pub enum ServiceConnection {
+    Reachable,
+    Unreachable,
+}
+
 /// A JWT response structure
 ///
 /// This struct is used to serialize response JWT into JSON format
@@ -203,4 +301,25 @@ mod tests {
         let json = serde_json::to_string(&msg).unwrap();
         assert_eq!(json, r#"{"translation_key":"ACCOUNT_CREATED"}"#);
     }
+
+    #[test]
+    fn test_health_skips_unchecked_dependencies() {
+        let health = Health {
+            status: HealthStatus::Healthy,
+            database: None,
+            auth_service: None,
+            version: "0.0.1".into(),
+        };
+        let json = serde_json::to_string(&health).unwrap();
+        assert_eq!(json, r#"{"status":"Healthy","version":"0.0.1"}"#);
+
+        let health = Health {
+            status: HealthStatus::Unhealthy,
+            database: Some(DatabaseConnection::Connected),
+            auth_service: Some(ServiceConnection::Unreachable),
+            version: "0.0.1".into(),
+        };
+        let json = serde_json::to_string(&health).unwrap();
+        assert!(json.contains(r#""auth_service":"Unreachable""#));
+    }
 }
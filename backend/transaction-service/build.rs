@@ -1,8 +1,14 @@
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    tonic_prost_build::configure()
-        .compile_protos(&["../proto/auth_service.proto"], &["../proto"])?;
+    tonic_prost_build::configure().compile_protos(
+        &[
+            "../proto/auth_service.proto",
+            "../proto/settings_service.proto",
+            "../proto/transaction_service.proto",
+        ],
+        &["../proto"],
+    )?;
 
     Ok(())
 }
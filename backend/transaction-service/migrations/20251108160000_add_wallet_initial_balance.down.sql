@@ -0,0 +1,2 @@
+-- Remove initial_balance column from wallets table
+ALTER TABLE wallets DROP COLUMN IF EXISTS initial_balance;
@@ -0,0 +1,7 @@
+-- Track the opening balance of a wallet separately from its mutable balance
+ALTER TABLE wallets ADD COLUMN initial_balance DECIMAL(15, 2) NOT NULL DEFAULT 0.00;
+
+-- Existing wallets were only ever funded through their creation balance
+UPDATE wallets SET initial_balance = balance;
+
+COMMENT ON COLUMN wallets.initial_balance IS 'Opening balance the wallet was created with; never changed afterwards';
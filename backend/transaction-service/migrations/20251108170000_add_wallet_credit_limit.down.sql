@@ -0,0 +1,3 @@
+-- Remove credit_limit column from wallets table
+ALTER TABLE wallets DROP CONSTRAINT IF EXISTS check_credit_limit_non_negative;
+ALTER TABLE wallets DROP COLUMN IF EXISTS credit_limit;
@@ -0,0 +1,6 @@
+-- Credit limit for credit cards and loans; NULL when not applicable or unknown
+ALTER TABLE wallets ADD COLUMN credit_limit DECIMAL(15, 2);
+
+ALTER TABLE wallets ADD CONSTRAINT check_credit_limit_non_negative CHECK (credit_limit IS NULL OR credit_limit >= 0);
+
+COMMENT ON COLUMN wallets.credit_limit IS 'Credit limit of CreditCard/Loan wallets, used to compute the available credit';
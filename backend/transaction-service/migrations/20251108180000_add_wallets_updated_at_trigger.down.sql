@@ -0,0 +1,3 @@
+-- Remove the updated_at trigger from wallets table
+DROP TRIGGER IF EXISTS set_updated_at ON wallets;
+DROP FUNCTION IF EXISTS set_wallets_updated_at();
@@ -0,0 +1,10 @@
+-- Keep wallets.updated_at current on every update instead of relying on each query.
+-- The function is specific to wallets, so dropping it never affects other tables.
+CREATE OR REPLACE FUNCTION set_wallets_updated_at() RETURNS TRIGGER AS $$ BEGIN NEW.updated_at = NOW();
+RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Attach trigger to wallets table
+CREATE TRIGGER set_updated_at BEFORE
+UPDATE ON wallets FOR EACH ROW EXECUTE FUNCTION set_wallets_updated_at();
@@ -0,0 +1,2 @@
+-- Drop wallet_shares table
+DROP TABLE IF EXISTS wallet_shares;
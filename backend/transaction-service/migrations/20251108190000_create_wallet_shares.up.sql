@@ -0,0 +1,14 @@
+-- Read-only access to a wallet granted by its owner to another user
+CREATE TABLE wallet_shares (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    wallet_id UUID NOT NULL REFERENCES wallets(id) ON DELETE CASCADE,
+    owner_id UUID NOT NULL,
+    shared_with_user_id UUID NOT NULL,
+    permission VARCHAR(20) NOT NULL DEFAULT 'ReadOnly',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_wallet_share UNIQUE (wallet_id, shared_with_user_id),
+    CONSTRAINT check_wallet_share_permission CHECK (permission IN ('ReadOnly'))
+);
+
+-- Create index for listing the wallets shared with a user
+CREATE INDEX idx_wallet_shares_shared_with_user_id ON wallet_shares(shared_with_user_id);
@@ -1,6 +1,14 @@
 use sqlx::PgPool;
 
-use crate::{Config, grpc::auth_service::service::auth_service_client::AuthServiceClient};
+use crate::{
+    Config,
+    exchange_rate::ExchangeRateProvider,
+    grpc::{
+        auth_service::service::auth_service_client::AuthServiceClient,
+        settings_service::service::settings_service_client::SettingsServiceClient,
+    },
+    models::export::ExportRateLimiter,
+};
 
 /// Application state shared across all routes
 ///
@@ -11,10 +19,16 @@ use crate::{Config, grpc::auth_service::service::auth_service_client::AuthServic
 /// * `config` - Application configuration settings
 /// * `db` - PostgreSQL connection pool for async database operations
 /// * `auth_service` - gRPC client for authentication service (cloneable for concurrent access)
+/// * `settings_service` - gRPC client for the settings service (cloneable for concurrent access)
+/// * `exchange_rate_provider` - Source of currency exchange rates
+/// * `export_rate_limiter` - Limits how often each user can export their data
 pub struct AppState {
     pub config: Config,
     db: PgPool,
     auth_service: AuthServiceClient<tonic::transport::Channel>,
+    settings_service: SettingsServiceClient<tonic::transport::Channel>,
+    exchange_rate_provider: Box<dyn ExchangeRateProvider>,
+    export_rate_limiter: ExportRateLimiter,
 }
 
 impl AppState {
@@ -26,11 +40,16 @@ impl AppState {
         config: Config,
         db: PgPool,
         auth_service: AuthServiceClient<tonic::transport::Channel>,
+        settings_service: SettingsServiceClient<tonic::transport::Channel>,
+        exchange_rate_provider: Box<dyn ExchangeRateProvider>,
     ) -> Self {
         Self {
             config,
             db,
             auth_service,
+            settings_service,
+            exchange_rate_provider,
+            export_rate_limiter: ExportRateLimiter::new(chrono::Duration::hours(1)),
         }
     }
 
@@ -49,4 +68,28 @@ impl AppState {
     pub fn get_auth_service(&self) -> AuthServiceClient<tonic::transport::Channel> {
         self.auth_service.clone()
     }
+
+    /// Gets a cloned settings service client for concurrent access
+    ///
+    /// # Returns
+    /// * `SettingsServiceClient<Channel>` - A cloned settings service client
+    pub fn get_settings_service(&self) -> SettingsServiceClient<tonic::transport::Channel> {
+        self.settings_service.clone()
+    }
+
+    /// Gets a reference to the exchange rate provider
+    ///
+    /// # Returns
+    /// * `&dyn ExchangeRateProvider` - A reference to the exchange rate provider
+    pub fn get_exchange_rate_provider(&self) -> &dyn ExchangeRateProvider {
+        self.exchange_rate_provider.as_ref()
+    }
+
+    /// Gets a reference to the export rate limiter
+    ///
+    /// # Returns
+    /// * `&ExportRateLimiter` - A reference to the export rate limiter
+    pub fn get_export_rate_limiter(&self) -> &ExportRateLimiter {
+        &self.export_rate_limiter
+    }
 }
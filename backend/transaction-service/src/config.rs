@@ -1,4 +1,6 @@
-use std::env::var;
+use std::{env::var, str::FromStr};
+
+use shared_types::enums::Currency;
 
 /// Application configuration loaded from environment variables
 ///
@@ -10,6 +12,7 @@ use std::env::var;
 ///
 /// ## Server Configuration
 /// * `transaction_http_port` - Port number for the HTTP server to listen on
+/// * `transaction_grpc_port` - Port number for the internal gRPC server to listen on
 ///
 /// ## Database Configuration
 /// * `pg_url` - PostgreSQL server hostname or IP address
@@ -18,21 +21,42 @@ use std::env::var;
 /// * `pg_database` - Name of the transaction-service database to connect to (default: brewget_transactions)
 ///
 /// ## Security Configuration
-/// * `cors_url` - Allowed CORS origin URL for frontend integration
+/// * `internal_grpc_secret` - Shared secret other services must send to call the gRPC server
 ///
 /// ## Service Integration
 /// * `auth_hostname` - Hostname of the auth service for gRPC communication
 /// * `auth_grpc_port` - Port number for the auth service gRPC server
+/// * `settings_hostname` - Hostname of the settings service for gRPC communication
+/// * `settings_grpc_port` - Port number for the settings service gRPC server
+/// * `settings_grpc_timeout_ms` - Milliseconds to wait for the settings service before using defaults (default: 500)
+/// * `default_currency` - Currency used when the user's preferred currency cannot be fetched (default: USD)
+///
+/// ## Wallet Configuration
+/// * `max_wallets_per_user` - Maximum number of wallets a user may own, 0 for unlimited (default: 50)
+///
+/// ## Exchange Rates Configuration
+/// * `exchange_rate_api_url` - Endpoint of the exchange rates API (optional, static rates when unset)
+/// * `exchange_rate_cache_ttl` - Seconds fetched exchange rates are considered fresh (default: 3600)
+/// * `exchange_rate_timeout_ms` - Milliseconds a request to the exchange rates API may take (default: 5000)
 #[derive(Clone)]
 pub struct Config {
     pub transaction_http_port: u32,
+    pub transaction_grpc_port: u32,
     pub pg_url: String,
     pub pg_username: String,
     pub pg_password: String,
     pub pg_database: String,
-    pub cors_url: String,
+    pub internal_grpc_secret: String,
     pub auth_hostname: String,
     pub auth_grpc_port: u32,
+    pub settings_hostname: String,
+    pub settings_grpc_port: u32,
+    pub settings_grpc_timeout_ms: u64,
+    pub default_currency: Currency,
+    pub max_wallets_per_user: usize,
+    pub exchange_rate_api_url: Option<String>,
+    pub exchange_rate_cache_ttl: u64,
+    pub exchange_rate_timeout_ms: u64,
 }
 
 impl Config {
@@ -45,19 +69,30 @@ impl Config {
     ///
     /// The following environment variables must be set:
     /// - `TRANSACTION_HTTP_PORT` - Must be a valid u32 port number
+    /// - `TRANSACTION_GRPC_PORT` - Must be a valid u32 port number
     /// - `PG_URL` - PostgreSQL server URL
     /// - `PG_USERNAME` - Database username
     /// - `PG_PASSWORD` - Database password
     /// - `TRANSACTION_PG_DATABASE` - Transaction service database name (falls back to PG_DATABASE if not set)
-    /// - `CORS_URL` - Allowed CORS origin URL
+    /// - `INTERNAL_GRPC_SECRET` - Shared secret for internal gRPC calls
     /// - `AUTH_HOSTNAME` - Auth service hostname
     /// - `AUTH_GRPC_PORT` - Must be a valid u32 port number
+    /// - `SETTINGS_HOSTNAME` - Settings service hostname
+    /// - `SETTINGS_GRPC_PORT` - Must be a valid u32 port number
+    /// - `SETTINGS_GRPC_TIMEOUT_MS` - Settings service call timeout in milliseconds (optional, defaults to 500)
+    /// - `DEFAULT_CURRENCY` - Fallback currency code (optional, defaults to USD)
+    /// - `MAX_WALLETS_PER_USER` - Maximum wallets per user, 0 for unlimited (optional, defaults to 50)
+    /// - `EXCHANGE_RATE_API_URL` - Exchange rates API endpoint (optional, static rates when unset)
+    /// - `EXCHANGE_RATE_CACHE_TTL` - Exchange rates cache TTL in seconds (optional, defaults to 3600)
+    /// - `EXCHANGE_RATE_TIMEOUT_MS` - Exchange rates API request timeout in milliseconds (optional, defaults to 5000)
     ///
     /// # Panics
     ///
     /// This method will panic if:
     /// - Any required environment variable is missing
-    /// - `TRANSACTION_HTTP_PORT` or `AUTH_GRPC_PORT` cannot be parsed as u32
+    /// - `TRANSACTION_HTTP_PORT`, `TRANSACTION_GRPC_PORT`, `AUTH_GRPC_PORT` or `SETTINGS_GRPC_PORT`
+    ///   cannot be parsed as u32
+    /// - `DEFAULT_CURRENCY` is not a supported currency
     ///
     /// # Returns
     ///
@@ -67,6 +102,10 @@ impl Config {
             .map(|val| val.parse::<u32>())
             .expect("TRANSACTION_HTTP_PORT must be provided.")
             .expect("TRANSACTION_HTTP_PORT must be a valid u32.");
+        let transaction_grpc_port = var("TRANSACTION_GRPC_PORT")
+            .map(|val| val.parse::<u32>())
+            .expect("TRANSACTION_GRPC_PORT must be provided.")
+            .expect("TRANSACTION_GRPC_PORT must be a valid u32.");
         let pg_url = var("PG_URL").expect("PG_URL must be provided.");
         let pg_username = var("PG_USERNAME").expect("PG_USERNAME must be provided.");
         let pg_password = var("PG_PASSWORD").expect("PG_PASSWORD must be provided.");
@@ -74,22 +113,63 @@ impl Config {
         let pg_database = var("TRANSACTION_PG_DATABASE")
             .or_else(|_| var("PG_DATABASE"))
             .expect("TRANSACTION_PG_DATABASE or PG_DATABASE must be provided.");
-        let cors_url = var("CORS_URL").expect("CORS_URL must be provided.");
+        let internal_grpc_secret =
+            var("INTERNAL_GRPC_SECRET").expect("INTERNAL_GRPC_SECRET must be provided.");
         let auth_hostname = var("AUTH_HOSTNAME").expect("AUTH_HOSTNAME must be provided.");
         let auth_grpc_port = var("AUTH_GRPC_PORT")
             .map(|val| val.parse::<u32>())
             .expect("AUTH_GRPC_PORT must be provided.")
             .expect("AUTH_GRPC_PORT must be a valid u32.");
+        let settings_hostname =
+            var("SETTINGS_HOSTNAME").expect("SETTINGS_HOSTNAME must be provided.");
+        let settings_grpc_port = var("SETTINGS_GRPC_PORT")
+            .map(|val| val.parse::<u32>())
+            .expect("SETTINGS_GRPC_PORT must be provided.")
+            .expect("SETTINGS_GRPC_PORT must be a valid u32.");
+        let settings_grpc_timeout_ms = var("SETTINGS_GRPC_TIMEOUT_MS")
+            .ok()
+            .and_then(|timeout| timeout.parse::<u64>().ok())
+            .unwrap_or(500);
+        let default_currency = var("DEFAULT_CURRENCY")
+            .map(|currency| {
+                Currency::from_str(&currency)
+                    .expect("DEFAULT_CURRENCY must be a supported currency.")
+            })
+            .unwrap_or(Currency::Usd);
+        let max_wallets_per_user = var("MAX_WALLETS_PER_USER")
+            .ok()
+            .and_then(|max| max.parse::<usize>().ok())
+            .unwrap_or(50);
+        let exchange_rate_api_url = var("EXCHANGE_RATE_API_URL")
+            .ok()
+            .filter(|url| !url.is_empty());
+        let exchange_rate_cache_ttl = var("EXCHANGE_RATE_CACHE_TTL")
+            .ok()
+            .and_then(|ttl| ttl.parse::<u64>().ok())
+            .unwrap_or(3600);
+        let exchange_rate_timeout_ms = var("EXCHANGE_RATE_TIMEOUT_MS")
+            .ok()
+            .and_then(|timeout| timeout.parse::<u64>().ok())
+            .unwrap_or(5000);
 
         Self {
             transaction_http_port,
+            transaction_grpc_port,
             pg_url,
             pg_username,
             pg_password,
             pg_database,
-            cors_url,
+            internal_grpc_secret,
             auth_hostname,
             auth_grpc_port,
+            settings_hostname,
+            settings_grpc_port,
+            settings_grpc_timeout_ms,
+            default_currency,
+            max_wallets_per_user,
+            exchange_rate_api_url,
+            exchange_rate_cache_ttl,
+            exchange_rate_timeout_ms,
         }
     }
 }
@@ -1 +1,2 @@
 pub mod wallet;
+pub mod wallet_share;
@@ -1,9 +1,11 @@
-use sqlx::PgPool;
+use axum::http::StatusCode;
+use shared_types::enums::Currency;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::models::{
-    response::Error,
-    wallet::{CreateWallet, UpdateWallet, Wallet},
+    response::{Error, TranslationKey},
+    wallet::{CreateWallet, UpdateWallet, Wallet, is_wallet_limit_reached},
 };
 
 /// Finds all wallets for a specific user
@@ -20,7 +22,7 @@ use crate::models::{
 pub async fn find_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<Wallet>, Error> {
     let wallets = sqlx::query_as::<_, Wallet>(
         r#"
-        SELECT id, user_id, name, balance, currency, wallet_type, created_at, updated_at
+        SELECT id, user_id, name, balance, initial_balance, credit_limit, currency, wallet_type, created_at, updated_at
         FROM wallets
         WHERE user_id = $1
         ORDER BY wallet_type, created_at DESC
@@ -33,6 +35,34 @@ pub async fn find_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<Wallet
     Ok(wallets)
 }
 
+/// Finds all wallets other users shared with a specific user
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user the wallets are shared with
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<Wallet>)` - The shared wallets, with `user_id` set to their owner
+/// * `Err(Error)` - Database operation error
+pub async fn find_shared_with_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<Wallet>, Error> {
+    let wallets = sqlx::query_as::<_, Wallet>(
+        r#"
+        SELECT w.id, w.user_id, w.name, w.balance, w.initial_balance, w.credit_limit, w.currency, w.wallet_type, w.created_at, w.updated_at
+        FROM wallets w
+        INNER JOIN wallet_shares s ON s.wallet_id = w.id
+        WHERE s.shared_with_user_id = $1
+        ORDER BY w.wallet_type, w.created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(wallets)
+}
+
 /// Finds a specific wallet by ID
 ///
 /// # Arguments
@@ -45,11 +75,10 @@ pub async fn find_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<Vec<Wallet
 ///
 /// * `Ok(Wallet)` - The wallet
 /// * `Err(Error)` - Database operation error or wallet not found
-#[allow(dead_code)]
 pub async fn find_by_id(wallet_id: Uuid, user_id: Uuid, pool: &PgPool) -> Result<Wallet, Error> {
-    let wallet = sqlx::query_as::<_, Wallet>(
+    sqlx::query_as::<_, Wallet>(
         r#"
-        SELECT id, user_id, name, balance, currency, wallet_type, created_at, updated_at
+        SELECT id, user_id, name, balance, initial_balance, credit_limit, currency, wallet_type, created_at, updated_at
         FROM wallets
         WHERE id = $1 AND user_id = $2
         "#,
@@ -57,50 +86,152 @@ pub async fn find_by_id(wallet_id: Uuid, user_id: Uuid, pool: &PgPool) -> Result
     .bind(wallet_id)
     .bind(user_id)
     .fetch_one(pool)
+    .await
+    .map_err(|e: sqlx::Error| -> Error {
+        match e {
+            sqlx::Error::RowNotFound => {
+                (StatusCode::NOT_FOUND, TranslationKey::WalletNotFound).into()
+            }
+            _ => e.into(),
+        }
+    })
+}
+
+/// Serializes wallet writes of a user until the end of the transaction
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user whose wallets are changed
+/// * `conn` - Connection of the open transaction
+///
+/// # Returns
+///
+/// * `Ok(())` - Once the lock is held
+/// * `Err(Error)` - Database operation error
+async fn lock_user_wallets(user_id: Uuid, conn: &mut PgConnection) -> Result<(), Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1::text))")
+        .bind(user_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Checks that a user has no other wallet with the given name
+///
+/// Names are compared case-insensitively and ignoring surrounding whitespace.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user owning the wallets
+/// * `name` - The wallet name to check
+/// * `exclude_wallet_id` - A wallet to ignore (the one being renamed)
+/// * `conn` - Connection of the open transaction
+///
+/// # Returns
+///
+/// * `Ok(())` - If the name is free
+/// * `Err(Error)` - Name already used or database operation error
+async fn ensure_name_available(
+    user_id: Uuid,
+    name: &str,
+    exclude_wallet_id: Option<Uuid>,
+    conn: &mut PgConnection,
+) -> Result<(), Error> {
+    let (name_used,): (bool,) = sqlx::query_as(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM wallets
+            WHERE user_id = $1 AND LOWER(BTRIM(name)) = LOWER(BTRIM($2))
+              AND ($3::uuid IS NULL OR id <> $3)
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(name)
+    .bind(exclude_wallet_id)
+    .fetch_one(conn)
     .await?;
 
-    Ok(wallet)
+    if name_used {
+        return Err((StatusCode::CONFLICT, TranslationKey::WalletNameAlreadyUsed).into());
+    }
+
+    Ok(())
 }
 
 /// Creates a new wallet for a user
 ///
+/// The user's wallets are counted and the name checked inside the same
+/// transaction as the insert, under a per-user advisory lock, so concurrent
+/// requests cannot exceed the limit or duplicate a name.
+///
 /// # Arguments
 ///
 /// * `user_id` - The UUID of the user creating the wallet
 /// * `create_wallet` - The wallet creation data
+/// * `currency` - The currency of the wallet, resolved from the request or the user's settings
+/// * `max_wallets` - Maximum number of wallets the user may own, 0 for unlimited
 /// * `pool` - Database connection pool
 ///
 /// # Returns
 ///
 /// * `Ok(Wallet)` - The newly created wallet
-/// * `Err(Error)` - Database operation error
+/// * `Err(Error)` - Wallet limit reached, name already used or database operation error
 pub async fn create(
     user_id: Uuid,
     create_wallet: CreateWallet,
+    currency: Currency,
+    max_wallets: usize,
     pool: &PgPool,
 ) -> Result<Wallet, Error> {
-    let balance = create_wallet.balance.unwrap_or_default();
+    let balance = create_wallet.opening_balance(currency);
+
+    let mut tx = pool.begin().await?;
+
+    lock_user_wallets(user_id, &mut tx).await?;
+
+    let (wallet_count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM wallets WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if is_wallet_limit_reached(wallet_count as usize, max_wallets) {
+        return Err(
+            Error::new(StatusCode::CONFLICT, TranslationKey::WalletLimitReached)
+                .with_param("limit", max_wallets),
+        );
+    }
+
+    ensure_name_available(user_id, &create_wallet.name, None, &mut tx).await?;
 
     let wallet = sqlx::query_as::<_, Wallet>(
         r#"
-        INSERT INTO wallets (user_id, name, balance, currency, wallet_type)
-        VALUES ($1, $2, $3, $4, $5)
-        RETURNING id, user_id, name, balance, currency, wallet_type, created_at, updated_at
+        INSERT INTO wallets (user_id, name, balance, initial_balance, credit_limit, currency, wallet_type)
+        VALUES ($1, $2, $3, $3, $4, $5, $6)
+        RETURNING id, user_id, name, balance, initial_balance, credit_limit, currency, wallet_type, created_at, updated_at
         "#,
     )
     .bind(user_id)
-    .bind(create_wallet.name)
+    .bind(create_wallet.name.trim())
     .bind(balance)
-    .bind(create_wallet.currency.as_str())
+    .bind(create_wallet.credit_limit)
+    .bind(currency.as_str())
     .bind(create_wallet.wallet_type.as_str())
-    .fetch_one(pool)
+    .fetch_one(&mut *tx)
     .await?;
 
+    tx.commit().await?;
+
     Ok(wallet)
 }
 
 /// Updates a wallet
 ///
+/// Changing the currency rounds the balance and initial balance to the new
+/// currency's decimal places. `updated_at` is maintained by the
+/// `set_updated_at` trigger on the table.
+///
 /// # Arguments
 ///
 /// * `wallet_id` - The UUID of the wallet to update
@@ -111,13 +242,20 @@ pub async fn create(
 /// # Returns
 ///
 /// * `Ok(Wallet)` - The updated wallet
-/// * `Err(Error)` - Database operation error
+/// * `Err(Error)` - Wallet not found, name already used or database operation error
 pub async fn update(
     wallet_id: Uuid,
     user_id: Uuid,
     update_wallet: UpdateWallet,
     pool: &PgPool,
 ) -> Result<Wallet, Error> {
+    let mut tx = pool.begin().await?;
+
+    if let Some(name) = &update_wallet.name {
+        lock_user_wallets(user_id, &mut tx).await?;
+        ensure_name_available(user_id, name, Some(wallet_id), &mut tx).await?;
+    }
+
     let wallet = sqlx::query_as::<_, Wallet>(
         r#"
         UPDATE wallets
@@ -125,18 +263,33 @@ pub async fn update(
             name = COALESCE($1, name),
             currency = COALESCE($2, currency),
             wallet_type = COALESCE($3, wallet_type),
-            updated_at = NOW()
-        WHERE id = $4 AND user_id = $5
-        RETURNING id, user_id, name, balance, currency, wallet_type, created_at, updated_at
+            credit_limit = CASE WHEN $4 THEN $5 ELSE credit_limit END,
+            balance = CASE WHEN $8::INT IS NULL THEN balance ELSE ROUND(balance, $8) END,
+            initial_balance = CASE WHEN $8::INT IS NULL THEN initial_balance ELSE ROUND(initial_balance, $8) END
+        WHERE id = $6 AND user_id = $7
+        RETURNING id, user_id, name, balance, initial_balance, credit_limit, currency, wallet_type, created_at, updated_at
         "#,
     )
-    .bind(update_wallet.name)
+    .bind(update_wallet.name.as_deref().map(str::trim))
     .bind(update_wallet.currency.map(|c| c.as_str()))
     .bind(update_wallet.wallet_type.map(|wt| wt.as_str()))
+    .bind(update_wallet.credit_limit.is_some())
+    .bind(update_wallet.credit_limit.flatten())
     .bind(wallet_id)
     .bind(user_id)
-    .fetch_one(pool)
-    .await?;
+    .bind(update_wallet.balance_decimal_places())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e: sqlx::Error| -> Error {
+        match e {
+            sqlx::Error::RowNotFound => {
+                (StatusCode::NOT_FOUND, TranslationKey::WalletNotFound).into()
+            }
+            _ => e.into(),
+        }
+    })?;
+
+    tx.commit().await?;
 
     Ok(wallet)
 }
@@ -167,3 +320,47 @@ pub async fn delete(wallet_id: Uuid, user_id: Uuid, pool: &PgPool) -> Result<usi
 
     Ok(result.rows_affected() as usize)
 }
+
+/// Deletes all wallets of a user and every share granted to the user
+///
+/// Used when the user's account is removed. Shares of the deleted wallets are
+/// removed by the `ON DELETE CASCADE` on `wallet_shares`. Running it again for
+/// the same user deletes nothing, so the call can be retried safely.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the removed user
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of wallets deleted
+/// * `Err(sqlx::Error)` - Database operation error, kept raw so callers can tell
+///   transient failures apart
+pub async fn delete_all_by_user(user_id: Uuid, pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM wallet_shares
+        WHERE shared_with_user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .execute(&mut *tx)
+    .await?;
+
+    let result = sqlx::query(
+        r#"
+        DELETE FROM wallets
+        WHERE user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected() as usize)
+}
@@ -0,0 +1,146 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{
+    response::Error,
+    wallet_share::{SharePermission, WalletShare},
+};
+
+/// Shares a wallet with another user
+///
+/// Sharing a wallet with a user it is already shared with changes nothing,
+/// so the caller cannot tell a new share from an existing one. Nothing is
+/// shared if the wallet is not owned by `owner_id`.
+///
+/// # Arguments
+///
+/// * `wallet_id` - The UUID of the wallet to share
+/// * `owner_id` - The UUID of the wallet owner
+/// * `shared_with_user_id` - The UUID of the user receiving access
+/// * `permission` - The access level to grant
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(true)` - If a new share was created
+/// * `Ok(false)` - If the wallet was already shared with the user or is not owned by `owner_id`
+/// * `Err(Error)` - Database operation error
+pub async fn create(
+    wallet_id: Uuid,
+    owner_id: Uuid,
+    shared_with_user_id: Uuid,
+    permission: SharePermission,
+    pool: &PgPool,
+) -> Result<bool, Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO wallet_shares (wallet_id, owner_id, shared_with_user_id, permission)
+        SELECT id, user_id, $3, $4
+        FROM wallets
+        WHERE id = $1 AND user_id = $2
+        ON CONFLICT (wallet_id, shared_with_user_id) DO NOTHING
+        "#,
+    )
+    .bind(wallet_id)
+    .bind(owner_id)
+    .bind(shared_with_user_id)
+    .bind(permission.as_str())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Finds all shares of a wallet
+///
+/// # Arguments
+///
+/// * `wallet_id` - The UUID of the wallet
+/// * `owner_id` - The UUID of the wallet owner (for authorization)
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<WalletShare>)` - The shares of the wallet
+/// * `Err(Error)` - Database operation error
+pub async fn find_by_wallet(
+    wallet_id: Uuid,
+    owner_id: Uuid,
+    pool: &PgPool,
+) -> Result<Vec<WalletShare>, Error> {
+    let shares = sqlx::query_as::<_, WalletShare>(
+        r#"
+        SELECT id, wallet_id, owner_id, shared_with_user_id, permission, created_at
+        FROM wallet_shares
+        WHERE wallet_id = $1 AND owner_id = $2
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(wallet_id)
+    .bind(owner_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(shares)
+}
+
+/// Finds all shares a user granted on their wallets
+///
+/// # Arguments
+///
+/// * `owner_id` - The UUID of the wallet owner
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(Vec<WalletShare>)` - The shares of all the owner's wallets
+/// * `Err(Error)` - Database operation error
+pub async fn find_by_owner(owner_id: Uuid, pool: &PgPool) -> Result<Vec<WalletShare>, Error> {
+    let shares = sqlx::query_as::<_, WalletShare>(
+        r#"
+        SELECT id, wallet_id, owner_id, shared_with_user_id, permission, created_at
+        FROM wallet_shares
+        WHERE owner_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(owner_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(shares)
+}
+
+/// Revokes a wallet share
+///
+/// # Arguments
+///
+/// * `share_id` - The UUID of the share to revoke
+/// * `wallet_id` - The UUID of the shared wallet
+/// * `owner_id` - The UUID of the wallet owner (for authorization)
+/// * `pool` - Database connection pool
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of rows deleted (1 if successful)
+/// * `Err(Error)` - Database operation error
+pub async fn delete(
+    share_id: Uuid,
+    wallet_id: Uuid,
+    owner_id: Uuid,
+    pool: &PgPool,
+) -> Result<usize, Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM wallet_shares
+        WHERE id = $1 AND wallet_id = $2 AND owner_id = $3
+        "#,
+    )
+    .bind(share_id)
+    .bind(wallet_id)
+    .bind(owner_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() as usize)
+}
@@ -0,0 +1,78 @@
+pub mod cached_provider;
+pub mod http_provider;
+pub mod static_provider;
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use shared_types::{enums::Currency, money};
+
+use crate::models::response::Error;
+
+pub use cached_provider::CachedExchangeRateProvider;
+pub use http_provider::HttpExchangeRateProvider;
+pub use static_provider::StaticExchangeRateProvider;
+
+/// A snapshot of exchange rates relative to a base currency
+///
+/// Each rate states how many units of the quoted currency one unit of the
+/// base currency buys (e.g. base EUR, `USD -> 1.08`).
+///
+/// # Fields
+///
+/// * `base` - The base currency of the snapshot
+/// * `rates` - Rates for every supported currency, including the base (always 1)
+/// * `fetched_at` - When the rates were obtained from the provider
+/// * `stale` - Whether the rates are a cached copy served because the provider failed
+#[derive(Clone, Debug, Serialize)]
+pub struct ExchangeRates {
+    pub base: Currency,
+    pub rates: HashMap<Currency, Decimal>,
+    pub fetched_at: DateTime<Utc>,
+    pub stale: bool,
+}
+
+impl ExchangeRates {
+    /// Converts an amount expressed in `from` into the base currency
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount to convert
+    /// * `from` - The currency the amount is expressed in
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Decimal)` - The converted amount, rounded to the base currency's decimal places
+    /// * `None` - If the snapshot has no usable rate for `from`
+    pub fn convert_to_base(&self, amount: Decimal, from: Currency) -> Option<Decimal> {
+        if from == self.base {
+            return Some(amount);
+        }
+
+        let rate = self.rates.get(&from).filter(|rate| !rate.is_zero())?;
+
+        Some(money::round(amount / rate, self.base))
+    }
+}
+
+/// Source of currency exchange rates
+///
+/// Implementations are stored on the `AppState` so features needing FX rates
+/// (net worth, cross-currency transfers, summaries) share a single source.
+#[async_trait]
+pub trait ExchangeRateProvider: Send + Sync {
+    /// Returns the current exchange rates for the given base currency
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The currency the rates should be relative to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ExchangeRates)` - A snapshot of the rates for `base`
+    /// * `Err(Error)` - If no rates are available
+    async fn get_rates(&self, base: Currency) -> Result<ExchangeRates, Error>;
+}
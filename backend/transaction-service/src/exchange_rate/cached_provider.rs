@@ -0,0 +1,194 @@
+use std::{collections::HashMap, time::Duration};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shared_types::enums::Currency;
+use tokio::sync::RwLock;
+
+use super::{ExchangeRateProvider, ExchangeRates};
+use crate::models::response::Error;
+
+/// Exchange rate provider caching the results of another provider
+///
+/// Fresh entries are served from memory until the TTL expires. When the inner
+/// provider fails, the last known rates are served with `stale` set, and the
+/// error is only surfaced when nothing has been cached for the base yet.
+///
+/// # Fields
+///
+/// * `inner` - The provider the rates are fetched from
+/// * `ttl` - How long cached rates are considered fresh
+/// * `cache` - Last known rates per base currency
+pub struct CachedExchangeRateProvider {
+    inner: Box<dyn ExchangeRateProvider>,
+    ttl: Duration,
+    cache: RwLock<HashMap<Currency, ExchangeRates>>,
+}
+
+impl CachedExchangeRateProvider {
+    /// Creates a new caching provider
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The provider the rates are fetched from
+    /// * `ttl` - How long cached rates are considered fresh
+    ///
+    /// # Returns
+    ///
+    /// A new `CachedExchangeRateProvider`
+    pub fn new(inner: Box<dyn ExchangeRateProvider>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Checks whether a cached snapshot is still within the TTL
+    fn is_fresh(&self, rates: &ExchangeRates) -> bool {
+        (Utc::now() - rates.fetched_at)
+            .to_std()
+            .is_ok_and(|age| age < self.ttl)
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for CachedExchangeRateProvider {
+    async fn get_rates(&self, base: Currency) -> Result<ExchangeRates, Error> {
+        if let Some(rates) = self.cache.read().await.get(&base)
+            && self.is_fresh(rates)
+        {
+            return Ok(rates.clone());
+        }
+
+        match self.inner.get_rates(base).await {
+            Ok(rates) => {
+                self.cache.write().await.insert(base, rates.clone());
+                Ok(rates)
+            }
+            Err(e) => match self.cache.read().await.get(&base) {
+                Some(rates) => {
+                    tracing::warn!("Serving stale exchange rates for {}", base);
+                    Ok(ExchangeRates {
+                        stale: true,
+                        ..rates.clone()
+                    })
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    };
+
+    use axum::http::StatusCode;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::models::response::TranslationKey;
+
+    /// Provider counting its calls which can be switched to failing
+    struct MockProvider {
+        calls: Arc<AtomicUsize>,
+        failing: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl ExchangeRateProvider for MockProvider {
+        async fn get_rates(&self, base: Currency) -> Result<ExchangeRates, Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(Error::new(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    TranslationKey::ExchangeRatesUnavailable,
+                ));
+            }
+
+            Ok(ExchangeRates {
+                base,
+                rates: HashMap::from([(base, Decimal::ONE)]),
+                fetched_at: Utc::now(),
+                stale: false,
+            })
+        }
+    }
+
+    fn provider(
+        ttl: Duration,
+        failing: bool,
+    ) -> (
+        CachedExchangeRateProvider,
+        Arc<AtomicUsize>,
+        Arc<AtomicBool>,
+    ) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let failing = Arc::new(AtomicBool::new(failing));
+        let inner = MockProvider {
+            calls: calls.clone(),
+            failing: failing.clone(),
+        };
+
+        (
+            CachedExchangeRateProvider::new(Box::new(inner), ttl),
+            calls,
+            failing,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_cached_rates_are_reused_within_ttl() {
+        let (provider, calls, _) = provider(Duration::from_secs(60), false);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(!rates.stale);
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_per_base_currency() {
+        let (provider, calls, _) = provider(Duration::from_secs(60), false);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        provider.get_rates(Currency::Usd).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_rates_are_refetched() {
+        let (provider, calls, _) = provider(Duration::ZERO, false);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_rates_served_when_provider_fails() {
+        let (provider, _, failing) = provider(Duration::ZERO, false);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        failing.store(true, Ordering::SeqCst);
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert!(rates.stale);
+        assert_eq!(rates.base, Currency::Eur);
+    }
+
+    #[tokio::test]
+    async fn test_error_when_nothing_cached() {
+        let (provider, _, _) = provider(Duration::from_secs(60), true);
+
+        assert!(provider.get_rates(Currency::Eur).await.is_err());
+    }
+}
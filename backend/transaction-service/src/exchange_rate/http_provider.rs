@@ -0,0 +1,247 @@
+use std::{collections::HashMap, str::FromStr, time::Duration};
+
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use shared_types::enums::Currency;
+
+use super::{ExchangeRateProvider, ExchangeRates};
+use crate::models::response::{Error, TranslationKey};
+
+/// Response body of an ECB/exchangerate.host style rates API
+///
+/// # Fields
+///
+/// * `rates` - How many units of each quoted currency one unit of the base buys
+#[derive(Deserialize)]
+pub struct RatesResponse {
+    pub rates: HashMap<String, Decimal>,
+}
+
+impl RatesResponse {
+    /// Converts the API response into an `ExchangeRates` snapshot
+    ///
+    /// Currencies not supported by the application are ignored and the base
+    /// currency is always present with a rate of 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base currency that was requested
+    ///
+    /// # Returns
+    ///
+    /// The parsed `ExchangeRates`
+    pub fn into_exchange_rates(self, base: Currency) -> ExchangeRates {
+        let mut rates: HashMap<Currency, Decimal> = self
+            .rates
+            .into_iter()
+            .filter_map(|(code, rate)| Some((Currency::from_str(&code).ok()?, rate)))
+            .collect();
+        rates.insert(base, Decimal::ONE);
+
+        ExchangeRates {
+            base,
+            rates,
+            fetched_at: Utc::now(),
+            stale: false,
+        }
+    }
+}
+
+/// Exchange rate provider fetching live rates over HTTP
+///
+/// Requests `{api_url}?base={CODE}` and expects a JSON body with a `rates`
+/// object, as returned by ECB mirrors and exchangerate.host style APIs.
+/// Requests give up after the configured timeout, so a hanging API fails
+/// like an unreachable one.
+///
+/// # Fields
+///
+/// * `client` - HTTP client used for the requests
+/// * `api_url` - Endpoint of the rates API
+pub struct HttpExchangeRateProvider {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl HttpExchangeRateProvider {
+    /// Creates a new HTTP exchange rate provider
+    ///
+    /// # Arguments
+    ///
+    /// * `api_url` - Endpoint of the rates API
+    /// * `timeout` - How long a request to the rates API may take
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HttpExchangeRateProvider)` - The new provider
+    /// * `Err(reqwest::Error)` - If the HTTP client cannot be built
+    pub fn new(api_url: String, timeout: Duration) -> Result<Self, reqwest::Error> {
+        Ok(Self {
+            client: reqwest::Client::builder().timeout(timeout).build()?,
+            api_url,
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for HttpExchangeRateProvider {
+    async fn get_rates(&self, base: Currency) -> Result<ExchangeRates, Error> {
+        let unavailable = |e: reqwest::Error| -> Error {
+            tracing::error!("Failed to fetch exchange rates for {}: {}", base, e);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                TranslationKey::ExchangeRatesUnavailable,
+            )
+                .into()
+        };
+
+        let response = self
+            .client
+            .get(&self.api_url)
+            .query(&[("base", base.as_str())])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(unavailable)?
+            .json::<RatesResponse>()
+            .await
+            .map_err(unavailable)?;
+
+        Ok(response.into_exchange_rates(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    };
+
+    use axum::{Json, Router, extract::State, response::IntoResponse, routing::get};
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::exchange_rate::CachedExchangeRateProvider;
+
+    const RATES_OK: u8 = 0;
+    const RATES_ERROR: u8 = 1;
+    const RATES_HANGING: u8 = 2;
+
+    /// Rates API answering, failing or hanging depending on its mode
+    async fn mock_rates(State(mode): State<Arc<AtomicU8>>) -> impl IntoResponse {
+        match mode.load(Ordering::SeqCst) {
+            RATES_OK => Json(serde_json::json!({ "rates": { "USD": 1.08 } })).into_response(),
+            RATES_ERROR => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+            _ => {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                StatusCode::OK.into_response()
+            }
+        }
+    }
+
+    /// Starts a mock rates API and returns its URL and mode switch
+    async fn mock_rates_api() -> (String, Arc<AtomicU8>) {
+        let mode = Arc::new(AtomicU8::new(RATES_OK));
+        let app = Router::new()
+            .route("/latest", get(mock_rates))
+            .with_state(mode.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        (format!("http://{}/latest", address), mode)
+    }
+
+    fn http_provider(api_url: String) -> HttpExchangeRateProvider {
+        HttpExchangeRateProvider::new(api_url, Duration::from_millis(200)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rates_fetched_from_api() {
+        let (api_url, _) = mock_rates_api().await;
+
+        let rates = http_provider(api_url)
+            .get_rates(Currency::Eur)
+            .await
+            .unwrap();
+
+        assert_eq!(rates.rates[&Currency::Usd], dec!(1.08));
+    }
+
+    #[tokio::test]
+    async fn test_hanging_api_times_out() {
+        let (api_url, mode) = mock_rates_api().await;
+        mode.store(RATES_HANGING, Ordering::SeqCst);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            http_provider(api_url).get_rates(Currency::Eur),
+        )
+        .await
+        .expect("request should time out before the test does");
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stale_rates_served_when_api_times_out() {
+        let (api_url, mode) = mock_rates_api().await;
+        let provider =
+            CachedExchangeRateProvider::new(Box::new(http_provider(api_url)), Duration::ZERO);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        mode.store(RATES_HANGING, Ordering::SeqCst);
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert!(rates.stale);
+        assert_eq!(rates.rates[&Currency::Usd], dec!(1.08));
+    }
+
+    #[tokio::test]
+    async fn test_stale_rates_served_when_api_fails() {
+        let (api_url, mode) = mock_rates_api().await;
+        let provider =
+            CachedExchangeRateProvider::new(Box::new(http_provider(api_url)), Duration::ZERO);
+
+        provider.get_rates(Currency::Eur).await.unwrap();
+        mode.store(RATES_ERROR, Ordering::SeqCst);
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert!(rates.stale);
+        assert_eq!(rates.rates[&Currency::Usd], dec!(1.08));
+    }
+
+    #[test]
+    fn test_rates_response_deserialization() {
+        let json = r#"{
+            "base": "EUR",
+            "date": "2025-11-08",
+            "rates": { "USD": 1.0812, "RON": 4.9731, "JPY": 162.5 }
+        }"#;
+
+        let response: RatesResponse = serde_json::from_str(json).unwrap();
+        let rates = response.into_exchange_rates(Currency::Eur);
+
+        assert_eq!(rates.base, Currency::Eur);
+        assert!(!rates.stale);
+        assert_eq!(rates.rates[&Currency::Eur], Decimal::ONE);
+        assert_eq!(rates.rates[&Currency::Usd], dec!(1.0812));
+        assert_eq!(rates.rates[&Currency::Jpy], dec!(162.5));
+    }
+
+    #[test]
+    fn test_rates_response_ignores_unsupported_currencies() {
+        let json = r#"{ "rates": { "USD": 1.08, "CHF": 0.94, "BTC": 0.00001 } }"#;
+
+        let response: RatesResponse = serde_json::from_str(json).unwrap();
+        let rates = response.into_exchange_rates(Currency::Eur);
+
+        assert_eq!(rates.rates.len(), 2);
+        assert!(rates.rates.contains_key(&Currency::Usd));
+        assert!(rates.rates.contains_key(&Currency::Eur));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::{Decimal, dec};
+use shared_types::enums::Currency;
+
+use super::{ExchangeRateProvider, ExchangeRates};
+use crate::models::response::Error;
+
+/// Number of decimal places kept for cross rates
+const RATE_DECIMAL_PLACES: u32 = 8;
+
+/// Exchange rate provider backed by a fixed table of rates
+///
+/// Rates are stored relative to EUR and cross rates are derived from them.
+/// Used as the fallback when no live provider is configured and to keep
+/// tests deterministic.
+///
+/// # Fields
+///
+/// * `rates_per_eur` - How many units of each currency one EUR buys
+pub struct StaticExchangeRateProvider {
+    rates_per_eur: HashMap<Currency, Decimal>,
+}
+
+impl StaticExchangeRateProvider {
+    /// Creates a provider from a table of rates relative to EUR
+    ///
+    /// # Arguments
+    ///
+    /// * `rates_per_eur` - How many units of each currency one EUR buys
+    ///
+    /// # Returns
+    ///
+    /// A new `StaticExchangeRateProvider`
+    pub fn new(mut rates_per_eur: HashMap<Currency, Decimal>) -> Self {
+        rates_per_eur.insert(Currency::Eur, Decimal::ONE);
+        Self { rates_per_eur }
+    }
+
+    /// Computes the rates for `base` from the EUR table
+    fn rates_for(&self, base: Currency) -> HashMap<Currency, Decimal> {
+        let Some(base_per_eur) = self.rates_per_eur.get(&base).filter(|rate| !rate.is_zero())
+        else {
+            return HashMap::from([(base, Decimal::ONE)]);
+        };
+
+        self.rates_per_eur
+            .iter()
+            .map(|(currency, per_eur)| {
+                let rate = if *currency == base {
+                    Decimal::ONE
+                } else {
+                    (per_eur / base_per_eur).round_dp(RATE_DECIMAL_PLACES)
+                };
+                (*currency, rate)
+            })
+            .collect()
+    }
+}
+
+impl Default for StaticExchangeRateProvider {
+    /// Creates a provider with an approximate fallback rate table
+    fn default() -> Self {
+        Self::new(HashMap::from([
+            (Currency::Usd, dec!(1.08)),
+            (Currency::Gbp, dec!(0.85)),
+            (Currency::Cad, dec!(1.47)),
+            (Currency::Jpy, dec!(162.00)),
+            (Currency::Ron, dec!(4.97)),
+        ]))
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for StaticExchangeRateProvider {
+    async fn get_rates(&self, base: Currency) -> Result<ExchangeRates, Error> {
+        Ok(ExchangeRates {
+            base,
+            rates: self.rates_for(base),
+            fetched_at: Utc::now(),
+            stale: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_static_provider_eur_base() {
+        let provider = StaticExchangeRateProvider::default();
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert_eq!(rates.base, Currency::Eur);
+        assert_eq!(rates.rates[&Currency::Eur], Decimal::ONE);
+        assert_eq!(rates.rates[&Currency::Usd], dec!(1.08));
+        assert_eq!(rates.rates.len(), Currency::all().len());
+    }
+
+    #[tokio::test]
+    async fn test_static_provider_cross_rates() {
+        let provider = StaticExchangeRateProvider::new(HashMap::from([
+            (Currency::Usd, dec!(2)),
+            (Currency::Ron, dec!(5)),
+        ]));
+        let rates = provider.get_rates(Currency::Usd).await.unwrap();
+
+        assert_eq!(rates.rates[&Currency::Usd], Decimal::ONE);
+        assert_eq!(rates.rates[&Currency::Eur], dec!(0.5));
+        assert_eq!(rates.rates[&Currency::Ron], dec!(2.5));
+    }
+
+    #[tokio::test]
+    async fn test_convert_to_base() {
+        let provider = StaticExchangeRateProvider::new(HashMap::from([
+            (Currency::Usd, dec!(2)),
+            (Currency::Jpy, dec!(150)),
+        ]));
+        let rates = provider.get_rates(Currency::Eur).await.unwrap();
+
+        assert_eq!(
+            rates.convert_to_base(dec!(10), Currency::Usd),
+            Some(dec!(5))
+        );
+        assert_eq!(
+            rates.convert_to_base(dec!(100), Currency::Jpy),
+            Some(dec!(0.67))
+        );
+        assert_eq!(
+            rates.convert_to_base(dec!(12.34), Currency::Eur),
+            Some(dec!(12.34))
+        );
+        assert_eq!(rates.convert_to_base(dec!(1), Currency::Gbp), None);
+    }
+}
@@ -1 +1,3 @@
 pub mod auth_service;
+pub mod settings_service;
+pub mod transaction_service;
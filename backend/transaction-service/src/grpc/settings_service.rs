@@ -0,0 +1,90 @@
+pub mod service {
+    tonic::include_proto!("settings_service");
+}
+
+use std::str::FromStr;
+
+use shared_types::{enums::Currency, grpc::INTERNAL_SECRET_METADATA_KEY};
+use tonic::Request;
+use uuid::Uuid;
+
+use crate::AppState;
+
+use service::GetUserSettingsRequest;
+
+/// Fetches the preferred currency of a user from the settings service
+///
+/// The settings service is only used to pick a sensible default, so any
+/// failure (timeout, unreachable service, unknown currency) falls back to the
+/// configured default currency instead of failing the request.
+///
+/// # Arguments
+/// * `state` - Shared application state holding the settings client
+/// * `user_id` - The UUID of the user
+///
+/// # Returns
+/// The user's preferred currency, or the configured default currency
+pub async fn preferred_currency(state: &AppState, user_id: Uuid) -> Currency {
+    let default_currency = state.config.default_currency;
+
+    let mut request = Request::new(GetUserSettingsRequest {
+        user_id: user_id.to_string(),
+    });
+    let secret = match state.config.internal_grpc_secret.parse() {
+        Ok(secret) => secret,
+        Err(_) => {
+            tracing::error!("INTERNAL_GRPC_SECRET is not valid gRPC metadata");
+            return default_currency;
+        }
+    };
+    request
+        .metadata_mut()
+        .insert(INTERNAL_SECRET_METADATA_KEY, secret);
+
+    match state
+        .get_settings_service()
+        .get_user_settings(request)
+        .await
+    {
+        Ok(response) => currency_or_default(&response.into_inner().currency, default_currency),
+        Err(status) => {
+            tracing::warn!(
+                "Could not fetch settings for user {}, using {}: {}",
+                user_id,
+                default_currency,
+                status
+            );
+            default_currency
+        }
+    }
+}
+
+/// Parses a currency code from the settings service
+///
+/// # Arguments
+/// * `code` - The currency code returned by the settings service
+/// * `default_currency` - The currency to use if the code is not supported
+///
+/// # Returns
+/// The parsed currency, or `default_currency` if the code is not supported
+fn currency_or_default(code: &str, default_currency: Currency) -> Currency {
+    Currency::from_str(code)
+        .inspect_err(|e| tracing::warn!("{}, using {}", e, default_currency))
+        .unwrap_or(default_currency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_or_default_parses_lowercase_codes() {
+        assert_eq!(currency_or_default("eur", Currency::Usd), Currency::Eur);
+    }
+
+    #[test]
+    fn test_currency_or_default_falls_back_on_unknown_codes() {
+        assert_eq!(currency_or_default("xyz", Currency::Ron), Currency::Ron);
+        assert_eq!(currency_or_default("", Currency::Usd), Currency::Usd);
+    }
+}
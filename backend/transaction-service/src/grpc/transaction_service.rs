@@ -0,0 +1,102 @@
+pub mod service {
+    tonic::include_proto!("transaction_service");
+}
+
+use std::sync::Arc;
+
+use shared_types::grpc::{authorize, database_status};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::{AppState, database};
+
+use service::{
+    DeleteUserDataRequest, DeleteUserDataResponse, GetWalletBalancesRequest,
+    GetWalletBalancesResponse, WalletBalance, transaction_service_server::TransactionService,
+};
+
+/// gRPC service exposing transaction data to other internal services
+pub struct TransactionServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl TransactionServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl TransactionService for TransactionServiceImpl {
+    /// Returns the balances of all wallets of a user
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<GetWalletBalancesResponse>)` - The user's wallet balances
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn get_wallet_balances(
+        &self,
+        request: Request<GetWalletBalancesRequest>,
+    ) -> Result<Response<GetWalletBalancesResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized wallet balances request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::debug!("Received wallet balances request for user: {}", user_id);
+
+        let wallets = database::wallet::find_all_by_user(user_id, self.state.get_database_pool())
+            .await
+            .map_err(|_| {
+                tracing::error!("Failed to fetch wallets for user {}", user_id);
+                Status::internal("Failed to fetch wallets")
+            })?;
+
+        let wallets = wallets
+            .into_iter()
+            .map(|wallet| WalletBalance {
+                wallet_id: wallet.id.to_string(),
+                name: wallet.name,
+                balance: wallet.balance.to_string(),
+                currency: wallet.currency,
+                wallet_type: wallet.wallet_type,
+            })
+            .collect();
+
+        Ok(Response::new(GetWalletBalancesResponse { wallets }))
+    }
+
+    /// Deletes the wallets of a removed user and the shares granted to them
+    ///
+    /// # Arguments
+    /// * `request` - gRPC request containing the user ID
+    ///
+    /// # Returns
+    /// * `Ok(Response<DeleteUserDataResponse>)` - The number of deleted wallets
+    /// * `Err(Status)` - If the caller is not authorized, the user ID is invalid or the query fails
+    async fn delete_user_data(
+        &self,
+        request: Request<DeleteUserDataRequest>,
+    ) -> Result<Response<DeleteUserDataResponse>, Status> {
+        authorize(request.metadata(), &self.state.config.internal_grpc_secret)
+            .inspect_err(|_| tracing::warn!("Rejected unauthorized delete user data request"))?;
+
+        let user_id = Uuid::parse_str(&request.into_inner().user_id)
+            .map_err(|_| Status::invalid_argument("Invalid user ID"))?;
+        tracing::info!("Deleting wallets of removed user {}", user_id);
+
+        let deleted_wallets =
+            database::wallet::delete_all_by_user(user_id, self.state.get_database_pool())
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to delete wallets for user {}: {}", user_id, e);
+                    database_status(&e, "Failed to delete wallets")
+                })?;
+
+        Ok(Response::new(DeleteUserDataResponse {
+            deleted_wallets: deleted_wallets as u64,
+        }))
+    }
+}
@@ -1,8 +1,15 @@
-use crate::{config::Config, routes::make_app};
+use crate::{
+    config::Config,
+    grpc::transaction_service::{
+        TransactionServiceImpl, service::transaction_service_server::TransactionServiceServer,
+    },
+    routes::{make_app, make_state},
+};
 
 mod app_state;
 mod config;
 mod database;
+mod exchange_rate;
 mod grpc;
 mod models;
 mod routes;
@@ -27,37 +34,70 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize configuration from environment variables
     let config = Config::init();
     tracing::info!("✅ Configuration loaded successfully");
-    tracing::debug!("HTTP port: {}", config.transaction_http_port);
+    tracing::debug!(
+        "HTTP port: {}, gRPC port: {}",
+        config.transaction_http_port,
+        config.transaction_grpc_port
+    );
     tracing::debug!(
         "Auth service: {}:{}",
         config.auth_hostname,
         config.auth_grpc_port
     );
 
-    // Bind TCP listener to the configured port
-    let listener =
+    // Bind TCP listener to the configured HTTP port
+    let http_listener =
         tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.transaction_http_port))
             .await
-            .expect("Could not bind TcpListener.");
+            .expect("Could not bind TcpListener for HTTP.");
     tracing::info!(
         "✅ HTTP listener bound to port {}",
         config.transaction_http_port
     );
 
+    // Bind gRPC server to the configured gRPC port
+    let grpc_addr: std::net::SocketAddr = format!("0.0.0.0:{}", config.transaction_grpc_port)
+        .parse()
+        .expect("Invalid gRPC address");
+    tracing::info!("✅ gRPC address configured: {}", grpc_addr);
+
+    // Create the shared state used by both the HTTP routes and the gRPC service
+    let state = make_state(config).await;
+
     // Create the Axum application with all routes and middleware
-    let app = make_app(config).await.expect("Could not create app.");
-    tracing::info!("✅ Routes and middleware configured");
+    let app = make_app(state.clone());
+    tracing::info!("✅ HTTP routes configured");
+
+    let transaction_service = TransactionServiceImpl::new(state);
+    tracing::info!("✅ gRPC service initialized");
 
     tracing::info!(
-        "🚀 Server started successfully on port {}",
-        listener.local_addr()?.port()
+        "🚀 HTTP Server started on port {}",
+        http_listener.local_addr()?.port()
     );
-    tracing::info!("📡 Server accepting connections");
+    tracing::info!("🚀 gRPC Server starting on port {}", grpc_addr.port());
+
+    // Spawn HTTP server
+    let http_server = tokio::spawn(async move {
+        tracing::info!("📡 HTTP server accepting connections");
+        axum::serve(http_listener, app)
+            .await
+            .expect("Could not serve axum server.");
+    });
+
+    // Spawn gRPC server
+    let grpc_server = tokio::spawn(async move {
+        tracing::info!("📡 gRPC server accepting connections");
+        tonic::transport::Server::builder()
+            .add_service(TransactionServiceServer::new(transaction_service))
+            .serve(grpc_addr)
+            .await
+            .expect("Could not serve gRPC server");
+    });
 
-    // Start serving HTTP requests
-    axum::serve(listener, app)
-        .await
-        .expect("Could not serve axum server.");
+    // Wait for both servers
+    tracing::info!("✅ Both servers are running");
+    tokio::try_join!(http_server, grpc_server)?;
 
     Ok(())
 }
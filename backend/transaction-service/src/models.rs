@@ -1,2 +1,5 @@
+pub mod export;
+pub mod net_worth;
 pub mod response;
 pub mod wallet;
+pub mod wallet_share;
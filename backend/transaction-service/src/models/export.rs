@@ -0,0 +1,264 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::{wallet::Wallet, wallet_share::WalletShare};
+
+/// Version of the export document format
+///
+/// Bump whenever the structure changes so importers can handle old exports.
+pub const EXPORT_VERSION: u32 = 1;
+
+/// All data the transaction service stores for a user
+///
+/// # Fields
+///
+/// * `version` - Version of the export document format
+/// * `user_id` - The user the data belongs to
+/// * `exported_at` - When the export was generated
+/// * `wallets` - The user's wallets
+/// * `wallet_shares` - The shares the user granted on their wallets
+#[derive(Serialize)]
+pub struct UserExport {
+    pub version: u32,
+    pub user_id: Uuid,
+    pub exported_at: DateTime<Utc>,
+    pub wallets: Vec<ExportedWallet>,
+    pub wallet_shares: Vec<ExportedWalletShare>,
+}
+
+impl UserExport {
+    /// Creates an export document for a user
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user the data belongs to
+    /// * `wallets` - The user's wallets
+    /// * `wallet_shares` - The shares the user granted on their wallets
+    ///
+    /// # Returns
+    ///
+    /// A new `UserExport` stamped with the current time
+    pub fn new(user_id: Uuid, wallets: Vec<Wallet>, wallet_shares: Vec<WalletShare>) -> Self {
+        Self {
+            version: EXPORT_VERSION,
+            user_id,
+            exported_at: Utc::now(),
+            wallets: wallets.into_iter().map(ExportedWallet::from).collect(),
+            wallet_shares: wallet_shares
+                .into_iter()
+                .map(ExportedWalletShare::from)
+                .collect(),
+        }
+    }
+}
+
+/// A wallet in the export document
+///
+/// Timestamps are stored without a time zone in UTC and exported as RFC 3339.
+#[derive(Serialize)]
+pub struct ExportedWallet {
+    pub id: Uuid,
+    pub name: String,
+    pub balance: Decimal,
+    pub initial_balance: Decimal,
+    pub credit_limit: Option<Decimal>,
+    pub currency: String,
+    pub wallet_type: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Wallet> for ExportedWallet {
+    fn from(wallet: Wallet) -> Self {
+        Self {
+            id: wallet.id,
+            name: wallet.name,
+            balance: wallet.balance,
+            initial_balance: wallet.initial_balance,
+            credit_limit: wallet.credit_limit,
+            currency: wallet.currency,
+            wallet_type: wallet.wallet_type,
+            created_at: wallet.created_at.and_utc(),
+            updated_at: wallet.updated_at.and_utc(),
+        }
+    }
+}
+
+/// A wallet share in the export document
+#[derive(Serialize)]
+pub struct ExportedWalletShare {
+    pub id: Uuid,
+    pub wallet_id: Uuid,
+    pub shared_with_user_id: Uuid,
+    pub permission: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WalletShare> for ExportedWalletShare {
+    fn from(share: WalletShare) -> Self {
+        Self {
+            id: share.id,
+            wallet_id: share.wallet_id,
+            shared_with_user_id: share.shared_with_user_id,
+            permission: share.permission,
+            created_at: share.created_at.and_utc(),
+        }
+    }
+}
+
+/// Limits how often each user can request an export
+///
+/// # Fields
+///
+/// * `interval` - Minimum time between two exports of the same user
+/// * `last_exports` - When each user last exported their data
+pub struct ExportRateLimiter {
+    interval: Duration,
+    last_exports: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+}
+
+impl ExportRateLimiter {
+    /// Creates a new rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Minimum time between two exports of the same user
+    ///
+    /// # Returns
+    ///
+    /// A new `ExportRateLimiter`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_exports: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records an export for a user if the interval has passed
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user requesting the export
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// `true` if the export may proceed, `false` if the user exported too recently
+    pub fn try_acquire(&self, user_id: Uuid, now: DateTime<Utc>) -> bool {
+        let mut last_exports = self
+            .last_exports
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(last_export) = last_exports.get(&user_id)
+            && now - *last_export < self.interval
+        {
+            return false;
+        }
+
+        last_exports.retain(|_, last_export| now - *last_export < self.interval);
+        last_exports.insert(user_id, now);
+        true
+    }
+
+    /// Gives back an export slot that was acquired for an export that failed
+    ///
+    /// The slot is only removed if it is still the one acquired at `acquired_at`,
+    /// so a newer export of the same user is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user whose export failed
+    /// * `acquired_at` - The time the slot was acquired with
+    pub fn release(&self, user_id: Uuid, acquired_at: DateTime<Utc>) {
+        let mut last_exports = self
+            .last_exports
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if last_exports.get(&user_id) == Some(&acquired_at) {
+            last_exports.remove(&user_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use rust_decimal::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_export_document_structure() {
+        let user_id = Uuid::new_v4();
+        let wallet = Wallet {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "Main".into(),
+            balance: dec!(12.50),
+            initial_balance: dec!(10),
+            credit_limit: None,
+            currency: "EUR".into(),
+            wallet_type: "Account".into(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        };
+
+        let export = UserExport::new(user_id, vec![wallet], vec![]);
+        let json = serde_json::to_value(&export).unwrap();
+
+        assert_eq!(json["version"], EXPORT_VERSION);
+        assert_eq!(json["user_id"], user_id.to_string());
+        assert_eq!(json["wallets"][0]["name"], "Main");
+        assert_eq!(json["wallets"][0]["created_at"], "1970-01-01T00:00:00Z");
+        assert!(json["wallets"][0].get("user_id").is_none());
+        assert!(json["wallet_shares"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rate_limiter_blocks_within_interval() {
+        let limiter = ExportRateLimiter::new(Duration::hours(1));
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        assert!(limiter.try_acquire(user_id, now));
+        assert!(!limiter.try_acquire(user_id, now + Duration::minutes(59)));
+        assert!(limiter.try_acquire(user_id, now + Duration::hours(1)));
+    }
+
+    #[test]
+    fn test_rate_limiter_release_frees_the_slot() {
+        let limiter = ExportRateLimiter::new(Duration::hours(1));
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        assert!(limiter.try_acquire(user_id, now));
+        limiter.release(user_id, now);
+        assert!(limiter.try_acquire(user_id, now + Duration::minutes(1)));
+    }
+
+    #[test]
+    fn test_rate_limiter_release_keeps_newer_slot() {
+        let limiter = ExportRateLimiter::new(Duration::hours(1));
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        assert!(limiter.try_acquire(user_id, now));
+        limiter.release(user_id, now - Duration::hours(2));
+        assert!(!limiter.try_acquire(user_id, now + Duration::minutes(1)));
+    }
+
+    #[test]
+    fn test_rate_limiter_is_per_user() {
+        let limiter = ExportRateLimiter::new(Duration::hours(1));
+        let now = Utc::now();
+
+        assert!(limiter.try_acquire(Uuid::new_v4(), now));
+        assert!(limiter.try_acquire(Uuid::new_v4(), now));
+    }
+}
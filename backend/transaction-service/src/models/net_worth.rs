@@ -0,0 +1,234 @@
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared_types::{
+    enums::{Currency, WalletType},
+    money,
+};
+use uuid::Uuid;
+
+use crate::{exchange_rate::ExchangeRates, models::wallet::Wallet};
+
+/// Query parameters for the net worth endpoint
+///
+/// # Fields
+///
+/// * `currency` - Currency code the net worth should be expressed in (defaults to the
+///   user's preferred currency)
+#[derive(Deserialize)]
+pub struct NetWorthQuery {
+    pub currency: Option<String>,
+}
+
+/// A single wallet's contribution to the net worth
+///
+/// # Fields
+///
+/// * `wallet_id` - Unique identifier of the wallet
+/// * `name` - Name of the wallet
+/// * `wallet_type` - Type of the wallet
+/// * `balance` - Balance of the wallet in its own currency
+/// * `currency` - Currency of the wallet
+/// * `converted_balance` - Balance converted into the target currency
+/// * `contribution` - Signed amount added to the total (negative for debts)
+#[derive(Debug, Serialize)]
+pub struct NetWorthWallet {
+    pub wallet_id: Uuid,
+    pub name: String,
+    pub wallet_type: String,
+    pub balance: Decimal,
+    pub currency: String,
+    pub converted_balance: Decimal,
+    pub contribution: Decimal,
+}
+
+/// The user's total net worth across all wallets
+///
+/// # Fields
+///
+/// * `currency` - Target currency of the totals
+/// * `total` - Sum of all wallet contributions
+/// * `wallets` - Per-wallet converted amounts
+/// * `excluded_wallet_ids` - Wallets left out of the total because their currency has no rate
+/// * `rates` - Rate snapshot used for the conversion (relative to `currency`)
+/// * `rates_fetched_at` - When the rate snapshot was obtained
+/// * `rates_stale` - Whether the rate snapshot is a stale cached copy
+#[derive(Debug, Serialize)]
+pub struct NetWorth {
+    pub currency: Currency,
+    pub total: Decimal,
+    pub wallets: Vec<NetWorthWallet>,
+    pub excluded_wallet_ids: Vec<Uuid>,
+    pub rates: HashMap<Currency, Decimal>,
+    pub rates_fetched_at: DateTime<Utc>,
+    pub rates_stale: bool,
+}
+
+impl NetWorth {
+    /// Computes the net worth of a set of wallets
+    ///
+    /// Credit cards and loans represent debt, so their balances are always
+    /// subtracted from the total regardless of the sign they are stored with.
+    /// Wallets whose currency has no rate in the snapshot are left out of the
+    /// total and listed in `excluded_wallet_ids`, so the total is never
+    /// silently incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallets` - The user's wallets
+    /// * `rates` - Exchange rates relative to the target currency
+    ///
+    /// # Returns
+    ///
+    /// The computed `NetWorth` in the rates' base currency
+    pub fn compute(wallets: Vec<Wallet>, rates: ExchangeRates) -> Self {
+        let mut converted = Vec::with_capacity(wallets.len());
+        let mut excluded_wallet_ids = Vec::new();
+
+        for wallet in wallets {
+            let Some(converted_balance) = Currency::from_str(&wallet.currency)
+                .ok()
+                .and_then(|currency| rates.convert_to_base(wallet.balance, currency))
+            else {
+                excluded_wallet_ids.push(wallet.id);
+                continue;
+            };
+            let contribution = if is_debt(&wallet.wallet_type) {
+                -converted_balance.abs()
+            } else {
+                converted_balance
+            };
+
+            converted.push(NetWorthWallet {
+                wallet_id: wallet.id,
+                name: wallet.name,
+                wallet_type: wallet.wallet_type,
+                balance: wallet.balance,
+                currency: wallet.currency,
+                converted_balance,
+                contribution,
+            });
+        }
+
+        Self {
+            currency: rates.base,
+            total: money::sum(converted.iter().map(|wallet| wallet.contribution)),
+            wallets: converted,
+            excluded_wallet_ids,
+            rates: rates.rates,
+            rates_fetched_at: rates.fetched_at,
+            rates_stale: rates.stale,
+        }
+    }
+}
+
+/// Checks whether a stored wallet type represents debt
+fn is_debt(wallet_type: &str) -> bool {
+    wallet_type == WalletType::CreditCard.as_str() || wallet_type == WalletType::Loan.as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use rust_decimal::dec;
+
+    use super::*;
+
+    fn wallet(balance: Decimal, currency: Currency, wallet_type: WalletType) -> Wallet {
+        Wallet {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            name: wallet_type.to_string(),
+            balance,
+            initial_balance: balance,
+            credit_limit: None,
+            currency: currency.to_string(),
+            wallet_type: wallet_type.to_string(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn eur_rates() -> ExchangeRates {
+        ExchangeRates {
+            base: Currency::Eur,
+            rates: HashMap::from([
+                (Currency::Eur, Decimal::ONE),
+                (Currency::Usd, dec!(2)),
+                (Currency::Ron, dec!(5)),
+            ]),
+            fetched_at: Utc::now(),
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_net_worth_converts_into_target_currency() {
+        let net_worth = NetWorth::compute(
+            vec![
+                wallet(dec!(100), Currency::Eur, WalletType::Account),
+                wallet(dec!(50), Currency::Usd, WalletType::Savings),
+                wallet(dec!(500), Currency::Ron, WalletType::Deposit),
+            ],
+            eur_rates(),
+        );
+
+        assert_eq!(net_worth.currency, Currency::Eur);
+        assert_eq!(net_worth.wallets.len(), 3);
+        assert_eq!(net_worth.wallets[1].converted_balance, dec!(25));
+        assert_eq!(net_worth.wallets[2].converted_balance, dec!(100));
+        assert_eq!(net_worth.total, dec!(225));
+    }
+
+    #[test]
+    fn test_net_worth_debts_are_negative() {
+        let net_worth = NetWorth::compute(
+            vec![
+                wallet(dec!(1000), Currency::Eur, WalletType::Account),
+                wallet(dec!(200), Currency::Usd, WalletType::CreditCard),
+                wallet(dec!(-300), Currency::Eur, WalletType::Loan),
+            ],
+            eur_rates(),
+        );
+
+        assert_eq!(net_worth.wallets[1].contribution, dec!(-100));
+        assert_eq!(net_worth.wallets[2].contribution, dec!(-300));
+        assert_eq!(net_worth.total, dec!(600));
+    }
+
+    #[test]
+    fn test_net_worth_lists_wallets_without_rate() {
+        let without_rate = wallet(dec!(10), Currency::Gbp, WalletType::Account);
+        let without_rate_id = without_rate.id;
+        let mut unknown_currency = wallet(dec!(10), Currency::Eur, WalletType::Account);
+        unknown_currency.currency = "XYZ".into();
+        let unknown_currency_id = unknown_currency.id;
+
+        let net_worth = NetWorth::compute(
+            vec![
+                wallet(dec!(10), Currency::Eur, WalletType::Account),
+                without_rate,
+                unknown_currency,
+            ],
+            eur_rates(),
+        );
+
+        assert_eq!(net_worth.wallets.len(), 1);
+        assert_eq!(net_worth.total, dec!(10));
+        assert_eq!(
+            net_worth.excluded_wallet_ids,
+            vec![without_rate_id, unknown_currency_id]
+        );
+    }
+
+    #[test]
+    fn test_net_worth_empty() {
+        let net_worth = NetWorth::compute(vec![], eur_rates());
+
+        assert!(net_worth.wallets.is_empty());
+        assert!(net_worth.excluded_wallet_ids.is_empty());
+        assert_eq!(net_worth.total, Decimal::ZERO);
+    }
+}
@@ -1,2 +1,4 @@
 // Re-export shared types
-pub use shared_types::{DatabaseConnection, Error, Health, HealthStatus, TranslationKey};
+pub use shared_types::{
+    DatabaseConnection, Error, Health, HealthStatus, ServiceConnection, TranslationKey,
+};
@@ -1,6 +1,11 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use shared_types::enums::{Currency, WalletType};
+use shared_types::{
+    enums::{Currency, WalletType},
+    money,
+};
+
+use crate::models::response::TranslationKey;
 use sqlx::FromRow;
 use uuid::Uuid;
 
@@ -15,6 +20,8 @@ use uuid::Uuid;
 /// * `user_id` - Unique identifier of the user who owns this wallet
 /// * `name` - Name of the wallet (e.g., "Savings", "Checking")
 /// * `balance` - Current balance of the wallet
+/// * `initial_balance` - Opening balance the wallet was created with
+/// * `credit_limit` - Optional credit limit for credit card and loan wallets
 /// * `currency` - Currency code for the wallet matching shared-types Currency enum (USD, EUR, GBP, CAD, JPY, RON)
 /// * `wallet_type` - Type of wallet matching shared-types WalletType enum (Account, Savings, Deposit, CreditCard, Loan)
 /// * `created_at` - Timestamp when the wallet was created
@@ -25,12 +32,121 @@ pub struct Wallet {
     pub user_id: Uuid,
     pub name: String,
     pub balance: rust_decimal::Decimal,
+    pub initial_balance: rust_decimal::Decimal,
+    pub credit_limit: Option<rust_decimal::Decimal>,
     pub currency: String,
     pub wallet_type: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
 
+impl Wallet {
+    /// Computes the credit still available on a credit card or loan wallet
+    ///
+    /// The balance of debt wallets is the amount owed, so the available
+    /// credit is the limit minus the absolute balance, regardless of the sign
+    /// the balance is stored with.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Decimal)` - The available credit for debt wallets with a credit limit
+    /// * `None` - If the wallet is not a debt wallet or has no credit limit
+    pub fn available(&self) -> Option<rust_decimal::Decimal> {
+        let is_debt = self.wallet_type == WalletType::CreditCard.as_str()
+            || self.wallet_type == WalletType::Loan.as_str();
+        if !is_debt {
+            return None;
+        }
+
+        self.credit_limit
+            .map(|credit_limit| credit_limit - self.balance.abs())
+    }
+}
+
+/// Maximum length of a wallet name in characters
+pub const WALLET_NAME_MAX_LENGTH: usize = 64;
+
+/// Validates a wallet name
+///
+/// Leading and trailing whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `name` - The wallet name to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - If the name is between 1 and `WALLET_NAME_MAX_LENGTH` characters
+/// * `Err(TranslationKey)` - If the name is empty or too long
+pub fn validate_wallet_name(name: &str) -> Result<(), TranslationKey> {
+    let length = name.trim().chars().count();
+    if length == 0 || length > WALLET_NAME_MAX_LENGTH {
+        return Err(TranslationKey::WalletNameInvalid);
+    }
+
+    Ok(())
+}
+
+/// Checks whether a user already owns the maximum number of wallets
+///
+/// # Arguments
+///
+/// * `wallet_count` - Number of wallets the user currently owns
+/// * `max_wallets` - Maximum number of wallets allowed, 0 for unlimited
+///
+/// # Returns
+///
+/// `true` if another wallet may not be created
+pub fn is_wallet_limit_reached(wallet_count: usize, max_wallets: usize) -> bool {
+    max_wallets != 0 && wallet_count >= max_wallets
+}
+
+/// Response struct for wallets
+///
+/// This is used for API responses to clients and adds the computed
+/// available credit to the stored wallet fields.
+///
+/// # Fields
+///
+/// * `wallet` - The stored wallet
+/// * `available` - Credit still available for credit card and loan wallets
+/// * `shared_by` - The owner of the wallet when it is shared with the viewer
+#[derive(Serialize)]
+pub struct WalletResponse {
+    #[serde(flatten)]
+    pub wallet: Wallet,
+    pub available: Option<rust_decimal::Decimal>,
+    pub shared_by: Option<Uuid>,
+}
+
+impl WalletResponse {
+    /// Creates the response for a wallet another user shared with the viewer
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - The shared wallet
+    ///
+    /// # Returns
+    ///
+    /// A `WalletResponse` with `shared_by` set to the wallet owner
+    pub fn shared(wallet: Wallet) -> Self {
+        Self {
+            shared_by: Some(wallet.user_id),
+            ..Self::from(wallet)
+        }
+    }
+}
+
+impl From<Wallet> for WalletResponse {
+    fn from(wallet: Wallet) -> Self {
+        Self {
+            available: wallet.available(),
+            shared_by: None,
+            wallet,
+        }
+    }
+}
+
 /// Represents a request to create a new wallet
 ///
 /// This struct is used when creating a new wallet.
@@ -39,16 +155,45 @@ pub struct Wallet {
 ///
 /// * `name` - Name of the wallet
 /// * `balance` - Optional initial balance (defaults to 0.00)
-/// * `currency` - Currency for the wallet (enum type-safe)
+/// * `currency` - Optional currency for the wallet (defaults to the user's preferred currency)
 /// * `wallet_type` - Type of wallet (enum type-safe, defaults to Account)
+/// * `credit_limit` - Optional credit limit for credit card and loan wallets
 #[derive(Deserialize)]
 pub struct CreateWallet {
     pub name: String,
     #[serde(default)]
     pub balance: Option<rust_decimal::Decimal>,
-    pub currency: Currency,
+    #[serde(default)]
+    pub currency: Option<Currency>,
     #[serde(default)]
     pub wallet_type: WalletType,
+    #[serde(default)]
+    pub credit_limit: Option<rust_decimal::Decimal>,
+}
+
+impl CreateWallet {
+    /// Returns the opening balance rounded to the currency's precision
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency the wallet is created with
+    ///
+    /// # Returns
+    ///
+    /// The requested balance (0 when omitted) rounded to the currency's decimal places
+    pub fn opening_balance(&self, currency: Currency) -> rust_decimal::Decimal {
+        money::round(self.balance.unwrap_or_default(), currency)
+    }
+
+    /// Validates the wallet creation data
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the data is valid
+    /// * `Err(TranslationKey)` - If the data is not valid and a translation key
+    pub fn validate(&self) -> Result<(), TranslationKey> {
+        validate_wallet_name(&self.name)
+    }
 }
 
 /// Represents updates to a wallet
@@ -61,18 +206,69 @@ pub struct CreateWallet {
 /// * `name` - Optional new name for the wallet
 /// * `currency` - Optional new currency (enum type-safe)
 /// * `wallet_type` - Optional new wallet type (enum type-safe)
+/// * `credit_limit` - Optional new credit limit, `null` removes the limit
 #[derive(Deserialize)]
 pub struct UpdateWallet {
     pub name: Option<String>,
     pub currency: Option<Currency>,
     pub wallet_type: Option<WalletType>,
+    #[serde(default, deserialize_with = "shared_types::de::nullable")]
+    pub credit_limit: Option<Option<rust_decimal::Decimal>>,
+}
+
+impl UpdateWallet {
+    /// Validates the wallet update data
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the data is valid
+    /// * `Err(TranslationKey)` - If the data is not valid and a translation key
+    pub fn validate(&self) -> Result<(), TranslationKey> {
+        match &self.name {
+            Some(name) => validate_wallet_name(name),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the decimal places the wallet's balances are rounded to
+    ///
+    /// Changing the currency rounds the balance and initial balance to the
+    /// new currency's precision, e.g. to whole yen for JPY.
+    ///
+    /// # Returns
+    ///
+    /// The new currency's decimal places, `None` if the currency is not changed
+    pub fn balance_decimal_places(&self) -> Option<i32> {
+        self.currency
+            .map(|currency| currency.decimal_places() as i32)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal::dec;
     use serde_json;
 
+    fn wallet(
+        balance: rust_decimal::Decimal,
+        credit_limit: Option<rust_decimal::Decimal>,
+        wallet_type: WalletType,
+    ) -> Wallet {
+        Wallet {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            name: wallet_type.to_string(),
+            balance,
+            initial_balance: balance,
+            credit_limit,
+            currency: Currency::Eur.to_string(),
+            wallet_type: wallet_type.to_string(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
     #[test]
     fn test_create_wallet_deserialization() {
         let json = r#"{
@@ -83,7 +279,7 @@ mod tests {
 
         let create_wallet: CreateWallet = serde_json::from_str(json).unwrap();
         assert_eq!(create_wallet.name, "Savings");
-        assert_eq!(create_wallet.currency, Currency::Eur);
+        assert_eq!(create_wallet.currency, Some(Currency::Eur));
         assert!(create_wallet.balance.is_some());
     }
 
@@ -96,7 +292,7 @@ mod tests {
 
         let create_wallet: CreateWallet = serde_json::from_str(json).unwrap();
         assert_eq!(create_wallet.name, "Checking");
-        assert_eq!(create_wallet.currency, Currency::Gbp);
+        assert_eq!(create_wallet.currency, Some(Currency::Gbp));
         assert!(create_wallet.balance.is_none());
     }
 
@@ -121,5 +317,163 @@ mod tests {
         assert_eq!(update.name, Some("Updated Name".to_string()));
         assert_eq!(update.currency, None);
         assert_eq!(update.wallet_type, None);
+        assert_eq!(update.credit_limit, None);
+    }
+
+    #[test]
+    fn test_update_wallet_null_credit_limit_clears_it() {
+        let update: UpdateWallet = serde_json::from_str(r#"{ "credit_limit": null }"#).unwrap();
+        assert_eq!(update.credit_limit, Some(None));
+
+        let update: UpdateWallet = serde_json::from_str(r#"{ "credit_limit": 500 }"#).unwrap();
+        assert_eq!(update.credit_limit, Some(Some(dec!(500))));
+    }
+
+    #[test]
+    fn test_currency_change_from_usd_to_jpy_rounds_balances() {
+        let mut wallet = wallet(dec!(1234.56), None, WalletType::Account);
+        wallet.currency = Currency::Usd.to_string();
+        wallet.initial_balance = dec!(99.49);
+
+        let update: UpdateWallet = serde_json::from_str(r#"{ "currency": "JPY" }"#).unwrap();
+        let decimal_places = update.balance_decimal_places().unwrap();
+        assert_eq!(decimal_places, 0);
+
+        // The update rounds both balances with ROUND(col, decimal_places)
+        assert_eq!(wallet.balance.round_dp(decimal_places as u32), dec!(1235));
+        assert_eq!(
+            wallet.initial_balance.round_dp(decimal_places as u32),
+            dec!(99)
+        );
+    }
+
+    #[test]
+    fn test_balances_not_rounded_without_currency_change() {
+        let update: UpdateWallet = serde_json::from_str(r#"{ "name": "Cash" }"#).unwrap();
+        assert_eq!(update.balance_decimal_places(), None);
+
+        let update: UpdateWallet = serde_json::from_str(r#"{ "currency": "EUR" }"#).unwrap();
+        assert_eq!(update.balance_decimal_places(), Some(2));
+    }
+
+    #[test]
+    fn test_opening_balance_rounded_to_currency() {
+        let create_wallet: CreateWallet =
+            serde_json::from_str(r#"{ "name": "Yen", "balance": 1234.56 }"#).unwrap();
+        assert_eq!(create_wallet.opening_balance(Currency::Jpy), dec!(1235));
+        assert_eq!(create_wallet.opening_balance(Currency::Eur), dec!(1234.56));
+
+        let create_wallet: CreateWallet =
+            serde_json::from_str(r#"{ "name": "Cash", "balance": 10.005 }"#).unwrap();
+        assert_eq!(create_wallet.opening_balance(Currency::Usd), dec!(10.00));
+
+        let create_wallet: CreateWallet = serde_json::from_str(r#"{ "name": "Empty" }"#).unwrap();
+        assert_eq!(create_wallet.opening_balance(Currency::Eur), dec!(0));
+    }
+
+    #[test]
+    fn test_initial_balance_kept_when_balance_changes() {
+        let create_wallet: CreateWallet =
+            serde_json::from_str(r#"{ "name": "Savings", "balance": 500 }"#).unwrap();
+        // The insert stores the opening balance as both balance and initial_balance
+        let mut savings = wallet(
+            create_wallet.opening_balance(Currency::Eur),
+            None,
+            WalletType::Savings,
+        );
+        assert_eq!(savings.initial_balance, dec!(500));
+
+        savings.balance -= dec!(180);
+
+        let json = serde_json::to_value(WalletResponse::from(savings)).unwrap();
+        assert_eq!(json["balance"], "320");
+        assert_eq!(json["initial_balance"], "500");
+    }
+
+    #[test]
+    fn test_create_wallet_currency_is_optional() {
+        let create_wallet: CreateWallet =
+            serde_json::from_str(r#"{ "name": "Cash", "currency": "RON" }"#).unwrap();
+        assert_eq!(create_wallet.currency, Some(Currency::Ron));
+
+        let create_wallet: CreateWallet = serde_json::from_str(r#"{ "name": "Cash" }"#).unwrap();
+        assert_eq!(create_wallet.currency, None);
+    }
+
+    #[test]
+    fn test_validate_wallet_name() {
+        assert!(validate_wallet_name("Savings").is_ok());
+        assert!(validate_wallet_name(&"a".repeat(WALLET_NAME_MAX_LENGTH)).is_ok());
+        assert!(validate_wallet_name(&"ă".repeat(WALLET_NAME_MAX_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_wallet_name_rejects_empty_and_too_long() {
+        assert!(matches!(
+            validate_wallet_name(""),
+            Err(TranslationKey::WalletNameInvalid)
+        ));
+        assert!(validate_wallet_name("   ").is_err());
+        assert!(validate_wallet_name(&"a".repeat(WALLET_NAME_MAX_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_update_wallet_validate_without_name() {
+        let update: UpdateWallet = serde_json::from_str(r#"{ "currency": "EUR" }"#).unwrap();
+        assert!(update.validate().is_ok());
+
+        let update: UpdateWallet = serde_json::from_str(r#"{ "name": "" }"#).unwrap();
+        assert!(update.validate().is_err());
+    }
+
+    #[test]
+    fn test_wallet_limit_boundary() {
+        assert!(!is_wallet_limit_reached(49, 50));
+        assert!(is_wallet_limit_reached(50, 50));
+        assert!(is_wallet_limit_reached(51, 50));
+    }
+
+    #[test]
+    fn test_wallet_limit_zero_is_unlimited() {
+        assert!(!is_wallet_limit_reached(0, 0));
+        assert!(!is_wallet_limit_reached(10_000, 0));
+    }
+
+    #[test]
+    fn test_available_credit_for_debt_wallets() {
+        let card = wallet(dec!(250), Some(dec!(1000)), WalletType::CreditCard);
+        assert_eq!(card.available(), Some(dec!(750)));
+
+        let loan = wallet(dec!(-4000), Some(dec!(5000)), WalletType::Loan);
+        assert_eq!(loan.available(), Some(dec!(1000)));
+    }
+
+    #[test]
+    fn test_available_credit_not_applicable() {
+        let card = wallet(dec!(250), None, WalletType::CreditCard);
+        assert_eq!(card.available(), None);
+
+        let account = wallet(dec!(250), Some(dec!(1000)), WalletType::Account);
+        assert_eq!(account.available(), None);
+    }
+
+    #[test]
+    fn test_wallet_response_serialization() {
+        let card = wallet(dec!(300), Some(dec!(500)), WalletType::CreditCard);
+        let json = serde_json::to_value(WalletResponse::from(card)).unwrap();
+
+        assert_eq!(json["wallet_type"], "CreditCard");
+        assert_eq!(json["credit_limit"], "500");
+        assert_eq!(json["available"], "200");
+        assert!(json["shared_by"].is_null());
+    }
+
+    #[test]
+    fn test_shared_wallet_response_marks_owner() {
+        let account = wallet(dec!(10), None, WalletType::Account);
+        let owner_id = account.user_id;
+        let response = WalletResponse::shared(account);
+
+        assert_eq!(response.shared_by, Some(owner_id));
     }
 }
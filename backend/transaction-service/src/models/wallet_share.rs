@@ -0,0 +1,85 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Access level granted by a wallet share
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SharePermission {
+    /// The user can see the wallet but not modify it
+    #[default]
+    ReadOnly,
+}
+
+impl SharePermission {
+    /// Returns the permission as it is stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SharePermission::ReadOnly => "ReadOnly",
+        }
+    }
+}
+
+/// Represents a wallet share stored in the database
+///
+/// This struct maps to the `wallet_shares` table and grants a user access
+/// to a wallet owned by someone else.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier of the share
+/// * `wallet_id` - The shared wallet
+/// * `owner_id` - The user who owns the wallet and created the share
+/// * `shared_with_user_id` - The user the wallet is shared with
+/// * `permission` - Access level matching `SharePermission` (ReadOnly)
+/// * `created_at` - Timestamp when the share was created
+#[derive(FromRow, Clone, Serialize)]
+pub struct WalletShare {
+    pub id: Uuid,
+    pub wallet_id: Uuid,
+    pub owner_id: Uuid,
+    pub shared_with_user_id: Uuid,
+    pub permission: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// Represents a request to share a wallet
+///
+/// # Fields
+///
+/// * `email` - Email of the account the wallet should be shared with
+/// * `permission` - Access level to grant (defaults to ReadOnly)
+#[derive(Deserialize)]
+pub struct CreateWalletShare {
+    pub email: String,
+    #[serde(default)]
+    pub permission: SharePermission,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_wallet_share_default_permission() {
+        let json = r#"{ "email": "partner@example.com" }"#;
+
+        let create_share: CreateWalletShare = serde_json::from_str(json).unwrap();
+        assert_eq!(create_share.email, "partner@example.com");
+        assert_eq!(create_share.permission, SharePermission::ReadOnly);
+    }
+
+    #[test]
+    fn test_share_permission_serialization() {
+        let json = serde_json::to_string(&SharePermission::ReadOnly).unwrap();
+        assert_eq!(json, r#""ReadOnly""#);
+        assert_eq!(SharePermission::ReadOnly.as_str(), "ReadOnly");
+    }
+
+    #[test]
+    fn test_share_permission_rejects_unknown() {
+        let json = r#"{ "email": "partner@example.com", "permission": "ReadWrite" }"#;
+
+        assert!(serde_json::from_str::<CreateWalletShare>(json).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::{StatusCode, header},
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    AppState, database,
+    models::{
+        export::UserExport,
+        response::{Error, TranslationKey},
+    },
+    routes::middlewares::auth_guard,
+};
+
+/// Creates a router for the export routes
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the export endpoint with auth middleware.
+///
+/// # Routes
+///
+/// - `GET /` - Export all data of the authenticated user (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(export_user_data))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Exports all data the transaction service stores for the authenticated user
+///
+/// Limited to one successful export per user per hour.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<UserExport>)` - The export document as a JSON attachment
+/// * `Err(Error)` - Rate limit exceeded or database operation error
+async fn export_user_data(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!("GET /export - Exporting data for user {}", user_id);
+
+    let rate_limiter = state.get_export_rate_limiter();
+    let requested_at = Utc::now();
+    if !rate_limiter.try_acquire(user_id, requested_at) {
+        tracing::warn!("User {} requested an export too soon", user_id);
+        return Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            TranslationKey::ExportRateLimited,
+        )
+            .into());
+    }
+
+    // A failed export does not count against the user's hourly limit
+    let export = build_export(user_id, &state)
+        .await
+        .inspect_err(|_| rate_limiter.release(user_id, requested_at))?;
+
+    tracing::info!(
+        "Successfully exported {} wallets for user {}",
+        export.wallets.len(),
+        user_id
+    );
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"brewget-export.json\"",
+        )],
+        Json(export),
+    ))
+}
+
+/// Loads everything the transaction service stores for a user
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the user to export
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(UserExport)` - The export document
+/// * `Err(Error)` - Database operation error
+async fn build_export(user_id: Uuid, state: &AppState) -> Result<UserExport, Error> {
+    let pool = state.get_database_pool();
+
+    let wallets = database::wallet::find_all_by_user(user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch wallets for user {}", user_id);
+        })?;
+
+    let wallet_shares = database::wallet_share::find_by_owner(user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch wallet shares for user {}", user_id);
+        })?;
+
+    Ok(UserExport::new(user_id, wallets, wallet_shares))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::routes::tests::unreachable_state;
+
+    #[tokio::test]
+    async fn test_failed_export_does_not_use_up_the_hourly_slot() {
+        let app = Router::new()
+            .route("/", get(export_user_data))
+            .layer(Extension(Uuid::new_v4()))
+            .with_state(unreachable_state());
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(Request::get("/").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+}
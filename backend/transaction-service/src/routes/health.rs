@@ -1,42 +1,100 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
 
 use crate::{
     AppState,
-    models::response::{DatabaseConnection, Health, HealthStatus},
+    grpc::auth_service::service::VerifyTokenRequest,
+    models::response::{DatabaseConnection, Health, HealthStatus, ServiceConnection},
 };
 
+/// How long the auth service gets to answer the readiness check
+const AUTH_SERVICE_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// Creates a router for the health routes
+///
+/// # Routes
+///
+/// - `GET /` - Readiness check (database and auth service)
+/// - `GET /live` - Liveness check (process only)
+/// - `GET /ready` - Readiness check (database and auth service)
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(health_checker_handler))
+        .route("/live", get(liveness_handler))
+        .route("/ready", get(health_checker_handler))
         .with_state(state)
 }
 
-/// Health check endpoint handler
+/// Liveness check endpoint handler
 ///
-/// Returns a health message indicating the service is operational
+/// Reports the process as running without checking any dependency, so an
+/// outage of the database or auth service does not get the pod restarted.
 ///
 /// # Returns
 /// JSON response with a health message
+async fn liveness_handler() -> impl IntoResponse {
+    Json(Health {
+        status: HealthStatus::Healthy,
+        database: None,
+        auth_service: None,
+        version: env!("CARGO_PKG_VERSION").into(),
+    })
+}
+
+/// Readiness check endpoint handler
+///
+/// Checks the database connection and whether the auth service gRPC server
+/// answers, since every protected route depends on it.
+///
+/// # Returns
+/// JSON response with the status of each dependency, `503` if any is down
 async fn health_checker_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let pool = state.get_database_pool();
-    match sqlx::query("SELECT 1").execute(pool).await {
-        Ok(_) => Json(Health {
-            status: HealthStatus::Healthy,
-            database: Some(DatabaseConnection::Connected),
+    let database = match sqlx::query("SELECT 1").execute(pool).await {
+        Ok(_) => DatabaseConnection::Connected,
+        Err(_) => DatabaseConnection::Disconnected,
+    };
+    let auth_service = check_auth_service(&state).await;
+
+    let healthy = matches!(database, DatabaseConnection::Connected)
+        && matches!(auth_service, ServiceConnection::Reachable);
+    let (status_code, status) = if healthy {
+        (StatusCode::OK, HealthStatus::Healthy)
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, HealthStatus::Unhealthy)
+    };
+
+    (
+        status_code,
+        Json(Health {
+            status,
+            database: Some(database),
+            auth_service: Some(auth_service),
             version: env!("CARGO_PKG_VERSION").into(),
-        })
-        .into_response(),
-        Err(_) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(Health {
-                status: HealthStatus::Unhealthy,
-                database: Some(DatabaseConnection::Disconnected),
-                version: env!("CARGO_PKG_VERSION").into(),
-            }),
-        )
-            .into_response(),
+        }),
+    )
+}
+
+/// Checks whether the auth service gRPC server answers
+///
+/// Sends a `verify_token` call with an empty token; any well-formed response
+/// means the service is reachable.
+async fn check_auth_service(state: &AppState) -> ServiceConnection {
+    let mut client = state.get_auth_service();
+    let request = tonic::Request::new(VerifyTokenRequest {
+        token: String::new(),
+    });
+
+    match tokio::time::timeout(AUTH_SERVICE_CHECK_TIMEOUT, client.verify_token(request)).await {
+        Ok(Ok(_)) => ServiceConnection::Reachable,
+        Ok(Err(e)) => {
+            tracing::warn!("Health check: auth service returned an error: {}", e);
+            ServiceConnection::Unreachable
+        }
+        Err(_) => {
+            tracing::warn!("Health check: auth service did not answer in time");
+            ServiceConnection::Unreachable
+        }
     }
 }
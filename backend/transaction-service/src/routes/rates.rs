@@ -0,0 +1,92 @@
+use std::{str::FromStr, sync::Arc};
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use serde::Deserialize;
+use shared_types::enums::Currency;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::response::{Error, TranslationKey},
+    routes::middlewares::auth_guard,
+};
+
+/// Query parameters for the exchange rates endpoint
+#[derive(Deserialize)]
+pub struct RatesQuery {
+    /// Base currency code of the rates (e.g. "USD")
+    base: String,
+}
+
+/// Creates a router for the exchange rate routes
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing the exchange rate provider
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the exchange rate endpoints with auth middleware.
+///
+/// # Routes
+///
+/// - `GET /?base=USD` - Get the exchange rates for a base currency (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_rates))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Retrieves the exchange rates for a base currency
+///
+/// Exposes the same rate source used for conversions so the frontend shows
+/// consistent values. Cached rates are flagged as `stale` when the upstream
+/// provider is unreachable.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+/// * `query` - Query parameters containing the base currency
+///
+/// # Returns
+///
+/// * `Ok(Json<ExchangeRates>)` - The exchange rates snapshot
+/// * `Err(Error)` - Unsupported currency (400) or no rates available (503)
+async fn get_rates(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<RatesQuery>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /rates - Fetching {} exchange rates for user {}",
+        query.base,
+        user_id
+    );
+
+    let base = Currency::from_str(&query.base).map_err(|e| -> Error {
+        tracing::error!("{}", e);
+        (StatusCode::BAD_REQUEST, TranslationKey::InvalidCurrency).into()
+    })?;
+
+    let rates = state
+        .get_exchange_rate_provider()
+        .get_rates(base)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to get exchange rates for {}", base);
+        })?;
+
+    Ok(Json(rates))
+}
@@ -1,20 +1,22 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use axum::{
     Extension, Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
     routing::{delete, get, post, put},
 };
+use shared_types::enums::Currency;
 use uuid::Uuid;
 
 use crate::{
-    AppState, database,
+    AppState, database, grpc,
     models::{
-        response::Error,
-        wallet::{CreateWallet, UpdateWallet},
+        net_worth::{NetWorth, NetWorthQuery},
+        response::{Error, TranslationKey},
+        wallet::{CreateWallet, UpdateWallet, WalletResponse},
     },
     routes::middlewares::auth_guard,
 };
@@ -33,14 +35,16 @@ use crate::{
 ///
 /// # Routes
 ///
-/// - `GET /` - Get all wallets for authenticated user (protected by auth middleware)
+/// - `GET /` - Get all own and shared wallets for authenticated user (protected by auth middleware)
 /// - `POST /` - Create a new wallet (protected by auth middleware)
-/// - `PUT /:id` - Update a wallet by ID (protected by auth middleware)
-/// - `DELETE /:id` - Delete a wallet by ID (protected by auth middleware)
+/// - `GET /net-worth` - Get the total net worth in a given or the preferred currency (protected by auth middleware)
+/// - `PUT /{id}` - Update a wallet by ID (protected by auth middleware)
+/// - `DELETE /{id}` - Delete a wallet by ID (protected by auth middleware)
 pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(get_all_wallets))
         .route("/", post(create_wallet))
+        .route("/net-worth", get(get_net_worth))
         .route("/{id}", put(update_wallet))
         .route("/{id}", delete(delete_wallet))
         .route_layer(middleware::from_fn_with_state(
@@ -52,6 +56,8 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 
 /// Retrieves all wallets for the authenticated user
 ///
+/// Includes the wallets other users shared with them, marked with `shared_by`.
+///
 /// # Arguments
 ///
 /// * `user_id` - The UUID of the authenticated user (from auth middleware)
@@ -59,7 +65,7 @@ pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Vec<Wallet>>)` - The user's wallets as JSON
+/// * `Ok(Json<Vec<WalletResponse>>)` - The user's own and shared wallets as JSON
 /// * `Err(Error)` - Database operation error
 async fn get_all_wallets(
     Extension(user_id): Extension<Uuid>,
@@ -75,12 +81,96 @@ async fn get_all_wallets(
             tracing::error!("Failed to fetch wallets for user {}", user_id);
         })?;
 
+    let shared_wallets = database::wallet::find_shared_with_user(user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch shared wallets for user {}", user_id);
+        })?;
+
     tracing::info!(
-        "Successfully fetched {} wallets for user {}",
+        "Successfully fetched {} wallets and {} shared wallets for user {}",
         wallets.len(),
+        shared_wallets.len(),
+        user_id
+    );
+    Ok(Json(
+        wallets
+            .into_iter()
+            .map(WalletResponse::from)
+            .chain(shared_wallets.into_iter().map(WalletResponse::shared))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Computes the total net worth of the authenticated user
+///
+/// Sums all wallet balances converted into the requested currency using the
+/// configured exchange rate provider. Credit cards and loans count as debt.
+/// Without a requested currency the user's preferred currency from the
+/// settings service is used.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `state` - Shared application state
+/// * `query` - Query parameters containing the optional target currency
+///
+/// # Returns
+///
+/// * `Ok(Json<NetWorth>)` - The net worth with per-wallet amounts and the rates used
+/// * `Err(Error)` - Unsupported currency, rate provider or database error
+async fn get_net_worth(
+    Extension(user_id): Extension<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<NetWorthQuery>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /wallet/net-worth - Computing net worth in {:?} for user {}",
+        query.currency,
+        user_id
+    );
+
+    let currency = match query.currency {
+        Some(currency) => Currency::from_str(&currency).map_err(|e| -> Error {
+            tracing::error!("{}", e);
+            (StatusCode::BAD_REQUEST, TranslationKey::InvalidCurrency).into()
+        })?,
+        None => grpc::settings_service::preferred_currency(&state, user_id).await,
+    };
+
+    let pool = state.get_database_pool();
+
+    let wallets = database::wallet::find_all_by_user(user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch wallets for user {}", user_id);
+        })?;
+
+    let rates = state
+        .get_exchange_rate_provider()
+        .get_rates(currency)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to get exchange rates for {}", currency);
+        })?;
+
+    let net_worth = NetWorth::compute(wallets, rates);
+    if !net_worth.excluded_wallet_ids.is_empty() {
+        tracing::warn!(
+            "No {} rate for wallets {:?} of user {}, left out of the net worth",
+            currency,
+            net_worth.excluded_wallet_ids,
+            user_id
+        );
+    }
+
+    tracing::info!(
+        "Successfully computed net worth of {} {} for user {}",
+        net_worth.total,
+        currency,
         user_id
     );
-    Ok(Json(wallets))
+    Ok(Json(net_worth))
 }
 
 /// Creates a new wallet for the authenticated user
@@ -89,12 +179,13 @@ async fn get_all_wallets(
 ///
 /// * `user_id` - The UUID of the authenticated user (from auth middleware)
 /// * `state` - Shared application state
-/// * `create_wallet` - The wallet creation data
+/// * `create_wallet` - The wallet creation data, without a currency the user's preferred
+///   currency from the settings service is used
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Wallet>)` - The created wallet as JSON
-/// * `Err(Error)` - Database operation error
+/// * `Ok(Json<WalletResponse>)` - The created wallet as JSON, including the chosen currency
+/// * `Err(Error)` - Invalid name, wallet limit reached or database operation error
 async fn create_wallet(
     Extension(user_id): Extension<Uuid>,
     State(state): State<Arc<AppState>>,
@@ -102,25 +193,43 @@ async fn create_wallet(
 ) -> Result<impl IntoResponse, Error> {
     tracing::info!("POST /wallet - Creating wallet for user {}", user_id);
     tracing::debug!(
-        "Create payload: name={}, currency={}",
+        "Create payload: name={}, currency={:?}",
         create_wallet.name,
         create_wallet.currency
     );
 
+    create_wallet
+        .validate()
+        .map_err(|translation_key| -> Error {
+            tracing::warn!("Invalid wallet name for user {}", user_id);
+            (StatusCode::BAD_REQUEST, translation_key).into()
+        })?;
+
+    let currency = match create_wallet.currency {
+        Some(currency) => currency,
+        None => grpc::settings_service::preferred_currency(&state, user_id).await,
+    };
+
     let pool = state.get_database_pool();
 
-    let wallet = database::wallet::create(user_id, create_wallet, pool)
-        .await
-        .inspect_err(|_| {
-            tracing::error!("Failed to create wallet for user {}", user_id);
-        })?;
+    let wallet = database::wallet::create(
+        user_id,
+        create_wallet,
+        currency,
+        state.config.max_wallets_per_user,
+        pool,
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!("Failed to create wallet for user {}", user_id);
+    })?;
 
     tracing::info!(
         "Successfully created wallet {} for user {}",
         wallet.id,
         user_id
     );
-    Ok((StatusCode::CREATED, Json(wallet)))
+    Ok((StatusCode::CREATED, Json(WalletResponse::from(wallet))))
 }
 
 /// Updates a wallet
@@ -134,8 +243,8 @@ async fn create_wallet(
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Wallet>)` - The updated wallet as JSON
-/// * `Err(Error)` - Database operation error
+/// * `Ok(Json<WalletResponse>)` - The updated wallet as JSON
+/// * `Err(Error)` - Invalid name, wallet not found or database operation error
 async fn update_wallet(
     Extension(user_id): Extension<Uuid>,
     Path(wallet_id): Path<Uuid>,
@@ -148,12 +257,20 @@ async fn update_wallet(
         user_id
     );
     tracing::debug!(
-        "Update payload: name={:?}, currency={:?}, wallet_type={:?}",
+        "Update payload: name={:?}, currency={:?}, wallet_type={:?}, credit_limit={:?}",
         update_wallet.name,
         update_wallet.currency,
-        update_wallet.wallet_type
+        update_wallet.wallet_type,
+        update_wallet.credit_limit
     );
 
+    update_wallet
+        .validate()
+        .map_err(|translation_key| -> Error {
+            tracing::warn!("Invalid wallet name for user {}", user_id);
+            (StatusCode::BAD_REQUEST, translation_key).into()
+        })?;
+
     let pool = state.get_database_pool();
 
     let wallet = database::wallet::update(wallet_id, user_id, update_wallet, pool)
@@ -167,7 +284,7 @@ async fn update_wallet(
         wallet_id,
         user_id
     );
-    Ok(Json(wallet))
+    Ok(Json(WalletResponse::from(wallet)))
 }
 
 /// Deletes a wallet
@@ -208,3 +325,31 @@ async fn delete_wallet(
     );
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::routes::tests::unreachable_state;
+
+    #[tokio::test]
+    async fn test_net_worth_with_unknown_currency_is_bad_request() {
+        let app = Router::new()
+            .route("/net-worth", get(get_net_worth))
+            .layer(Extension(Uuid::new_v4()))
+            .with_state(unreachable_state());
+
+        let response = app
+            .oneshot(
+                Request::get("/net-worth?currency=XYZ")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use shared_types::grpc::INTERNAL_SECRET_METADATA_KEY;
+use uuid::Uuid;
+
+use crate::{
+    AppState, database,
+    grpc::auth_service::service::GetUserInfoRequest,
+    models::{
+        response::{Error, TranslationKey},
+        wallet_share::CreateWalletShare,
+    },
+    routes::middlewares::auth_guard,
+};
+
+/// Creates a router for the wallet share routes
+///
+/// This router is nested under `/wallet/{wallet_id}/shares`. Only the owner
+/// of the wallet can manage its shares.
+///
+/// # Arguments
+///
+/// * `state` - Shared application state containing configuration and database connection
+///
+/// # Returns
+///
+/// Returns an Axum router configured with the wallet share endpoints with auth middleware.
+///
+/// # Routes
+///
+/// - `GET /` - List the shares of a wallet (protected by auth middleware)
+/// - `POST /` - Share a wallet with another user by email (protected by auth middleware)
+/// - `DELETE /{share_id}` - Revoke a share (protected by auth middleware)
+pub fn get_router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_wallet_shares))
+        .route("/", post(create_wallet_share))
+        .route("/{share_id}", delete(delete_wallet_share))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_guard::auth_guard,
+        ))
+        .with_state(state)
+}
+
+/// Lists the shares of a wallet owned by the authenticated user
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `wallet_id` - The UUID of the wallet
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<WalletShare>>)` - The shares of the wallet as JSON
+/// * `Err(Error)` - Wallet not found or database operation error
+async fn get_wallet_shares(
+    Extension(user_id): Extension<Uuid>,
+    Path(wallet_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "GET /wallet/{}/shares - Fetching shares for user {}",
+        wallet_id,
+        user_id
+    );
+
+    let pool = state.get_database_pool();
+
+    database::wallet::find_by_id(wallet_id, user_id, pool).await?;
+
+    let shares = database::wallet_share::find_by_wallet(wallet_id, user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!("Failed to fetch shares of wallet {}", wallet_id);
+        })?;
+
+    Ok(Json(shares))
+}
+
+/// Shares a wallet owned by the authenticated user with another account
+///
+/// The other account is resolved by email through the auth service. The
+/// response is the same whether or not an account uses the email and whether
+/// or not the user owns the wallet, so the route cannot be used to find out
+/// which emails are registered. Only sharing a wallet with oneself is refused.
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `wallet_id` - The UUID of the wallet to share
+/// * `state` - Shared application state
+/// * `create_share` - The share creation data
+///
+/// # Returns
+///
+/// * `Ok(StatusCode::ACCEPTED)` - If the wallet is shared with the account using the email, if any
+/// * `Err(Error)` - Sharing with oneself, auth service or database operation error
+async fn create_wallet_share(
+    Extension(user_id): Extension<Uuid>,
+    Path(wallet_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    Json(create_share): Json<CreateWalletShare>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "POST /wallet/{}/shares - Sharing wallet for user {}",
+        wallet_id,
+        user_id
+    );
+
+    let mut request = tonic::Request::new(GetUserInfoRequest {
+        email: create_share.email,
+    });
+    let secret = state
+        .config
+        .internal_grpc_secret
+        .parse()
+        .map_err(|_| -> Error {
+            tracing::error!("INTERNAL_GRPC_SECRET is not valid gRPC metadata");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                TranslationKey::InternalServerError,
+            )
+                .into()
+        })?;
+    request
+        .metadata_mut()
+        .insert(INTERNAL_SECRET_METADATA_KEY, secret);
+
+    let user_info = state
+        .get_auth_service()
+        .get_user_info(request)
+        .await?
+        .into_inner();
+
+    let Some(shared_with_user_id) = user_info.user_id.and_then(|id| Uuid::parse_str(&id).ok())
+    else {
+        tracing::info!("No account found to share wallet {} with", wallet_id);
+        return Ok(StatusCode::ACCEPTED);
+    };
+
+    if shared_with_user_id == user_id {
+        tracing::warn!(
+            "User {} tried to share wallet {} with themselves",
+            user_id,
+            wallet_id
+        );
+        return Err((
+            StatusCode::BAD_REQUEST,
+            TranslationKey::CannotShareWalletWithSelf,
+        )
+            .into());
+    }
+
+    let created = database::wallet_share::create(
+        wallet_id,
+        user_id,
+        shared_with_user_id,
+        create_share.permission,
+        state.get_database_pool(),
+    )
+    .await
+    .inspect_err(|_| {
+        tracing::error!("Failed to share wallet {} for user {}", wallet_id, user_id);
+    })?;
+
+    if created {
+        tracing::info!(
+            "Successfully shared wallet {} with user {}",
+            wallet_id,
+            shared_with_user_id
+        );
+    } else {
+        tracing::info!(
+            "Wallet {} is already shared with user {} or not owned by user {}",
+            wallet_id,
+            shared_with_user_id,
+            user_id
+        );
+    }
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Revokes a share of a wallet owned by the authenticated user
+///
+/// # Arguments
+///
+/// * `user_id` - The UUID of the authenticated user (from auth middleware)
+/// * `wallet_id` - The UUID of the shared wallet
+/// * `share_id` - The UUID of the share to revoke
+/// * `state` - Shared application state
+///
+/// # Returns
+///
+/// * `Ok(StatusCode::NO_CONTENT)` - If revocation succeeds
+/// * `Err(Error)` - Share not found or database operation error
+async fn delete_wallet_share(
+    Extension(user_id): Extension<Uuid>,
+    Path((wallet_id, share_id)): Path<(Uuid, Uuid)>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    tracing::info!(
+        "DELETE /wallet/{}/shares/{} - Revoking share for user {}",
+        wallet_id,
+        share_id,
+        user_id
+    );
+
+    let pool = state.get_database_pool();
+
+    let deleted = database::wallet_share::delete(share_id, wallet_id, user_id, pool)
+        .await
+        .inspect_err(|_| {
+            tracing::error!(
+                "Failed to revoke share {} of wallet {}",
+                share_id,
+                wallet_id
+            );
+        })?;
+
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, TranslationKey::WalletShareNotFound).into());
+    }
+
+    tracing::info!(
+        "Successfully revoked share {} of wallet {}",
+        share_id,
+        wallet_id
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+        response::Response,
+    };
+    use shared_types::identity::{INTERNAL_SECRET_HEADER, USER_ID_HEADER};
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Endpoint, Server};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        grpc::auth_service::service::{
+            GetUserInfoResponse, VerifyTokenRequest, VerifyTokenResponse,
+            auth_service_client::AuthServiceClient,
+            auth_service_server::{AuthService, AuthServiceServer},
+        },
+        routes::{make_app, tests::state_with_auth_service},
+    };
+
+    const USER_ID: &str = "6f1c2f4e-8b7a-4c1d-9e2f-3a4b5c6d7e8f";
+
+    /// Knows a single account, `owner@example.com` of `USER_ID`
+    struct MockAuthService;
+
+    #[tonic::async_trait]
+    impl AuthService for MockAuthService {
+        async fn verify_token(
+            &self,
+            _request: tonic::Request<VerifyTokenRequest>,
+        ) -> Result<tonic::Response<VerifyTokenResponse>, tonic::Status> {
+            Err(tonic::Status::unimplemented("not mocked"))
+        }
+
+        async fn get_user_info(
+            &self,
+            request: tonic::Request<GetUserInfoRequest>,
+        ) -> Result<tonic::Response<GetUserInfoResponse>, tonic::Status> {
+            let user_id =
+                (request.get_ref().email == "owner@example.com").then(|| USER_ID.to_string());
+
+            Ok(tonic::Response::new(GetUserInfoResponse {
+                user_id,
+                username: None,
+            }))
+        }
+    }
+
+    /// Shares a wallet of `USER_ID` by email, with an unreachable database
+    async fn share_with(email: &str) -> Response {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(AuthServiceServer::new(MockAuthService))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        let channel = Endpoint::from_shared(url).unwrap().connect_lazy();
+        let state = state_with_auth_service(AuthServiceClient::new(channel));
+
+        make_app(state)
+            .oneshot(
+                Request::post(format!("/wallet/{}/shares", Uuid::new_v4()))
+                    .header(USER_ID_HEADER, USER_ID)
+                    .header(INTERNAL_SECRET_HEADER, "secret")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "email": email }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sharing_with_self_is_bad_request() {
+        let response = share_with("owner@example.com").await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["translation_key"], "CANNOT_SHARE_WALLET_WITH_SELF");
+    }
+
+    #[tokio::test]
+    async fn test_sharing_with_unknown_email_is_accepted() {
+        let response = share_with("nobody@example.com").await;
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+}
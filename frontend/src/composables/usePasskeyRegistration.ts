@@ -1,6 +1,6 @@
 import { ref } from "vue";
 
-import type { ServerResponse } from "@/services/types";
+import type { ServerResponse, TranslationKeyError } from "@/services/types";
 
 import { authService } from "@/services/auth";
 import { ServerStatus } from "@/services/types";
@@ -137,10 +137,9 @@ export function usePasskeyRegistration() {
    * @param toast Toast store instance
    */
   function handleError(response: ServerResponse<unknown>, toast: ReturnType<typeof useToastStore>) {
-    const errorKey =
-      (response.data as { translation_key?: string })?.translation_key ||
-      "SOMETHING_WENT_WRONG";
-    toast.showTranslationKey(errorKey, ToastSeverity.ERROR);
+    const data = response.data as Partial<TranslationKeyError> | undefined;
+    const errorKey = data?.translation_key || "SOMETHING_WENT_WRONG";
+    toast.showTranslationKey(errorKey, ToastSeverity.ERROR, undefined, data?.params);
   }
 
   return {
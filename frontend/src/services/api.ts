@@ -3,24 +3,24 @@ import axios, { type AxiosInstance } from "axios";
 import router from "@/router";
 
 // Auth service axios instance
-// Dev: http://localhost:8000
+// Dev: http://localhost:3000/auth (proxied by the gateway)
 // Prod: /api/auth (proxied by nginx)
 export const authApi = axios.create({
-    baseURL: import.meta.env.PROD ? "/api/auth" : "http://localhost:8000",
+    baseURL: import.meta.env.PROD ? "/api/auth" : "http://localhost:3000/auth",
 });
 
 // Settings service axios instance
-// Dev: http://localhost:8002
+// Dev: http://localhost:3000/settings (proxied by the gateway)
 // Prod: /api/settings (proxied by nginx)
 export const settingsApi = axios.create({
-    baseURL: import.meta.env.PROD ? "/api/settings" : "http://localhost:8002",
+    baseURL: import.meta.env.PROD ? "/api/settings" : "http://localhost:3000/settings",
 });
 
 // Transaction service axios instance
-// Dev: http://localhost:8003
+// Dev: http://localhost:3000/transactions (proxied by the gateway)
 // Prod: /api/transaction (proxied by nginx)
 export const transactionApi = axios.create({
-    baseURL: import.meta.env.PROD ? "/api/transaction" : "http://localhost:8003",
+    baseURL: import.meta.env.PROD ? "/api/transaction" : "http://localhost:3000/transactions",
 });
 
 // Add response interceptor to handle token expiration globally
@@ -45,6 +45,18 @@ async function changePassword(values: { id: string, password: string }): Promise
     }
 }
 
+async function changePasswordAuthenticated(values: { current_password: string, new_password: string }): Promise<ServerResponse<ChangePasswordResponse>> {
+    try {
+        return await authApi.post("/change-password/authenticated", values, {
+            headers: {
+                Authorization: useAuthStore().bearerToken,
+            },
+        });
+    } catch (error) {
+        return (error as AxiosError).response as ErrorResponse;
+    }
+}
+
 async function logout(): Promise<ServerResponse<LogoutResponse>> {
     try {
         return await authApi.get("/logout", {
@@ -57,6 +69,18 @@ async function logout(): Promise<ServerResponse<LogoutResponse>> {
     }
 }
 
+async function logoutAll(): Promise<ServerResponse<LogoutResponse>> {
+    try {
+        return await authApi.get("/logout/all", {
+            headers: {
+                Authorization: useAuthStore().bearerToken,
+            },
+        });
+    } catch (error) {
+        return (error as AxiosError).response as ErrorResponse;
+    }
+}
+
 async function verify(): Promise<ServerResponse<VerifyResponse>> {
     try {
         return await authApi.get("/verify", {
@@ -186,4 +210,4 @@ async function auditList(limit?: number): Promise<ServerResponse<AuthAuditListRe
   }
 }
 
-export const authService = { activate, auditList, changePassword, forgotPassword, login, logout, passkeyAddFinish, passkeyAddStart, passkeyList, passkeyLoginFinish, passkeyLoginStart, passkeyRegisterFinish, passkeyRegisterStart, passkeyRemove, register, verify };
+export const authService = { activate, auditList, changePassword, changePasswordAuthenticated, forgotPassword, login, logout, logoutAll, passkeyAddFinish, passkeyAddStart, passkeyList, passkeyLoginFinish, passkeyLoginStart, passkeyRegisterFinish, passkeyRegisterStart, passkeyRemove, register, verify };
@@ -2,6 +2,9 @@ export interface LoginResponse {
   token: string;
 }
 
+// Sent in the `status` param of login errors for accounts that cannot log in
+export type AccountStatus = "unverified" | "active" | "deactivated";
+
 export interface TranslationKeyMessage {
   translation_key: string;
 }
@@ -24,7 +24,7 @@ async function updateSettings(
   settings: UpdateSettings,
 ): Promise<ServerResponse<Settings>> {
   try {
-    return await settingsApi.post("/user", settings, {
+    return await settingsApi.patch("/user", settings, {
       headers: {
         Authorization: useAuthStore().bearerToken,
       },
@@ -6,6 +6,21 @@ export interface Settings {
   alarm_time: string;
   alarm_offset_minutes: number;
   night_mode: boolean;
+  theme: string;
+  timezone: string;
+  date_format: string;
+  number_format: string;
+  week_starts_on: string;
+  budget_period: string;
+  analytics_enabled: boolean;
+  crash_reports_enabled: boolean;
+  summary_email_frequency: string;
+  summary_email_day: number;
+  currency_symbol_position: string;
+  thousands_separator: string;
+  decimal_places_override: number | null;
+  schema_version: number;
+  updated_at: string;
 }
 
 export interface UpdateSettings {
@@ -15,4 +30,17 @@ export interface UpdateSettings {
   alarm_time?: string;
   alarm_offset_minutes?: number;
   night_mode?: boolean;
+  theme?: string;
+  timezone?: string;
+  date_format?: string;
+  number_format?: string;
+  week_starts_on?: string;
+  budget_period?: string;
+  analytics_enabled?: boolean;
+  crash_reports_enabled?: boolean;
+  summary_email_frequency?: string;
+  summary_email_day?: number;
+  currency_symbol_position?: string;
+  thousands_separator?: string;
+  decimal_places_override?: number | null;
 }
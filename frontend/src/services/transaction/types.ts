@@ -3,6 +3,10 @@ export interface Wallet {
   user_id: string;
   name: string;
   balance: number;
+  initial_balance: number;
+  credit_limit: number | null;
+  available: number | null;
+  shared_by: string | null;
   currency: string;
   wallet_type: string;
   created_at: string;
@@ -12,12 +16,15 @@ export interface Wallet {
 export interface CreateWallet {
   name: string;
   balance?: number;
-  currency: string;
+  currency?: string;
   wallet_type?: string;
+  credit_limit?: number;
 }
 
 export interface UpdateWallet {
   name?: string;
   currency?: string;
   wallet_type?: string;
+  // null removes the credit limit, leaving it out keeps the current one
+  credit_limit?: number | null;
 }
@@ -2,6 +2,7 @@ import type { AxiosResponse } from "axios";
 
 export interface TranslationKeyError {
   translation_key: string;
+  params?: Record<string, unknown>;
 }
 
 export type SuccessResponse<T> = AxiosResponse<T> & {
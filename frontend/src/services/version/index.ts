@@ -3,25 +3,25 @@ import axios from "axios";
 import type { HealthResponse } from "./types";
 
 // Email service axios instance
-// Dev: http://localhost:8001
+// Dev: http://localhost:3000/email (proxied by the gateway)
 // Prod: /api/email (proxied by nginx)
 const emailApi = axios.create({
-  baseURL: import.meta.env.PROD ? "/api/email" : "http://localhost:8001",
+  baseURL: import.meta.env.PROD ? "/api/email" : "http://localhost:3000/email",
 });
 
 // Auth service axios instance for health endpoint
 const authHealthApi = axios.create({
-  baseURL: import.meta.env.PROD ? "/api/auth" : "http://localhost:8000",
+  baseURL: import.meta.env.PROD ? "/api/auth" : "http://localhost:3000/auth",
 });
 
 // Settings service axios instance for health endpoint
 const settingsHealthApi = axios.create({
-  baseURL: import.meta.env.PROD ? "/api/settings" : "http://localhost:8002",
+  baseURL: import.meta.env.PROD ? "/api/settings" : "http://localhost:3000/settings",
 });
 
 // Transaction service axios instance for health endpoint
 const transactionHealthApi = axios.create({
-  baseURL: import.meta.env.PROD ? "/api/transaction" : "http://localhost:8003",
+  baseURL: import.meta.env.PROD ? "/api/transaction" : "http://localhost:3000/transactions",
 });
 
 export const versionService = {
@@ -96,6 +96,8 @@ export const useAuthStore = defineStore(
         useToastStore().showTranslationKey(
           errorResponse.data.translation_key,
           ToastSeverity.ERROR,
+          undefined,
+          errorResponse.data.params,
         );
         return false;
       }
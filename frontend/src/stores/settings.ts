@@ -34,7 +34,10 @@ export const useSettingsStore = defineStore("settings", () => {
     try {
       const response = await settingsService.updateSettings(updates);
 
-      if (response.status !== ServerStatus.NO_ERROR) {
+      if (
+        response.status !== ServerStatus.NO_ERROR &&
+        response.status !== ServerStatus.CREATED
+      ) {
         useToastStore().showError(i18n.global.t("settings.failed_to_update"));
         return false;
       }
@@ -79,13 +79,15 @@ export const useToastStore = defineStore("toast", () => {
    * @param translationKey The backend translation key
    * @param severity The toast severity (defaults to INFO)
    * @param life Toast display duration in milliseconds
+   * @param params Values for the placeholders of the message
    */
   function showTranslationKey(
     translationKey: string,
     severity: ToastSeverity = ToastSeverity.INFO,
     life: number = 5000,
+    params: Record<string, unknown> = {},
   ) {
-    const message = i18n.global.t(`translation_keys.${translationKey}`);
+    const message = i18n.global.t(`translation_keys.${translationKey}`, params);
 
     getToast().add({
       severity,
@@ -185,6 +185,9 @@ kubectl apply -f "$SCRIPT_DIR/07-settings-service.yaml"
 echo "💳 Deploying transaction service..."
 kubectl apply -f "$SCRIPT_DIR/07.5-transaction-service.yaml"
 
+echo "🚪 Deploying gateway..."
+kubectl apply -f "$SCRIPT_DIR/07.8-gateway.yaml"
+
 echo "🎨 Deploying frontend..."
 kubectl apply -f "$SCRIPT_DIR/08-frontend.yaml"
 
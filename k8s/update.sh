@@ -61,6 +61,10 @@ echo "💳 Restarting transaction service..."
 kubectl apply -f "$SCRIPT_DIR/07.5-transaction-service.yaml"
 restart_deployment transaction-service
 
+echo "🚪 Restarting gateway..."
+kubectl apply -f "$SCRIPT_DIR/07.8-gateway.yaml"
+restart_deployment gateway
+
 echo "🎨 Restarting frontend..."
 kubectl apply -f "$SCRIPT_DIR/08-frontend.yaml"
 restart_deployment frontend
@@ -77,6 +81,7 @@ kubectl rollout status deployment/email-service -n brewget --timeout=300s
 kubectl rollout status deployment/auth-service -n brewget --timeout=300s
 kubectl rollout status deployment/settings-service -n brewget --timeout=300s
 kubectl rollout status deployment/transaction-service -n brewget --timeout=300s
+kubectl rollout status deployment/gateway -n brewget --timeout=300s
 kubectl rollout status deployment/frontend -n brewget --timeout=300s
 kubectl rollout status deployment/nginx -n brewget --timeout=300s
 